@@ -0,0 +1,1104 @@
+//! Compares the linear key scan of `LocalizationTable::localize` with the hashed `KEY_INDEX` lookup used
+//! by the generated `localize` function, and with `LocaleHandle::localize_at` on key indices resolved
+//! ahead of time. A table small enough for the generated `localize` to match on its keys is also compared
+//! with the same table generated with `#[match_threshold(0)]`, which looks up every key in `KEY_INDEX`.
+//!
+//! Run with `cargo bench --bench lookup`.
+
+use localize::localization_table;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+localization_table! {LargeTable = LDSL {
+    key_0000 = { en => "English 0", es => "Español 0" },
+    key_0001 = { en => "English 1", es => "Español 1" },
+    key_0002 = { en => "English 2", es => "Español 2" },
+    key_0003 = { en => "English 3", es => "Español 3" },
+    key_0004 = { en => "English 4", es => "Español 4" },
+    key_0005 = { en => "English 5", es => "Español 5" },
+    key_0006 = { en => "English 6", es => "Español 6" },
+    key_0007 = { en => "English 7", es => "Español 7" },
+    key_0008 = { en => "English 8", es => "Español 8" },
+    key_0009 = { en => "English 9", es => "Español 9" },
+    key_0010 = { en => "English 10", es => "Español 10" },
+    key_0011 = { en => "English 11", es => "Español 11" },
+    key_0012 = { en => "English 12", es => "Español 12" },
+    key_0013 = { en => "English 13", es => "Español 13" },
+    key_0014 = { en => "English 14", es => "Español 14" },
+    key_0015 = { en => "English 15", es => "Español 15" },
+    key_0016 = { en => "English 16", es => "Español 16" },
+    key_0017 = { en => "English 17", es => "Español 17" },
+    key_0018 = { en => "English 18", es => "Español 18" },
+    key_0019 = { en => "English 19", es => "Español 19" },
+    key_0020 = { en => "English 20", es => "Español 20" },
+    key_0021 = { en => "English 21", es => "Español 21" },
+    key_0022 = { en => "English 22", es => "Español 22" },
+    key_0023 = { en => "English 23", es => "Español 23" },
+    key_0024 = { en => "English 24", es => "Español 24" },
+    key_0025 = { en => "English 25", es => "Español 25" },
+    key_0026 = { en => "English 26", es => "Español 26" },
+    key_0027 = { en => "English 27", es => "Español 27" },
+    key_0028 = { en => "English 28", es => "Español 28" },
+    key_0029 = { en => "English 29", es => "Español 29" },
+    key_0030 = { en => "English 30", es => "Español 30" },
+    key_0031 = { en => "English 31", es => "Español 31" },
+    key_0032 = { en => "English 32", es => "Español 32" },
+    key_0033 = { en => "English 33", es => "Español 33" },
+    key_0034 = { en => "English 34", es => "Español 34" },
+    key_0035 = { en => "English 35", es => "Español 35" },
+    key_0036 = { en => "English 36", es => "Español 36" },
+    key_0037 = { en => "English 37", es => "Español 37" },
+    key_0038 = { en => "English 38", es => "Español 38" },
+    key_0039 = { en => "English 39", es => "Español 39" },
+    key_0040 = { en => "English 40", es => "Español 40" },
+    key_0041 = { en => "English 41", es => "Español 41" },
+    key_0042 = { en => "English 42", es => "Español 42" },
+    key_0043 = { en => "English 43", es => "Español 43" },
+    key_0044 = { en => "English 44", es => "Español 44" },
+    key_0045 = { en => "English 45", es => "Español 45" },
+    key_0046 = { en => "English 46", es => "Español 46" },
+    key_0047 = { en => "English 47", es => "Español 47" },
+    key_0048 = { en => "English 48", es => "Español 48" },
+    key_0049 = { en => "English 49", es => "Español 49" },
+    key_0050 = { en => "English 50", es => "Español 50" },
+    key_0051 = { en => "English 51", es => "Español 51" },
+    key_0052 = { en => "English 52", es => "Español 52" },
+    key_0053 = { en => "English 53", es => "Español 53" },
+    key_0054 = { en => "English 54", es => "Español 54" },
+    key_0055 = { en => "English 55", es => "Español 55" },
+    key_0056 = { en => "English 56", es => "Español 56" },
+    key_0057 = { en => "English 57", es => "Español 57" },
+    key_0058 = { en => "English 58", es => "Español 58" },
+    key_0059 = { en => "English 59", es => "Español 59" },
+    key_0060 = { en => "English 60", es => "Español 60" },
+    key_0061 = { en => "English 61", es => "Español 61" },
+    key_0062 = { en => "English 62", es => "Español 62" },
+    key_0063 = { en => "English 63", es => "Español 63" },
+    key_0064 = { en => "English 64", es => "Español 64" },
+    key_0065 = { en => "English 65", es => "Español 65" },
+    key_0066 = { en => "English 66", es => "Español 66" },
+    key_0067 = { en => "English 67", es => "Español 67" },
+    key_0068 = { en => "English 68", es => "Español 68" },
+    key_0069 = { en => "English 69", es => "Español 69" },
+    key_0070 = { en => "English 70", es => "Español 70" },
+    key_0071 = { en => "English 71", es => "Español 71" },
+    key_0072 = { en => "English 72", es => "Español 72" },
+    key_0073 = { en => "English 73", es => "Español 73" },
+    key_0074 = { en => "English 74", es => "Español 74" },
+    key_0075 = { en => "English 75", es => "Español 75" },
+    key_0076 = { en => "English 76", es => "Español 76" },
+    key_0077 = { en => "English 77", es => "Español 77" },
+    key_0078 = { en => "English 78", es => "Español 78" },
+    key_0079 = { en => "English 79", es => "Español 79" },
+    key_0080 = { en => "English 80", es => "Español 80" },
+    key_0081 = { en => "English 81", es => "Español 81" },
+    key_0082 = { en => "English 82", es => "Español 82" },
+    key_0083 = { en => "English 83", es => "Español 83" },
+    key_0084 = { en => "English 84", es => "Español 84" },
+    key_0085 = { en => "English 85", es => "Español 85" },
+    key_0086 = { en => "English 86", es => "Español 86" },
+    key_0087 = { en => "English 87", es => "Español 87" },
+    key_0088 = { en => "English 88", es => "Español 88" },
+    key_0089 = { en => "English 89", es => "Español 89" },
+    key_0090 = { en => "English 90", es => "Español 90" },
+    key_0091 = { en => "English 91", es => "Español 91" },
+    key_0092 = { en => "English 92", es => "Español 92" },
+    key_0093 = { en => "English 93", es => "Español 93" },
+    key_0094 = { en => "English 94", es => "Español 94" },
+    key_0095 = { en => "English 95", es => "Español 95" },
+    key_0096 = { en => "English 96", es => "Español 96" },
+    key_0097 = { en => "English 97", es => "Español 97" },
+    key_0098 = { en => "English 98", es => "Español 98" },
+    key_0099 = { en => "English 99", es => "Español 99" },
+    key_0100 = { en => "English 100", es => "Español 100" },
+    key_0101 = { en => "English 101", es => "Español 101" },
+    key_0102 = { en => "English 102", es => "Español 102" },
+    key_0103 = { en => "English 103", es => "Español 103" },
+    key_0104 = { en => "English 104", es => "Español 104" },
+    key_0105 = { en => "English 105", es => "Español 105" },
+    key_0106 = { en => "English 106", es => "Español 106" },
+    key_0107 = { en => "English 107", es => "Español 107" },
+    key_0108 = { en => "English 108", es => "Español 108" },
+    key_0109 = { en => "English 109", es => "Español 109" },
+    key_0110 = { en => "English 110", es => "Español 110" },
+    key_0111 = { en => "English 111", es => "Español 111" },
+    key_0112 = { en => "English 112", es => "Español 112" },
+    key_0113 = { en => "English 113", es => "Español 113" },
+    key_0114 = { en => "English 114", es => "Español 114" },
+    key_0115 = { en => "English 115", es => "Español 115" },
+    key_0116 = { en => "English 116", es => "Español 116" },
+    key_0117 = { en => "English 117", es => "Español 117" },
+    key_0118 = { en => "English 118", es => "Español 118" },
+    key_0119 = { en => "English 119", es => "Español 119" },
+    key_0120 = { en => "English 120", es => "Español 120" },
+    key_0121 = { en => "English 121", es => "Español 121" },
+    key_0122 = { en => "English 122", es => "Español 122" },
+    key_0123 = { en => "English 123", es => "Español 123" },
+    key_0124 = { en => "English 124", es => "Español 124" },
+    key_0125 = { en => "English 125", es => "Español 125" },
+    key_0126 = { en => "English 126", es => "Español 126" },
+    key_0127 = { en => "English 127", es => "Español 127" },
+    key_0128 = { en => "English 128", es => "Español 128" },
+    key_0129 = { en => "English 129", es => "Español 129" },
+    key_0130 = { en => "English 130", es => "Español 130" },
+    key_0131 = { en => "English 131", es => "Español 131" },
+    key_0132 = { en => "English 132", es => "Español 132" },
+    key_0133 = { en => "English 133", es => "Español 133" },
+    key_0134 = { en => "English 134", es => "Español 134" },
+    key_0135 = { en => "English 135", es => "Español 135" },
+    key_0136 = { en => "English 136", es => "Español 136" },
+    key_0137 = { en => "English 137", es => "Español 137" },
+    key_0138 = { en => "English 138", es => "Español 138" },
+    key_0139 = { en => "English 139", es => "Español 139" },
+    key_0140 = { en => "English 140", es => "Español 140" },
+    key_0141 = { en => "English 141", es => "Español 141" },
+    key_0142 = { en => "English 142", es => "Español 142" },
+    key_0143 = { en => "English 143", es => "Español 143" },
+    key_0144 = { en => "English 144", es => "Español 144" },
+    key_0145 = { en => "English 145", es => "Español 145" },
+    key_0146 = { en => "English 146", es => "Español 146" },
+    key_0147 = { en => "English 147", es => "Español 147" },
+    key_0148 = { en => "English 148", es => "Español 148" },
+    key_0149 = { en => "English 149", es => "Español 149" },
+    key_0150 = { en => "English 150", es => "Español 150" },
+    key_0151 = { en => "English 151", es => "Español 151" },
+    key_0152 = { en => "English 152", es => "Español 152" },
+    key_0153 = { en => "English 153", es => "Español 153" },
+    key_0154 = { en => "English 154", es => "Español 154" },
+    key_0155 = { en => "English 155", es => "Español 155" },
+    key_0156 = { en => "English 156", es => "Español 156" },
+    key_0157 = { en => "English 157", es => "Español 157" },
+    key_0158 = { en => "English 158", es => "Español 158" },
+    key_0159 = { en => "English 159", es => "Español 159" },
+    key_0160 = { en => "English 160", es => "Español 160" },
+    key_0161 = { en => "English 161", es => "Español 161" },
+    key_0162 = { en => "English 162", es => "Español 162" },
+    key_0163 = { en => "English 163", es => "Español 163" },
+    key_0164 = { en => "English 164", es => "Español 164" },
+    key_0165 = { en => "English 165", es => "Español 165" },
+    key_0166 = { en => "English 166", es => "Español 166" },
+    key_0167 = { en => "English 167", es => "Español 167" },
+    key_0168 = { en => "English 168", es => "Español 168" },
+    key_0169 = { en => "English 169", es => "Español 169" },
+    key_0170 = { en => "English 170", es => "Español 170" },
+    key_0171 = { en => "English 171", es => "Español 171" },
+    key_0172 = { en => "English 172", es => "Español 172" },
+    key_0173 = { en => "English 173", es => "Español 173" },
+    key_0174 = { en => "English 174", es => "Español 174" },
+    key_0175 = { en => "English 175", es => "Español 175" },
+    key_0176 = { en => "English 176", es => "Español 176" },
+    key_0177 = { en => "English 177", es => "Español 177" },
+    key_0178 = { en => "English 178", es => "Español 178" },
+    key_0179 = { en => "English 179", es => "Español 179" },
+    key_0180 = { en => "English 180", es => "Español 180" },
+    key_0181 = { en => "English 181", es => "Español 181" },
+    key_0182 = { en => "English 182", es => "Español 182" },
+    key_0183 = { en => "English 183", es => "Español 183" },
+    key_0184 = { en => "English 184", es => "Español 184" },
+    key_0185 = { en => "English 185", es => "Español 185" },
+    key_0186 = { en => "English 186", es => "Español 186" },
+    key_0187 = { en => "English 187", es => "Español 187" },
+    key_0188 = { en => "English 188", es => "Español 188" },
+    key_0189 = { en => "English 189", es => "Español 189" },
+    key_0190 = { en => "English 190", es => "Español 190" },
+    key_0191 = { en => "English 191", es => "Español 191" },
+    key_0192 = { en => "English 192", es => "Español 192" },
+    key_0193 = { en => "English 193", es => "Español 193" },
+    key_0194 = { en => "English 194", es => "Español 194" },
+    key_0195 = { en => "English 195", es => "Español 195" },
+    key_0196 = { en => "English 196", es => "Español 196" },
+    key_0197 = { en => "English 197", es => "Español 197" },
+    key_0198 = { en => "English 198", es => "Español 198" },
+    key_0199 = { en => "English 199", es => "Español 199" },
+    key_0200 = { en => "English 200", es => "Español 200" },
+    key_0201 = { en => "English 201", es => "Español 201" },
+    key_0202 = { en => "English 202", es => "Español 202" },
+    key_0203 = { en => "English 203", es => "Español 203" },
+    key_0204 = { en => "English 204", es => "Español 204" },
+    key_0205 = { en => "English 205", es => "Español 205" },
+    key_0206 = { en => "English 206", es => "Español 206" },
+    key_0207 = { en => "English 207", es => "Español 207" },
+    key_0208 = { en => "English 208", es => "Español 208" },
+    key_0209 = { en => "English 209", es => "Español 209" },
+    key_0210 = { en => "English 210", es => "Español 210" },
+    key_0211 = { en => "English 211", es => "Español 211" },
+    key_0212 = { en => "English 212", es => "Español 212" },
+    key_0213 = { en => "English 213", es => "Español 213" },
+    key_0214 = { en => "English 214", es => "Español 214" },
+    key_0215 = { en => "English 215", es => "Español 215" },
+    key_0216 = { en => "English 216", es => "Español 216" },
+    key_0217 = { en => "English 217", es => "Español 217" },
+    key_0218 = { en => "English 218", es => "Español 218" },
+    key_0219 = { en => "English 219", es => "Español 219" },
+    key_0220 = { en => "English 220", es => "Español 220" },
+    key_0221 = { en => "English 221", es => "Español 221" },
+    key_0222 = { en => "English 222", es => "Español 222" },
+    key_0223 = { en => "English 223", es => "Español 223" },
+    key_0224 = { en => "English 224", es => "Español 224" },
+    key_0225 = { en => "English 225", es => "Español 225" },
+    key_0226 = { en => "English 226", es => "Español 226" },
+    key_0227 = { en => "English 227", es => "Español 227" },
+    key_0228 = { en => "English 228", es => "Español 228" },
+    key_0229 = { en => "English 229", es => "Español 229" },
+    key_0230 = { en => "English 230", es => "Español 230" },
+    key_0231 = { en => "English 231", es => "Español 231" },
+    key_0232 = { en => "English 232", es => "Español 232" },
+    key_0233 = { en => "English 233", es => "Español 233" },
+    key_0234 = { en => "English 234", es => "Español 234" },
+    key_0235 = { en => "English 235", es => "Español 235" },
+    key_0236 = { en => "English 236", es => "Español 236" },
+    key_0237 = { en => "English 237", es => "Español 237" },
+    key_0238 = { en => "English 238", es => "Español 238" },
+    key_0239 = { en => "English 239", es => "Español 239" },
+    key_0240 = { en => "English 240", es => "Español 240" },
+    key_0241 = { en => "English 241", es => "Español 241" },
+    key_0242 = { en => "English 242", es => "Español 242" },
+    key_0243 = { en => "English 243", es => "Español 243" },
+    key_0244 = { en => "English 244", es => "Español 244" },
+    key_0245 = { en => "English 245", es => "Español 245" },
+    key_0246 = { en => "English 246", es => "Español 246" },
+    key_0247 = { en => "English 247", es => "Español 247" },
+    key_0248 = { en => "English 248", es => "Español 248" },
+    key_0249 = { en => "English 249", es => "Español 249" },
+    key_0250 = { en => "English 250", es => "Español 250" },
+    key_0251 = { en => "English 251", es => "Español 251" },
+    key_0252 = { en => "English 252", es => "Español 252" },
+    key_0253 = { en => "English 253", es => "Español 253" },
+    key_0254 = { en => "English 254", es => "Español 254" },
+    key_0255 = { en => "English 255", es => "Español 255" },
+    key_0256 = { en => "English 256", es => "Español 256" },
+    key_0257 = { en => "English 257", es => "Español 257" },
+    key_0258 = { en => "English 258", es => "Español 258" },
+    key_0259 = { en => "English 259", es => "Español 259" },
+    key_0260 = { en => "English 260", es => "Español 260" },
+    key_0261 = { en => "English 261", es => "Español 261" },
+    key_0262 = { en => "English 262", es => "Español 262" },
+    key_0263 = { en => "English 263", es => "Español 263" },
+    key_0264 = { en => "English 264", es => "Español 264" },
+    key_0265 = { en => "English 265", es => "Español 265" },
+    key_0266 = { en => "English 266", es => "Español 266" },
+    key_0267 = { en => "English 267", es => "Español 267" },
+    key_0268 = { en => "English 268", es => "Español 268" },
+    key_0269 = { en => "English 269", es => "Español 269" },
+    key_0270 = { en => "English 270", es => "Español 270" },
+    key_0271 = { en => "English 271", es => "Español 271" },
+    key_0272 = { en => "English 272", es => "Español 272" },
+    key_0273 = { en => "English 273", es => "Español 273" },
+    key_0274 = { en => "English 274", es => "Español 274" },
+    key_0275 = { en => "English 275", es => "Español 275" },
+    key_0276 = { en => "English 276", es => "Español 276" },
+    key_0277 = { en => "English 277", es => "Español 277" },
+    key_0278 = { en => "English 278", es => "Español 278" },
+    key_0279 = { en => "English 279", es => "Español 279" },
+    key_0280 = { en => "English 280", es => "Español 280" },
+    key_0281 = { en => "English 281", es => "Español 281" },
+    key_0282 = { en => "English 282", es => "Español 282" },
+    key_0283 = { en => "English 283", es => "Español 283" },
+    key_0284 = { en => "English 284", es => "Español 284" },
+    key_0285 = { en => "English 285", es => "Español 285" },
+    key_0286 = { en => "English 286", es => "Español 286" },
+    key_0287 = { en => "English 287", es => "Español 287" },
+    key_0288 = { en => "English 288", es => "Español 288" },
+    key_0289 = { en => "English 289", es => "Español 289" },
+    key_0290 = { en => "English 290", es => "Español 290" },
+    key_0291 = { en => "English 291", es => "Español 291" },
+    key_0292 = { en => "English 292", es => "Español 292" },
+    key_0293 = { en => "English 293", es => "Español 293" },
+    key_0294 = { en => "English 294", es => "Español 294" },
+    key_0295 = { en => "English 295", es => "Español 295" },
+    key_0296 = { en => "English 296", es => "Español 296" },
+    key_0297 = { en => "English 297", es => "Español 297" },
+    key_0298 = { en => "English 298", es => "Español 298" },
+    key_0299 = { en => "English 299", es => "Español 299" },
+    key_0300 = { en => "English 300", es => "Español 300" },
+    key_0301 = { en => "English 301", es => "Español 301" },
+    key_0302 = { en => "English 302", es => "Español 302" },
+    key_0303 = { en => "English 303", es => "Español 303" },
+    key_0304 = { en => "English 304", es => "Español 304" },
+    key_0305 = { en => "English 305", es => "Español 305" },
+    key_0306 = { en => "English 306", es => "Español 306" },
+    key_0307 = { en => "English 307", es => "Español 307" },
+    key_0308 = { en => "English 308", es => "Español 308" },
+    key_0309 = { en => "English 309", es => "Español 309" },
+    key_0310 = { en => "English 310", es => "Español 310" },
+    key_0311 = { en => "English 311", es => "Español 311" },
+    key_0312 = { en => "English 312", es => "Español 312" },
+    key_0313 = { en => "English 313", es => "Español 313" },
+    key_0314 = { en => "English 314", es => "Español 314" },
+    key_0315 = { en => "English 315", es => "Español 315" },
+    key_0316 = { en => "English 316", es => "Español 316" },
+    key_0317 = { en => "English 317", es => "Español 317" },
+    key_0318 = { en => "English 318", es => "Español 318" },
+    key_0319 = { en => "English 319", es => "Español 319" },
+    key_0320 = { en => "English 320", es => "Español 320" },
+    key_0321 = { en => "English 321", es => "Español 321" },
+    key_0322 = { en => "English 322", es => "Español 322" },
+    key_0323 = { en => "English 323", es => "Español 323" },
+    key_0324 = { en => "English 324", es => "Español 324" },
+    key_0325 = { en => "English 325", es => "Español 325" },
+    key_0326 = { en => "English 326", es => "Español 326" },
+    key_0327 = { en => "English 327", es => "Español 327" },
+    key_0328 = { en => "English 328", es => "Español 328" },
+    key_0329 = { en => "English 329", es => "Español 329" },
+    key_0330 = { en => "English 330", es => "Español 330" },
+    key_0331 = { en => "English 331", es => "Español 331" },
+    key_0332 = { en => "English 332", es => "Español 332" },
+    key_0333 = { en => "English 333", es => "Español 333" },
+    key_0334 = { en => "English 334", es => "Español 334" },
+    key_0335 = { en => "English 335", es => "Español 335" },
+    key_0336 = { en => "English 336", es => "Español 336" },
+    key_0337 = { en => "English 337", es => "Español 337" },
+    key_0338 = { en => "English 338", es => "Español 338" },
+    key_0339 = { en => "English 339", es => "Español 339" },
+    key_0340 = { en => "English 340", es => "Español 340" },
+    key_0341 = { en => "English 341", es => "Español 341" },
+    key_0342 = { en => "English 342", es => "Español 342" },
+    key_0343 = { en => "English 343", es => "Español 343" },
+    key_0344 = { en => "English 344", es => "Español 344" },
+    key_0345 = { en => "English 345", es => "Español 345" },
+    key_0346 = { en => "English 346", es => "Español 346" },
+    key_0347 = { en => "English 347", es => "Español 347" },
+    key_0348 = { en => "English 348", es => "Español 348" },
+    key_0349 = { en => "English 349", es => "Español 349" },
+    key_0350 = { en => "English 350", es => "Español 350" },
+    key_0351 = { en => "English 351", es => "Español 351" },
+    key_0352 = { en => "English 352", es => "Español 352" },
+    key_0353 = { en => "English 353", es => "Español 353" },
+    key_0354 = { en => "English 354", es => "Español 354" },
+    key_0355 = { en => "English 355", es => "Español 355" },
+    key_0356 = { en => "English 356", es => "Español 356" },
+    key_0357 = { en => "English 357", es => "Español 357" },
+    key_0358 = { en => "English 358", es => "Español 358" },
+    key_0359 = { en => "English 359", es => "Español 359" },
+    key_0360 = { en => "English 360", es => "Español 360" },
+    key_0361 = { en => "English 361", es => "Español 361" },
+    key_0362 = { en => "English 362", es => "Español 362" },
+    key_0363 = { en => "English 363", es => "Español 363" },
+    key_0364 = { en => "English 364", es => "Español 364" },
+    key_0365 = { en => "English 365", es => "Español 365" },
+    key_0366 = { en => "English 366", es => "Español 366" },
+    key_0367 = { en => "English 367", es => "Español 367" },
+    key_0368 = { en => "English 368", es => "Español 368" },
+    key_0369 = { en => "English 369", es => "Español 369" },
+    key_0370 = { en => "English 370", es => "Español 370" },
+    key_0371 = { en => "English 371", es => "Español 371" },
+    key_0372 = { en => "English 372", es => "Español 372" },
+    key_0373 = { en => "English 373", es => "Español 373" },
+    key_0374 = { en => "English 374", es => "Español 374" },
+    key_0375 = { en => "English 375", es => "Español 375" },
+    key_0376 = { en => "English 376", es => "Español 376" },
+    key_0377 = { en => "English 377", es => "Español 377" },
+    key_0378 = { en => "English 378", es => "Español 378" },
+    key_0379 = { en => "English 379", es => "Español 379" },
+    key_0380 = { en => "English 380", es => "Español 380" },
+    key_0381 = { en => "English 381", es => "Español 381" },
+    key_0382 = { en => "English 382", es => "Español 382" },
+    key_0383 = { en => "English 383", es => "Español 383" },
+    key_0384 = { en => "English 384", es => "Español 384" },
+    key_0385 = { en => "English 385", es => "Español 385" },
+    key_0386 = { en => "English 386", es => "Español 386" },
+    key_0387 = { en => "English 387", es => "Español 387" },
+    key_0388 = { en => "English 388", es => "Español 388" },
+    key_0389 = { en => "English 389", es => "Español 389" },
+    key_0390 = { en => "English 390", es => "Español 390" },
+    key_0391 = { en => "English 391", es => "Español 391" },
+    key_0392 = { en => "English 392", es => "Español 392" },
+    key_0393 = { en => "English 393", es => "Español 393" },
+    key_0394 = { en => "English 394", es => "Español 394" },
+    key_0395 = { en => "English 395", es => "Español 395" },
+    key_0396 = { en => "English 396", es => "Español 396" },
+    key_0397 = { en => "English 397", es => "Español 397" },
+    key_0398 = { en => "English 398", es => "Español 398" },
+    key_0399 = { en => "English 399", es => "Español 399" },
+    key_0400 = { en => "English 400", es => "Español 400" },
+    key_0401 = { en => "English 401", es => "Español 401" },
+    key_0402 = { en => "English 402", es => "Español 402" },
+    key_0403 = { en => "English 403", es => "Español 403" },
+    key_0404 = { en => "English 404", es => "Español 404" },
+    key_0405 = { en => "English 405", es => "Español 405" },
+    key_0406 = { en => "English 406", es => "Español 406" },
+    key_0407 = { en => "English 407", es => "Español 407" },
+    key_0408 = { en => "English 408", es => "Español 408" },
+    key_0409 = { en => "English 409", es => "Español 409" },
+    key_0410 = { en => "English 410", es => "Español 410" },
+    key_0411 = { en => "English 411", es => "Español 411" },
+    key_0412 = { en => "English 412", es => "Español 412" },
+    key_0413 = { en => "English 413", es => "Español 413" },
+    key_0414 = { en => "English 414", es => "Español 414" },
+    key_0415 = { en => "English 415", es => "Español 415" },
+    key_0416 = { en => "English 416", es => "Español 416" },
+    key_0417 = { en => "English 417", es => "Español 417" },
+    key_0418 = { en => "English 418", es => "Español 418" },
+    key_0419 = { en => "English 419", es => "Español 419" },
+    key_0420 = { en => "English 420", es => "Español 420" },
+    key_0421 = { en => "English 421", es => "Español 421" },
+    key_0422 = { en => "English 422", es => "Español 422" },
+    key_0423 = { en => "English 423", es => "Español 423" },
+    key_0424 = { en => "English 424", es => "Español 424" },
+    key_0425 = { en => "English 425", es => "Español 425" },
+    key_0426 = { en => "English 426", es => "Español 426" },
+    key_0427 = { en => "English 427", es => "Español 427" },
+    key_0428 = { en => "English 428", es => "Español 428" },
+    key_0429 = { en => "English 429", es => "Español 429" },
+    key_0430 = { en => "English 430", es => "Español 430" },
+    key_0431 = { en => "English 431", es => "Español 431" },
+    key_0432 = { en => "English 432", es => "Español 432" },
+    key_0433 = { en => "English 433", es => "Español 433" },
+    key_0434 = { en => "English 434", es => "Español 434" },
+    key_0435 = { en => "English 435", es => "Español 435" },
+    key_0436 = { en => "English 436", es => "Español 436" },
+    key_0437 = { en => "English 437", es => "Español 437" },
+    key_0438 = { en => "English 438", es => "Español 438" },
+    key_0439 = { en => "English 439", es => "Español 439" },
+    key_0440 = { en => "English 440", es => "Español 440" },
+    key_0441 = { en => "English 441", es => "Español 441" },
+    key_0442 = { en => "English 442", es => "Español 442" },
+    key_0443 = { en => "English 443", es => "Español 443" },
+    key_0444 = { en => "English 444", es => "Español 444" },
+    key_0445 = { en => "English 445", es => "Español 445" },
+    key_0446 = { en => "English 446", es => "Español 446" },
+    key_0447 = { en => "English 447", es => "Español 447" },
+    key_0448 = { en => "English 448", es => "Español 448" },
+    key_0449 = { en => "English 449", es => "Español 449" },
+    key_0450 = { en => "English 450", es => "Español 450" },
+    key_0451 = { en => "English 451", es => "Español 451" },
+    key_0452 = { en => "English 452", es => "Español 452" },
+    key_0453 = { en => "English 453", es => "Español 453" },
+    key_0454 = { en => "English 454", es => "Español 454" },
+    key_0455 = { en => "English 455", es => "Español 455" },
+    key_0456 = { en => "English 456", es => "Español 456" },
+    key_0457 = { en => "English 457", es => "Español 457" },
+    key_0458 = { en => "English 458", es => "Español 458" },
+    key_0459 = { en => "English 459", es => "Español 459" },
+    key_0460 = { en => "English 460", es => "Español 460" },
+    key_0461 = { en => "English 461", es => "Español 461" },
+    key_0462 = { en => "English 462", es => "Español 462" },
+    key_0463 = { en => "English 463", es => "Español 463" },
+    key_0464 = { en => "English 464", es => "Español 464" },
+    key_0465 = { en => "English 465", es => "Español 465" },
+    key_0466 = { en => "English 466", es => "Español 466" },
+    key_0467 = { en => "English 467", es => "Español 467" },
+    key_0468 = { en => "English 468", es => "Español 468" },
+    key_0469 = { en => "English 469", es => "Español 469" },
+    key_0470 = { en => "English 470", es => "Español 470" },
+    key_0471 = { en => "English 471", es => "Español 471" },
+    key_0472 = { en => "English 472", es => "Español 472" },
+    key_0473 = { en => "English 473", es => "Español 473" },
+    key_0474 = { en => "English 474", es => "Español 474" },
+    key_0475 = { en => "English 475", es => "Español 475" },
+    key_0476 = { en => "English 476", es => "Español 476" },
+    key_0477 = { en => "English 477", es => "Español 477" },
+    key_0478 = { en => "English 478", es => "Español 478" },
+    key_0479 = { en => "English 479", es => "Español 479" },
+    key_0480 = { en => "English 480", es => "Español 480" },
+    key_0481 = { en => "English 481", es => "Español 481" },
+    key_0482 = { en => "English 482", es => "Español 482" },
+    key_0483 = { en => "English 483", es => "Español 483" },
+    key_0484 = { en => "English 484", es => "Español 484" },
+    key_0485 = { en => "English 485", es => "Español 485" },
+    key_0486 = { en => "English 486", es => "Español 486" },
+    key_0487 = { en => "English 487", es => "Español 487" },
+    key_0488 = { en => "English 488", es => "Español 488" },
+    key_0489 = { en => "English 489", es => "Español 489" },
+    key_0490 = { en => "English 490", es => "Español 490" },
+    key_0491 = { en => "English 491", es => "Español 491" },
+    key_0492 = { en => "English 492", es => "Español 492" },
+    key_0493 = { en => "English 493", es => "Español 493" },
+    key_0494 = { en => "English 494", es => "Español 494" },
+    key_0495 = { en => "English 495", es => "Español 495" },
+    key_0496 = { en => "English 496", es => "Español 496" },
+    key_0497 = { en => "English 497", es => "Español 497" },
+    key_0498 = { en => "English 498", es => "Español 498" },
+    key_0499 = { en => "English 499", es => "Español 499" },
+    key_0500 = { en => "English 500", es => "Español 500" },
+    key_0501 = { en => "English 501", es => "Español 501" },
+    key_0502 = { en => "English 502", es => "Español 502" },
+    key_0503 = { en => "English 503", es => "Español 503" },
+    key_0504 = { en => "English 504", es => "Español 504" },
+    key_0505 = { en => "English 505", es => "Español 505" },
+    key_0506 = { en => "English 506", es => "Español 506" },
+    key_0507 = { en => "English 507", es => "Español 507" },
+    key_0508 = { en => "English 508", es => "Español 508" },
+    key_0509 = { en => "English 509", es => "Español 509" },
+    key_0510 = { en => "English 510", es => "Español 510" },
+    key_0511 = { en => "English 511", es => "Español 511" },
+    key_0512 = { en => "English 512", es => "Español 512" },
+    key_0513 = { en => "English 513", es => "Español 513" },
+    key_0514 = { en => "English 514", es => "Español 514" },
+    key_0515 = { en => "English 515", es => "Español 515" },
+    key_0516 = { en => "English 516", es => "Español 516" },
+    key_0517 = { en => "English 517", es => "Español 517" },
+    key_0518 = { en => "English 518", es => "Español 518" },
+    key_0519 = { en => "English 519", es => "Español 519" },
+    key_0520 = { en => "English 520", es => "Español 520" },
+    key_0521 = { en => "English 521", es => "Español 521" },
+    key_0522 = { en => "English 522", es => "Español 522" },
+    key_0523 = { en => "English 523", es => "Español 523" },
+    key_0524 = { en => "English 524", es => "Español 524" },
+    key_0525 = { en => "English 525", es => "Español 525" },
+    key_0526 = { en => "English 526", es => "Español 526" },
+    key_0527 = { en => "English 527", es => "Español 527" },
+    key_0528 = { en => "English 528", es => "Español 528" },
+    key_0529 = { en => "English 529", es => "Español 529" },
+    key_0530 = { en => "English 530", es => "Español 530" },
+    key_0531 = { en => "English 531", es => "Español 531" },
+    key_0532 = { en => "English 532", es => "Español 532" },
+    key_0533 = { en => "English 533", es => "Español 533" },
+    key_0534 = { en => "English 534", es => "Español 534" },
+    key_0535 = { en => "English 535", es => "Español 535" },
+    key_0536 = { en => "English 536", es => "Español 536" },
+    key_0537 = { en => "English 537", es => "Español 537" },
+    key_0538 = { en => "English 538", es => "Español 538" },
+    key_0539 = { en => "English 539", es => "Español 539" },
+    key_0540 = { en => "English 540", es => "Español 540" },
+    key_0541 = { en => "English 541", es => "Español 541" },
+    key_0542 = { en => "English 542", es => "Español 542" },
+    key_0543 = { en => "English 543", es => "Español 543" },
+    key_0544 = { en => "English 544", es => "Español 544" },
+    key_0545 = { en => "English 545", es => "Español 545" },
+    key_0546 = { en => "English 546", es => "Español 546" },
+    key_0547 = { en => "English 547", es => "Español 547" },
+    key_0548 = { en => "English 548", es => "Español 548" },
+    key_0549 = { en => "English 549", es => "Español 549" },
+    key_0550 = { en => "English 550", es => "Español 550" },
+    key_0551 = { en => "English 551", es => "Español 551" },
+    key_0552 = { en => "English 552", es => "Español 552" },
+    key_0553 = { en => "English 553", es => "Español 553" },
+    key_0554 = { en => "English 554", es => "Español 554" },
+    key_0555 = { en => "English 555", es => "Español 555" },
+    key_0556 = { en => "English 556", es => "Español 556" },
+    key_0557 = { en => "English 557", es => "Español 557" },
+    key_0558 = { en => "English 558", es => "Español 558" },
+    key_0559 = { en => "English 559", es => "Español 559" },
+    key_0560 = { en => "English 560", es => "Español 560" },
+    key_0561 = { en => "English 561", es => "Español 561" },
+    key_0562 = { en => "English 562", es => "Español 562" },
+    key_0563 = { en => "English 563", es => "Español 563" },
+    key_0564 = { en => "English 564", es => "Español 564" },
+    key_0565 = { en => "English 565", es => "Español 565" },
+    key_0566 = { en => "English 566", es => "Español 566" },
+    key_0567 = { en => "English 567", es => "Español 567" },
+    key_0568 = { en => "English 568", es => "Español 568" },
+    key_0569 = { en => "English 569", es => "Español 569" },
+    key_0570 = { en => "English 570", es => "Español 570" },
+    key_0571 = { en => "English 571", es => "Español 571" },
+    key_0572 = { en => "English 572", es => "Español 572" },
+    key_0573 = { en => "English 573", es => "Español 573" },
+    key_0574 = { en => "English 574", es => "Español 574" },
+    key_0575 = { en => "English 575", es => "Español 575" },
+    key_0576 = { en => "English 576", es => "Español 576" },
+    key_0577 = { en => "English 577", es => "Español 577" },
+    key_0578 = { en => "English 578", es => "Español 578" },
+    key_0579 = { en => "English 579", es => "Español 579" },
+    key_0580 = { en => "English 580", es => "Español 580" },
+    key_0581 = { en => "English 581", es => "Español 581" },
+    key_0582 = { en => "English 582", es => "Español 582" },
+    key_0583 = { en => "English 583", es => "Español 583" },
+    key_0584 = { en => "English 584", es => "Español 584" },
+    key_0585 = { en => "English 585", es => "Español 585" },
+    key_0586 = { en => "English 586", es => "Español 586" },
+    key_0587 = { en => "English 587", es => "Español 587" },
+    key_0588 = { en => "English 588", es => "Español 588" },
+    key_0589 = { en => "English 589", es => "Español 589" },
+    key_0590 = { en => "English 590", es => "Español 590" },
+    key_0591 = { en => "English 591", es => "Español 591" },
+    key_0592 = { en => "English 592", es => "Español 592" },
+    key_0593 = { en => "English 593", es => "Español 593" },
+    key_0594 = { en => "English 594", es => "Español 594" },
+    key_0595 = { en => "English 595", es => "Español 595" },
+    key_0596 = { en => "English 596", es => "Español 596" },
+    key_0597 = { en => "English 597", es => "Español 597" },
+    key_0598 = { en => "English 598", es => "Español 598" },
+    key_0599 = { en => "English 599", es => "Español 599" },
+    key_0600 = { en => "English 600", es => "Español 600" },
+    key_0601 = { en => "English 601", es => "Español 601" },
+    key_0602 = { en => "English 602", es => "Español 602" },
+    key_0603 = { en => "English 603", es => "Español 603" },
+    key_0604 = { en => "English 604", es => "Español 604" },
+    key_0605 = { en => "English 605", es => "Español 605" },
+    key_0606 = { en => "English 606", es => "Español 606" },
+    key_0607 = { en => "English 607", es => "Español 607" },
+    key_0608 = { en => "English 608", es => "Español 608" },
+    key_0609 = { en => "English 609", es => "Español 609" },
+    key_0610 = { en => "English 610", es => "Español 610" },
+    key_0611 = { en => "English 611", es => "Español 611" },
+    key_0612 = { en => "English 612", es => "Español 612" },
+    key_0613 = { en => "English 613", es => "Español 613" },
+    key_0614 = { en => "English 614", es => "Español 614" },
+    key_0615 = { en => "English 615", es => "Español 615" },
+    key_0616 = { en => "English 616", es => "Español 616" },
+    key_0617 = { en => "English 617", es => "Español 617" },
+    key_0618 = { en => "English 618", es => "Español 618" },
+    key_0619 = { en => "English 619", es => "Español 619" },
+    key_0620 = { en => "English 620", es => "Español 620" },
+    key_0621 = { en => "English 621", es => "Español 621" },
+    key_0622 = { en => "English 622", es => "Español 622" },
+    key_0623 = { en => "English 623", es => "Español 623" },
+    key_0624 = { en => "English 624", es => "Español 624" },
+    key_0625 = { en => "English 625", es => "Español 625" },
+    key_0626 = { en => "English 626", es => "Español 626" },
+    key_0627 = { en => "English 627", es => "Español 627" },
+    key_0628 = { en => "English 628", es => "Español 628" },
+    key_0629 = { en => "English 629", es => "Español 629" },
+    key_0630 = { en => "English 630", es => "Español 630" },
+    key_0631 = { en => "English 631", es => "Español 631" },
+    key_0632 = { en => "English 632", es => "Español 632" },
+    key_0633 = { en => "English 633", es => "Español 633" },
+    key_0634 = { en => "English 634", es => "Español 634" },
+    key_0635 = { en => "English 635", es => "Español 635" },
+    key_0636 = { en => "English 636", es => "Español 636" },
+    key_0637 = { en => "English 637", es => "Español 637" },
+    key_0638 = { en => "English 638", es => "Español 638" },
+    key_0639 = { en => "English 639", es => "Español 639" },
+    key_0640 = { en => "English 640", es => "Español 640" },
+    key_0641 = { en => "English 641", es => "Español 641" },
+    key_0642 = { en => "English 642", es => "Español 642" },
+    key_0643 = { en => "English 643", es => "Español 643" },
+    key_0644 = { en => "English 644", es => "Español 644" },
+    key_0645 = { en => "English 645", es => "Español 645" },
+    key_0646 = { en => "English 646", es => "Español 646" },
+    key_0647 = { en => "English 647", es => "Español 647" },
+    key_0648 = { en => "English 648", es => "Español 648" },
+    key_0649 = { en => "English 649", es => "Español 649" },
+    key_0650 = { en => "English 650", es => "Español 650" },
+    key_0651 = { en => "English 651", es => "Español 651" },
+    key_0652 = { en => "English 652", es => "Español 652" },
+    key_0653 = { en => "English 653", es => "Español 653" },
+    key_0654 = { en => "English 654", es => "Español 654" },
+    key_0655 = { en => "English 655", es => "Español 655" },
+    key_0656 = { en => "English 656", es => "Español 656" },
+    key_0657 = { en => "English 657", es => "Español 657" },
+    key_0658 = { en => "English 658", es => "Español 658" },
+    key_0659 = { en => "English 659", es => "Español 659" },
+    key_0660 = { en => "English 660", es => "Español 660" },
+    key_0661 = { en => "English 661", es => "Español 661" },
+    key_0662 = { en => "English 662", es => "Español 662" },
+    key_0663 = { en => "English 663", es => "Español 663" },
+    key_0664 = { en => "English 664", es => "Español 664" },
+    key_0665 = { en => "English 665", es => "Español 665" },
+    key_0666 = { en => "English 666", es => "Español 666" },
+    key_0667 = { en => "English 667", es => "Español 667" },
+    key_0668 = { en => "English 668", es => "Español 668" },
+    key_0669 = { en => "English 669", es => "Español 669" },
+    key_0670 = { en => "English 670", es => "Español 670" },
+    key_0671 = { en => "English 671", es => "Español 671" },
+    key_0672 = { en => "English 672", es => "Español 672" },
+    key_0673 = { en => "English 673", es => "Español 673" },
+    key_0674 = { en => "English 674", es => "Español 674" },
+    key_0675 = { en => "English 675", es => "Español 675" },
+    key_0676 = { en => "English 676", es => "Español 676" },
+    key_0677 = { en => "English 677", es => "Español 677" },
+    key_0678 = { en => "English 678", es => "Español 678" },
+    key_0679 = { en => "English 679", es => "Español 679" },
+    key_0680 = { en => "English 680", es => "Español 680" },
+    key_0681 = { en => "English 681", es => "Español 681" },
+    key_0682 = { en => "English 682", es => "Español 682" },
+    key_0683 = { en => "English 683", es => "Español 683" },
+    key_0684 = { en => "English 684", es => "Español 684" },
+    key_0685 = { en => "English 685", es => "Español 685" },
+    key_0686 = { en => "English 686", es => "Español 686" },
+    key_0687 = { en => "English 687", es => "Español 687" },
+    key_0688 = { en => "English 688", es => "Español 688" },
+    key_0689 = { en => "English 689", es => "Español 689" },
+    key_0690 = { en => "English 690", es => "Español 690" },
+    key_0691 = { en => "English 691", es => "Español 691" },
+    key_0692 = { en => "English 692", es => "Español 692" },
+    key_0693 = { en => "English 693", es => "Español 693" },
+    key_0694 = { en => "English 694", es => "Español 694" },
+    key_0695 = { en => "English 695", es => "Español 695" },
+    key_0696 = { en => "English 696", es => "Español 696" },
+    key_0697 = { en => "English 697", es => "Español 697" },
+    key_0698 = { en => "English 698", es => "Español 698" },
+    key_0699 = { en => "English 699", es => "Español 699" },
+    key_0700 = { en => "English 700", es => "Español 700" },
+    key_0701 = { en => "English 701", es => "Español 701" },
+    key_0702 = { en => "English 702", es => "Español 702" },
+    key_0703 = { en => "English 703", es => "Español 703" },
+    key_0704 = { en => "English 704", es => "Español 704" },
+    key_0705 = { en => "English 705", es => "Español 705" },
+    key_0706 = { en => "English 706", es => "Español 706" },
+    key_0707 = { en => "English 707", es => "Español 707" },
+    key_0708 = { en => "English 708", es => "Español 708" },
+    key_0709 = { en => "English 709", es => "Español 709" },
+    key_0710 = { en => "English 710", es => "Español 710" },
+    key_0711 = { en => "English 711", es => "Español 711" },
+    key_0712 = { en => "English 712", es => "Español 712" },
+    key_0713 = { en => "English 713", es => "Español 713" },
+    key_0714 = { en => "English 714", es => "Español 714" },
+    key_0715 = { en => "English 715", es => "Español 715" },
+    key_0716 = { en => "English 716", es => "Español 716" },
+    key_0717 = { en => "English 717", es => "Español 717" },
+    key_0718 = { en => "English 718", es => "Español 718" },
+    key_0719 = { en => "English 719", es => "Español 719" },
+    key_0720 = { en => "English 720", es => "Español 720" },
+    key_0721 = { en => "English 721", es => "Español 721" },
+    key_0722 = { en => "English 722", es => "Español 722" },
+    key_0723 = { en => "English 723", es => "Español 723" },
+    key_0724 = { en => "English 724", es => "Español 724" },
+    key_0725 = { en => "English 725", es => "Español 725" },
+    key_0726 = { en => "English 726", es => "Español 726" },
+    key_0727 = { en => "English 727", es => "Español 727" },
+    key_0728 = { en => "English 728", es => "Español 728" },
+    key_0729 = { en => "English 729", es => "Español 729" },
+    key_0730 = { en => "English 730", es => "Español 730" },
+    key_0731 = { en => "English 731", es => "Español 731" },
+    key_0732 = { en => "English 732", es => "Español 732" },
+    key_0733 = { en => "English 733", es => "Español 733" },
+    key_0734 = { en => "English 734", es => "Español 734" },
+    key_0735 = { en => "English 735", es => "Español 735" },
+    key_0736 = { en => "English 736", es => "Español 736" },
+    key_0737 = { en => "English 737", es => "Español 737" },
+    key_0738 = { en => "English 738", es => "Español 738" },
+    key_0739 = { en => "English 739", es => "Español 739" },
+    key_0740 = { en => "English 740", es => "Español 740" },
+    key_0741 = { en => "English 741", es => "Español 741" },
+    key_0742 = { en => "English 742", es => "Español 742" },
+    key_0743 = { en => "English 743", es => "Español 743" },
+    key_0744 = { en => "English 744", es => "Español 744" },
+    key_0745 = { en => "English 745", es => "Español 745" },
+    key_0746 = { en => "English 746", es => "Español 746" },
+    key_0747 = { en => "English 747", es => "Español 747" },
+    key_0748 = { en => "English 748", es => "Español 748" },
+    key_0749 = { en => "English 749", es => "Español 749" },
+    key_0750 = { en => "English 750", es => "Español 750" },
+    key_0751 = { en => "English 751", es => "Español 751" },
+    key_0752 = { en => "English 752", es => "Español 752" },
+    key_0753 = { en => "English 753", es => "Español 753" },
+    key_0754 = { en => "English 754", es => "Español 754" },
+    key_0755 = { en => "English 755", es => "Español 755" },
+    key_0756 = { en => "English 756", es => "Español 756" },
+    key_0757 = { en => "English 757", es => "Español 757" },
+    key_0758 = { en => "English 758", es => "Español 758" },
+    key_0759 = { en => "English 759", es => "Español 759" },
+    key_0760 = { en => "English 760", es => "Español 760" },
+    key_0761 = { en => "English 761", es => "Español 761" },
+    key_0762 = { en => "English 762", es => "Español 762" },
+    key_0763 = { en => "English 763", es => "Español 763" },
+    key_0764 = { en => "English 764", es => "Español 764" },
+    key_0765 = { en => "English 765", es => "Español 765" },
+    key_0766 = { en => "English 766", es => "Español 766" },
+    key_0767 = { en => "English 767", es => "Español 767" },
+    key_0768 = { en => "English 768", es => "Español 768" },
+    key_0769 = { en => "English 769", es => "Español 769" },
+    key_0770 = { en => "English 770", es => "Español 770" },
+    key_0771 = { en => "English 771", es => "Español 771" },
+    key_0772 = { en => "English 772", es => "Español 772" },
+    key_0773 = { en => "English 773", es => "Español 773" },
+    key_0774 = { en => "English 774", es => "Español 774" },
+    key_0775 = { en => "English 775", es => "Español 775" },
+    key_0776 = { en => "English 776", es => "Español 776" },
+    key_0777 = { en => "English 777", es => "Español 777" },
+    key_0778 = { en => "English 778", es => "Español 778" },
+    key_0779 = { en => "English 779", es => "Español 779" },
+    key_0780 = { en => "English 780", es => "Español 780" },
+    key_0781 = { en => "English 781", es => "Español 781" },
+    key_0782 = { en => "English 782", es => "Español 782" },
+    key_0783 = { en => "English 783", es => "Español 783" },
+    key_0784 = { en => "English 784", es => "Español 784" },
+    key_0785 = { en => "English 785", es => "Español 785" },
+    key_0786 = { en => "English 786", es => "Español 786" },
+    key_0787 = { en => "English 787", es => "Español 787" },
+    key_0788 = { en => "English 788", es => "Español 788" },
+    key_0789 = { en => "English 789", es => "Español 789" },
+    key_0790 = { en => "English 790", es => "Español 790" },
+    key_0791 = { en => "English 791", es => "Español 791" },
+    key_0792 = { en => "English 792", es => "Español 792" },
+    key_0793 = { en => "English 793", es => "Español 793" },
+    key_0794 = { en => "English 794", es => "Español 794" },
+    key_0795 = { en => "English 795", es => "Español 795" },
+    key_0796 = { en => "English 796", es => "Español 796" },
+    key_0797 = { en => "English 797", es => "Español 797" },
+    key_0798 = { en => "English 798", es => "Español 798" },
+    key_0799 = { en => "English 799", es => "Español 799" },
+    key_0800 = { en => "English 800", es => "Español 800" },
+    key_0801 = { en => "English 801", es => "Español 801" },
+    key_0802 = { en => "English 802", es => "Español 802" },
+    key_0803 = { en => "English 803", es => "Español 803" },
+    key_0804 = { en => "English 804", es => "Español 804" },
+    key_0805 = { en => "English 805", es => "Español 805" },
+    key_0806 = { en => "English 806", es => "Español 806" },
+    key_0807 = { en => "English 807", es => "Español 807" },
+    key_0808 = { en => "English 808", es => "Español 808" },
+    key_0809 = { en => "English 809", es => "Español 809" },
+    key_0810 = { en => "English 810", es => "Español 810" },
+    key_0811 = { en => "English 811", es => "Español 811" },
+    key_0812 = { en => "English 812", es => "Español 812" },
+    key_0813 = { en => "English 813", es => "Español 813" },
+    key_0814 = { en => "English 814", es => "Español 814" },
+    key_0815 = { en => "English 815", es => "Español 815" },
+    key_0816 = { en => "English 816", es => "Español 816" },
+    key_0817 = { en => "English 817", es => "Español 817" },
+    key_0818 = { en => "English 818", es => "Español 818" },
+    key_0819 = { en => "English 819", es => "Español 819" },
+    key_0820 = { en => "English 820", es => "Español 820" },
+    key_0821 = { en => "English 821", es => "Español 821" },
+    key_0822 = { en => "English 822", es => "Español 822" },
+    key_0823 = { en => "English 823", es => "Español 823" },
+    key_0824 = { en => "English 824", es => "Español 824" },
+    key_0825 = { en => "English 825", es => "Español 825" },
+    key_0826 = { en => "English 826", es => "Español 826" },
+    key_0827 = { en => "English 827", es => "Español 827" },
+    key_0828 = { en => "English 828", es => "Español 828" },
+    key_0829 = { en => "English 829", es => "Español 829" },
+    key_0830 = { en => "English 830", es => "Español 830" },
+    key_0831 = { en => "English 831", es => "Español 831" },
+    key_0832 = { en => "English 832", es => "Español 832" },
+    key_0833 = { en => "English 833", es => "Español 833" },
+    key_0834 = { en => "English 834", es => "Español 834" },
+    key_0835 = { en => "English 835", es => "Español 835" },
+    key_0836 = { en => "English 836", es => "Español 836" },
+    key_0837 = { en => "English 837", es => "Español 837" },
+    key_0838 = { en => "English 838", es => "Español 838" },
+    key_0839 = { en => "English 839", es => "Español 839" },
+    key_0840 = { en => "English 840", es => "Español 840" },
+    key_0841 = { en => "English 841", es => "Español 841" },
+    key_0842 = { en => "English 842", es => "Español 842" },
+    key_0843 = { en => "English 843", es => "Español 843" },
+    key_0844 = { en => "English 844", es => "Español 844" },
+    key_0845 = { en => "English 845", es => "Español 845" },
+    key_0846 = { en => "English 846", es => "Español 846" },
+    key_0847 = { en => "English 847", es => "Español 847" },
+    key_0848 = { en => "English 848", es => "Español 848" },
+    key_0849 = { en => "English 849", es => "Español 849" },
+    key_0850 = { en => "English 850", es => "Español 850" },
+    key_0851 = { en => "English 851", es => "Español 851" },
+    key_0852 = { en => "English 852", es => "Español 852" },
+    key_0853 = { en => "English 853", es => "Español 853" },
+    key_0854 = { en => "English 854", es => "Español 854" },
+    key_0855 = { en => "English 855", es => "Español 855" },
+    key_0856 = { en => "English 856", es => "Español 856" },
+    key_0857 = { en => "English 857", es => "Español 857" },
+    key_0858 = { en => "English 858", es => "Español 858" },
+    key_0859 = { en => "English 859", es => "Español 859" },
+    key_0860 = { en => "English 860", es => "Español 860" },
+    key_0861 = { en => "English 861", es => "Español 861" },
+    key_0862 = { en => "English 862", es => "Español 862" },
+    key_0863 = { en => "English 863", es => "Español 863" },
+    key_0864 = { en => "English 864", es => "Español 864" },
+    key_0865 = { en => "English 865", es => "Español 865" },
+    key_0866 = { en => "English 866", es => "Español 866" },
+    key_0867 = { en => "English 867", es => "Español 867" },
+    key_0868 = { en => "English 868", es => "Español 868" },
+    key_0869 = { en => "English 869", es => "Español 869" },
+    key_0870 = { en => "English 870", es => "Español 870" },
+    key_0871 = { en => "English 871", es => "Español 871" },
+    key_0872 = { en => "English 872", es => "Español 872" },
+    key_0873 = { en => "English 873", es => "Español 873" },
+    key_0874 = { en => "English 874", es => "Español 874" },
+    key_0875 = { en => "English 875", es => "Español 875" },
+    key_0876 = { en => "English 876", es => "Español 876" },
+    key_0877 = { en => "English 877", es => "Español 877" },
+    key_0878 = { en => "English 878", es => "Español 878" },
+    key_0879 = { en => "English 879", es => "Español 879" },
+    key_0880 = { en => "English 880", es => "Español 880" },
+    key_0881 = { en => "English 881", es => "Español 881" },
+    key_0882 = { en => "English 882", es => "Español 882" },
+    key_0883 = { en => "English 883", es => "Español 883" },
+    key_0884 = { en => "English 884", es => "Español 884" },
+    key_0885 = { en => "English 885", es => "Español 885" },
+    key_0886 = { en => "English 886", es => "Español 886" },
+    key_0887 = { en => "English 887", es => "Español 887" },
+    key_0888 = { en => "English 888", es => "Español 888" },
+    key_0889 = { en => "English 889", es => "Español 889" },
+    key_0890 = { en => "English 890", es => "Español 890" },
+    key_0891 = { en => "English 891", es => "Español 891" },
+    key_0892 = { en => "English 892", es => "Español 892" },
+    key_0893 = { en => "English 893", es => "Español 893" },
+    key_0894 = { en => "English 894", es => "Español 894" },
+    key_0895 = { en => "English 895", es => "Español 895" },
+    key_0896 = { en => "English 896", es => "Español 896" },
+    key_0897 = { en => "English 897", es => "Español 897" },
+    key_0898 = { en => "English 898", es => "Español 898" },
+    key_0899 = { en => "English 899", es => "Español 899" },
+    key_0900 = { en => "English 900", es => "Español 900" },
+    key_0901 = { en => "English 901", es => "Español 901" },
+    key_0902 = { en => "English 902", es => "Español 902" },
+    key_0903 = { en => "English 903", es => "Español 903" },
+    key_0904 = { en => "English 904", es => "Español 904" },
+    key_0905 = { en => "English 905", es => "Español 905" },
+    key_0906 = { en => "English 906", es => "Español 906" },
+    key_0907 = { en => "English 907", es => "Español 907" },
+    key_0908 = { en => "English 908", es => "Español 908" },
+    key_0909 = { en => "English 909", es => "Español 909" },
+    key_0910 = { en => "English 910", es => "Español 910" },
+    key_0911 = { en => "English 911", es => "Español 911" },
+    key_0912 = { en => "English 912", es => "Español 912" },
+    key_0913 = { en => "English 913", es => "Español 913" },
+    key_0914 = { en => "English 914", es => "Español 914" },
+    key_0915 = { en => "English 915", es => "Español 915" },
+    key_0916 = { en => "English 916", es => "Español 916" },
+    key_0917 = { en => "English 917", es => "Español 917" },
+    key_0918 = { en => "English 918", es => "Español 918" },
+    key_0919 = { en => "English 919", es => "Español 919" },
+    key_0920 = { en => "English 920", es => "Español 920" },
+    key_0921 = { en => "English 921", es => "Español 921" },
+    key_0922 = { en => "English 922", es => "Español 922" },
+    key_0923 = { en => "English 923", es => "Español 923" },
+    key_0924 = { en => "English 924", es => "Español 924" },
+    key_0925 = { en => "English 925", es => "Español 925" },
+    key_0926 = { en => "English 926", es => "Español 926" },
+    key_0927 = { en => "English 927", es => "Español 927" },
+    key_0928 = { en => "English 928", es => "Español 928" },
+    key_0929 = { en => "English 929", es => "Español 929" },
+    key_0930 = { en => "English 930", es => "Español 930" },
+    key_0931 = { en => "English 931", es => "Español 931" },
+    key_0932 = { en => "English 932", es => "Español 932" },
+    key_0933 = { en => "English 933", es => "Español 933" },
+    key_0934 = { en => "English 934", es => "Español 934" },
+    key_0935 = { en => "English 935", es => "Español 935" },
+    key_0936 = { en => "English 936", es => "Español 936" },
+    key_0937 = { en => "English 937", es => "Español 937" },
+    key_0938 = { en => "English 938", es => "Español 938" },
+    key_0939 = { en => "English 939", es => "Español 939" },
+    key_0940 = { en => "English 940", es => "Español 940" },
+    key_0941 = { en => "English 941", es => "Español 941" },
+    key_0942 = { en => "English 942", es => "Español 942" },
+    key_0943 = { en => "English 943", es => "Español 943" },
+    key_0944 = { en => "English 944", es => "Español 944" },
+    key_0945 = { en => "English 945", es => "Español 945" },
+    key_0946 = { en => "English 946", es => "Español 946" },
+    key_0947 = { en => "English 947", es => "Español 947" },
+    key_0948 = { en => "English 948", es => "Español 948" },
+    key_0949 = { en => "English 949", es => "Español 949" },
+    key_0950 = { en => "English 950", es => "Español 950" },
+    key_0951 = { en => "English 951", es => "Español 951" },
+    key_0952 = { en => "English 952", es => "Español 952" },
+    key_0953 = { en => "English 953", es => "Español 953" },
+    key_0954 = { en => "English 954", es => "Español 954" },
+    key_0955 = { en => "English 955", es => "Español 955" },
+    key_0956 = { en => "English 956", es => "Español 956" },
+    key_0957 = { en => "English 957", es => "Español 957" },
+    key_0958 = { en => "English 958", es => "Español 958" },
+    key_0959 = { en => "English 959", es => "Español 959" },
+    key_0960 = { en => "English 960", es => "Español 960" },
+    key_0961 = { en => "English 961", es => "Español 961" },
+    key_0962 = { en => "English 962", es => "Español 962" },
+    key_0963 = { en => "English 963", es => "Español 963" },
+    key_0964 = { en => "English 964", es => "Español 964" },
+    key_0965 = { en => "English 965", es => "Español 965" },
+    key_0966 = { en => "English 966", es => "Español 966" },
+    key_0967 = { en => "English 967", es => "Español 967" },
+    key_0968 = { en => "English 968", es => "Español 968" },
+    key_0969 = { en => "English 969", es => "Español 969" },
+    key_0970 = { en => "English 970", es => "Español 970" },
+    key_0971 = { en => "English 971", es => "Español 971" },
+    key_0972 = { en => "English 972", es => "Español 972" },
+    key_0973 = { en => "English 973", es => "Español 973" },
+    key_0974 = { en => "English 974", es => "Español 974" },
+    key_0975 = { en => "English 975", es => "Español 975" },
+    key_0976 = { en => "English 976", es => "Español 976" },
+    key_0977 = { en => "English 977", es => "Español 977" },
+    key_0978 = { en => "English 978", es => "Español 978" },
+    key_0979 = { en => "English 979", es => "Español 979" },
+    key_0980 = { en => "English 980", es => "Español 980" },
+    key_0981 = { en => "English 981", es => "Español 981" },
+    key_0982 = { en => "English 982", es => "Español 982" },
+    key_0983 = { en => "English 983", es => "Español 983" },
+    key_0984 = { en => "English 984", es => "Español 984" },
+    key_0985 = { en => "English 985", es => "Español 985" },
+    key_0986 = { en => "English 986", es => "Español 986" },
+    key_0987 = { en => "English 987", es => "Español 987" },
+    key_0988 = { en => "English 988", es => "Español 988" },
+    key_0989 = { en => "English 989", es => "Español 989" },
+    key_0990 = { en => "English 990", es => "Español 990" },
+    key_0991 = { en => "English 991", es => "Español 991" },
+    key_0992 = { en => "English 992", es => "Español 992" },
+    key_0993 = { en => "English 993", es => "Español 993" },
+    key_0994 = { en => "English 994", es => "Español 994" },
+    key_0995 = { en => "English 995", es => "Español 995" },
+    key_0996 = { en => "English 996", es => "Español 996" },
+    key_0997 = { en => "English 997", es => "Español 997" },
+    key_0998 = { en => "English 998", es => "Español 998" },
+    key_0999 = { en => "English 999", es => "Español 999" },
+}}
+
+localization_table! {SmallTable = LDSL {
+    open = { en => "Open", es => "Abrir", fr => "Ouvrir" },
+    close = { en => "Close", es => "Cerrar", fr => "Fermer" },
+    save = { en => "Save", es => "Guardar", fr => "Enregistrer" },
+    save_as = { en => "Save as", es => "Guardar como", fr => "Enregistrer sous" },
+    undo = { en => "Undo", es => "Deshacer", fr => "Annuler" },
+    redo = { en => "Redo", es => "Rehacer", fr => "Rétablir" },
+    cut = { en => "Cut", es => "Cortar", fr => "Couper" },
+    paste = { en => "Paste", es => "Pegar", fr => "Coller" },
+}}
+
+localization_table! {SmallScannedTable = LDSL #[match_threshold(0)] {
+    open = { en => "Open", es => "Abrir", fr => "Ouvrir" },
+    close = { en => "Close", es => "Cerrar", fr => "Fermer" },
+    save = { en => "Save", es => "Guardar", fr => "Enregistrer" },
+    save_as = { en => "Save as", es => "Guardar como", fr => "Enregistrer sous" },
+    undo = { en => "Undo", es => "Deshacer", fr => "Annuler" },
+    redo = { en => "Redo", es => "Rehacer", fr => "Rétablir" },
+    cut = { en => "Cut", es => "Cortar", fr => "Couper" },
+    paste = { en => "Paste", es => "Pegar", fr => "Coller" },
+}}
+
+const ITERATIONS: usize = 200;
+
+/// The number of times the small table's keys are looked up per iteration, so that its timings are
+/// about as long as the large table's
+const SMALL_REPEATS: usize = 125;
+
+/// Time `ITERATIONS` runs of `lookup_all`, which does `lookups_per_run` lookups
+fn measure(name: &str, lookups_per_run: usize, lookup_all: impl Fn()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        lookup_all();
+    }
+    let elapsed = start.elapsed();
+    let lookups = ITERATIONS * lookups_per_run;
+    #[allow(clippy::cast_precision_loss)]
+    let per_lookup = elapsed.as_nanos() as f64 / lookups as f64;
+    println!("{name:>8}: {elapsed:?} for {lookups} lookups ({per_lookup:.1} ns/lookup)");
+    elapsed
+}
+
+fn bench(name: &str, lookup: impl Fn(&str, &str) -> &'static str) -> Duration {
+    let keys = LargeTable::TABLE.translation_keys;
+    measure(name, keys.len(), || {
+        for key in keys {
+            black_box(lookup(black_box(key), black_box("es")));
+        }
+    })
+}
+
+fn bench_small(name: &str, lookup: impl Fn(&str, &str) -> &'static str) -> Duration {
+    let keys = SmallTable::TABLE.translation_keys;
+    measure(name, keys.len() * SMALL_REPEATS, || {
+        for _ in 0..SMALL_REPEATS {
+            for key in keys {
+                black_box(lookup(black_box(key), black_box("es")));
+            }
+        }
+    })
+}
+
+fn main() {
+    let linear = bench("linear", |key, locale| {
+        LargeTable::TABLE.localize(key, locale)
+    });
+    let hashed = bench("hashed", LargeTable::localize);
+    #[allow(clippy::cast_precision_loss)]
+    let speedup = linear.as_nanos() as f64 / hashed.as_nanos() as f64;
+    println!(" speedup: {speedup:.1}x");
+
+    // resolve the locale and every key once, like a loop that reuses them would
+    let spanish = LargeTable::get_locale("es");
+    let key_indices: Vec<usize> = LargeTable::TABLE
+        .translation_keys
+        .iter()
+        .filter_map(|key| spanish.key_index(key))
+        .collect();
+    measure("indexed", key_indices.len(), || {
+        for &key_idx in &key_indices {
+            black_box(spanish.localize_at(black_box(key_idx)));
+        }
+    });
+
+    let scanned = bench_small("scanned", SmallScannedTable::localize);
+    let matched = bench_small("matched", SmallTable::localize);
+    #[allow(clippy::cast_precision_loss)]
+    let speedup = scanned.as_nanos() as f64 / matched.as_nanos() as f64;
+    println!(" speedup: {speedup:.1}x");
+}
@@ -0,0 +1,30 @@
+//! A `#![no_std]` library using a localization table, to make sure the generated code and the
+//! allocation-free parts of `localize` don't depend on `std`.
+//!
+//! Build it against the core crate with `cargo build --example no_std --no-default-features`.
+#![no_std]
+
+use localize::localization_table;
+
+localization_table! {Firmware = LDSL {
+    "booting" = {
+        en => "Booting...",
+        de => "Startet..."
+    },
+    "ready" = {
+        en => "Ready",
+        de => "Bereit"
+    }
+}}
+
+/// Get the status line to show on the display
+#[must_use]
+pub fn status(ready: bool, locale: &str) -> &'static str {
+    Firmware::localize(if ready { "ready" } else { "booting" }, locale)
+}
+
+/// Get the status line from a locale handle, as the firmware would after reading its settings
+#[must_use]
+pub fn status_in(locale: &str) -> &'static str {
+    Firmware::get_locale(locale).localize("ready")
+}
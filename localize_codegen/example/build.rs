@@ -0,0 +1,9 @@
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=strings.ldsl");
+    let input = fs::read_to_string("strings.ldsl").unwrap();
+    let source = localize_codegen::generate(&input).unwrap_or_else(|err| panic!("{err}"));
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("strings.rs"), source).unwrap();
+}
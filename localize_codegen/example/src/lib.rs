@@ -0,0 +1,3 @@
+//! A table generated by `build.rs` with `localize_codegen` instead of the `localization_table!` macro.
+
+include!(concat!(env!("OUT_DIR"), "/strings.rs"));
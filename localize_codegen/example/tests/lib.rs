@@ -0,0 +1,15 @@
+use localize_codegen_example::{Strings, StringsKey};
+
+/// Make sure the table generated by the build script works like one from the macro
+#[test]
+fn test_generated_table() {
+    assert_eq!(Strings::localize("farewell", "es"), "Adiós");
+    assert_eq!(Strings::localize("farewell", "de"), "Goodbye");
+    assert_eq!(Strings::localize("welcome", "en"), "<Missing>");
+    assert_eq!(
+        Strings::TABLE.localize_cow("greeting", "es", &[("name", "Ferris")]),
+        "¡Hola, Ferris!"
+    );
+    assert_eq!(Strings::localize_key(StringsKey::Farewell, "en"), "Goodbye");
+    assert_eq!(Strings::TABLE.locales, ["en", "es"]);
+}
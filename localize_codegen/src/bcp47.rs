@@ -0,0 +1,95 @@
+//! A syntax check for BCP 47 language tags, for the `#[validate_locales]` attribute.
+//!
+//! Only the shape of the tag is checked; subtags aren't looked up in the IANA registry.
+
+/// Check that `tag` is a well-formed BCP 47 language tag, like `en`, `en-US`, `zh-Hant`, or `es-419`.
+///
+/// Subtags must be separated by `-`. The language is followed by optional extended language, script,
+/// region, and variant subtags, then by extensions (like `-u-ca-buddhist`) and private use subtags (like
+/// `-x-pirate`). A tag can also consist of private use subtags only, like `x-klingon`. Grandfathered tags
+/// aren't supported.
+pub fn validate(tag: &str) -> Result<(), String> {
+    if tag.split('-').any(str::is_empty) {
+        return Err("found an empty subtag".to_owned());
+    }
+    let mut subtags = tag.split('-').peekable();
+    let language = subtags.next().unwrap_or_default();
+    if language.eq_ignore_ascii_case("x") {
+        return private_use(subtags);
+    }
+    if !(is_alpha(language) && matches!(language.len(), 2..=3 | 5..=8)) {
+        return Err(format!(
+            "`{language}` isn't a language subtag of 2-3 or 5-8 letters"
+        ));
+    }
+    // extended language subtags, like `yue` in `zh-yue`
+    if language.len() <= 3 {
+        for _ in 0..3 {
+            if subtags.next_if(|s| is_alpha(s) && s.len() == 3).is_none() {
+                break;
+            }
+        }
+    }
+    // script, like `Hant`
+    subtags.next_if(|s| is_alpha(s) && s.len() == 4);
+    // region, like `US` or `419`
+    subtags.next_if(|s| (is_alpha(s) && s.len() == 2) || (is_digit(s) && s.len() == 3));
+    // variants, like `1901` or `valencia`
+    while subtags
+        .next_if(|s| {
+            is_alphanumeric(s)
+                && (matches!(s.len(), 5..=8) || (s.len() == 4 && s.as_bytes()[0].is_ascii_digit()))
+        })
+        .is_some()
+    {}
+    while let Some(singleton) = subtags.next() {
+        if singleton.eq_ignore_ascii_case("x") {
+            return private_use(subtags);
+        }
+        if singleton.len() != 1 || !is_alphanumeric(singleton) {
+            return Err(format!("unexpected subtag `{singleton}`"));
+        }
+        let mut extension_len = 0;
+        while subtags
+            .next_if(|s| is_alphanumeric(s) && matches!(s.len(), 2..=8))
+            .is_some()
+        {
+            extension_len += 1;
+        }
+        if extension_len == 0 {
+            return Err(format!(
+                "the extension `{singleton}` must be followed by subtags of 2-8 characters"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check the subtags after an `x` singleton, which must be 1-8 characters long
+fn private_use<'t>(subtags: impl Iterator<Item = &'t str>) -> Result<(), String> {
+    let mut count = 0;
+    for subtag in subtags {
+        if !is_alphanumeric(subtag) || subtag.len() > 8 {
+            return Err(format!(
+                "`{subtag}` isn't a private use subtag of 1-8 characters"
+            ));
+        }
+        count += 1;
+    }
+    if count == 0 {
+        return Err("`x` must be followed by private use subtags".to_owned());
+    }
+    Ok(())
+}
+
+fn is_alpha(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_digit(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_alphanumeric(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
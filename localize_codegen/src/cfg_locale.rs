@@ -0,0 +1,155 @@
+//! Locales that are only part of a table under a `cfg` condition, for the `#[cfg_locale(...)]` attribute.
+
+use crate::{expand_table, Locale, TableOptions, TranslationInput, Translations};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Result, Token,
+};
+
+/// The most different conditions a table can have, since it's generated once for every combination of
+/// them
+const MAX_CONDITIONS: usize = 4;
+
+/// A `locale, condition` pair from the `#[cfg_locale(...)]` attribute, like
+/// `#[cfg_locale(fr, feature = "fr")]`
+#[derive(Clone)]
+pub struct CfgLocale {
+    pub locale: Locale,
+    /// Anything that can go inside `#[cfg(...)]`
+    condition: TokenStream2,
+}
+
+impl Parse for CfgLocale {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let locale: Locale = input.parse()?;
+        let _: Token![,] = crate::parse_expecting(
+            input,
+            format_args!("Expected `,` followed by a `cfg` condition after locale `{locale}`"),
+        )?;
+        if input.is_empty() {
+            return Err(input.error(format!(
+                "Expected a `cfg` condition after locale `{locale}`"
+            )));
+        }
+        let condition = input.parse()?;
+        Ok(Self { locale, condition })
+    }
+}
+
+/// Generate the table once for every combination of the conditions of its `#[cfg_locale(...)]`
+/// attributes, each without the locales whose conditions are false, and put every item behind a `cfg`
+/// for its combination.
+///
+/// The arrays of the table are sized by its locales, so the macro can't generate one table that works
+/// for every configuration, and it can't evaluate the conditions itself.
+pub fn expand_cfg_locales(input: &TranslationInput) -> Result<TokenStream2> {
+    let cfg_locales = &input.options.cfg_locales;
+    // the different conditions, compared by their tokens
+    let mut conditions: Vec<(String, &TokenStream2)> = Vec::new();
+    for CfgLocale { locale, condition } in cfg_locales {
+        if !input.translations.locales.contains(locale) {
+            return Err(syn::Error::new(
+                locale.span,
+                format!("Unknown locale `{locale}` in `cfg_locale`"),
+            ));
+        }
+        if input.options.default_locale.as_ref() == Some(locale) {
+            return Err(syn::Error::new(
+                locale.span,
+                format!("The default locale `{locale}` can't have a `cfg_locale` condition"),
+            ));
+        }
+        let key = condition.to_string();
+        if conditions.iter().all(|(other, _)| *other != key) {
+            if conditions.len() == MAX_CONDITIONS {
+                return Err(syn::Error::new(
+                    locale.span,
+                    format!(
+                        "A table can only have {MAX_CONDITIONS} different `cfg_locale` conditions"
+                    ),
+                ));
+            }
+            conditions.push((key, condition));
+        }
+    }
+    let mut variants = Vec::new();
+    for enabled in 0..1usize << conditions.len() {
+        let is_enabled = |condition: &TokenStream2| {
+            let key = condition.to_string();
+            let idx = conditions.iter().position(|(other, _)| *other == key);
+            idx.is_some_and(|idx| enabled & (1 << idx) != 0)
+        };
+        let disabled: Vec<&Locale> = cfg_locales
+            .iter()
+            .filter(|cfg_locale| !is_enabled(&cfg_locale.condition))
+            .map(|cfg_locale| &cfg_locale.locale)
+            .collect();
+        let predicates = conditions.iter().map(|(_, condition)| {
+            if is_enabled(condition) {
+                quote!(#condition)
+            } else {
+                quote!(not(#condition))
+            }
+        });
+        let cfg = quote!(#[cfg(all(#(#predicates),*))]);
+        let table: syn::File = syn::parse2(expand_table(without_locales(input, &disabled))?)?;
+        let items = table.items;
+        variants.push(quote!(#(#cfg #items)*));
+    }
+    Ok(quote!(#(#variants)*))
+}
+
+/// Copy the input without any of the translations or options of the `disabled` locales
+fn without_locales(input: &TranslationInput, disabled: &[&Locale]) -> TranslationInput {
+    let enabled = |locale: &Locale| !disabled.contains(&locale);
+    let Translations {
+        strings,
+        contexts,
+        locales,
+        descriptions,
+    } = &input.translations;
+    let translations = Translations {
+        strings: strings
+            .iter()
+            .map(|(key, cells)| {
+                let cells = cells
+                    .iter()
+                    .filter(|(locale, _)| enabled(locale))
+                    .map(|(locale, value)| (locale.clone(), value.clone()))
+                    .collect();
+                (key.clone(), cells)
+            })
+            .collect(),
+        contexts: contexts
+            .iter()
+            .filter(|variant| enabled(&variant.locale))
+            .cloned()
+            .collect(),
+        locales: locales
+            .iter()
+            .filter(|locale| enabled(locale))
+            .cloned()
+            .collect(),
+        descriptions: descriptions.clone(),
+    };
+    let mut options: TableOptions = input.options.clone();
+    options.cfg_locales.clear();
+    options.aliases.retain(|alias| enabled(&alias.locale));
+    options.locale_meta.retain(|entry| enabled(&entry.locale));
+    options.display_names.retain(|entry| enabled(&entry.locale));
+    options.locale_notes.retain(|entry| enabled(&entry.locale));
+    options.const_names.retain(|entry| enabled(&entry.locale));
+    options.default_keys.retain(|entry| enabled(&entry.locale));
+    if let Some(expected) = &mut options.expected_locales {
+        expected.retain(|locale| enabled(locale));
+    }
+    TranslationInput {
+        struct_name: input.struct_name.clone(),
+        options,
+        translations,
+        sources: input.sources.clone(),
+        env_vars: input.env_vars.clone(),
+    }
+}
@@ -0,0 +1,102 @@
+//! A small CSV reader for the `CSV` syntax, following RFC 4180.
+
+/// One row of a CSV file
+pub struct Record {
+    /// The line the row starts on, counting from 1
+    pub line: usize,
+    pub cells: Vec<String>,
+}
+
+/// A problem with the CSV syntax of a file
+#[derive(Debug, PartialEq, Eq)]
+pub struct CsvError {
+    /// The line of the malformed row, counting from 1
+    pub line: usize,
+    pub message: &'static str,
+}
+
+/// Split CSV text into rows of cells.
+///
+/// Cells are separated by commas and rows by `\n` or `\r\n`. A cell can be wrapped in double quotes to
+/// include commas, line breaks, and `""` (an escaped double quote). Blank lines are skipped, as is a
+/// leading byte order mark.
+pub fn parse(source: &str) -> Result<Vec<Record>, CsvError> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+    let mut records = Vec::new();
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    // whether the current cell was quoted, and whether we're still inside the quotes
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = source.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    cell.push(ch);
+                }
+                _ => cell.push(ch),
+            }
+            continue;
+        }
+        match ch {
+            '"' if cell.is_empty() && !quoted => {
+                quoted = true;
+                in_quotes = true;
+            }
+            '"' => {
+                return Err(CsvError {
+                    line: record_line,
+                    message: "unexpected `\"` in an unquoted cell",
+                })
+            }
+            ',' => {
+                cells.push(std::mem::take(&mut cell));
+                quoted = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                cells.push(std::mem::take(&mut cell));
+                if cells.len() > 1 || quoted || !cells[0].is_empty() {
+                    records.push(Record {
+                        line: record_line,
+                        cells: std::mem::take(&mut cells),
+                    });
+                }
+                cells.clear();
+                quoted = false;
+                line += 1;
+                record_line = line;
+            }
+            _ if quoted => {
+                return Err(CsvError {
+                    line: record_line,
+                    message: "unexpected text after a closing `\"`",
+                })
+            }
+            _ => cell.push(ch),
+        }
+    }
+    if in_quotes {
+        return Err(CsvError {
+            line: record_line,
+            message: "unterminated quoted cell",
+        });
+    }
+    cells.push(cell);
+    if cells.len() > 1 || quoted || !cells[0].is_empty() {
+        records.push(Record {
+            line: record_line,
+            cells,
+        });
+    }
+    Ok(records)
+}
@@ -0,0 +1,4225 @@
+//! Code generation for the `localization_table!` and `merge_tables!` macros of the `localize` crate.
+//!
+//! The macros themselves live in `localize_macros`, which only forwards their input to
+//! [`expand_tokens`] and [`expand_merge_tokens`]. This crate can also be used on its own to
+//! generate tables from a build script instead of a macro invocation, which keeps very large tables
+//! out of macro expansion and lets the input come from anywhere. [`generate`] takes the same input
+//! as the macro, as a string, and returns the Rust source of the table.
+//!
+//! # Build scripts
+//!
+//! Add `localize_codegen` to the `[build-dependencies]`, write the generated source to `OUT_DIR` in
+//! `build.rs`:
+//!
+//! ```no_run
+//! // build.rs
+//! use std::{env, fs, path::Path};
+//!
+//! fn main() {
+//!     println!("cargo:rerun-if-changed=strings.ldsl");
+//!     let input = fs::read_to_string("strings.ldsl").unwrap();
+//!     let source = localize_codegen::generate(&input).unwrap_or_else(|err| panic!("{err}"));
+//!     let out_dir = env::var_os("OUT_DIR").unwrap();
+//!     fs::write(Path::new(&out_dir).join("strings.rs"), source).unwrap();
+//! }
+//! ```
+//!
+//! where `strings.ldsl` contains everything that would go inside `localization_table!{...}`, like
+//! `Strings = LDSL { ... }`. Then `include!` the generated file wherever the table should be defined,
+//! with `localize` as a normal dependency:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/strings.rs"));
+//!
+//! assert_eq!(Strings::localize("greeting", "es"), "Hola");
+//! ```
+//!
+//! Files named by `LDSL from` and the `CSV`, `PO`, `JSON_DIR`, and `TOML` syntaxes are relative
+//! to `CARGO_MANIFEST_DIR` like they are for the macro, which Cargo also sets for build scripts.
+//! Warnings like those of `#[warn_inconsistent_locales]` are reported where the generated file is
+//! included.
+
+#![warn(clippy::pedantic, clippy::nursery)]
+
+use cfg_locale::CfgLocale;
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::PathBuf,
+};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Ident, LitByteStr, LitInt, LitStr, Path, Result, Token,
+};
+
+mod bcp47;
+mod cfg_locale;
+mod csv;
+mod merge;
+mod po;
+
+struct TranslationInput {
+    struct_name: Ident,
+    options: TableOptions,
+    translations: Translations,
+    /// Files the translations were read from
+    sources: Vec<LitStr>,
+    /// Environment variables that named files the translations were read from
+    env_vars: Vec<LitStr>,
+}
+
+/// The translations of a table, independent of the syntax they were written in
+#[derive(Clone)]
+struct Translations {
+    strings: BTreeMap<String, BTreeMap<Locale, LitStr>>,
+    contexts: Vec<ContextVariant>,
+    /// Every locale, in the order it first appears in the input
+    locales: Vec<Locale>,
+    /// The descriptions of the translation keys that have one, like `greeting = "The top-of-page hello" {...}`
+    descriptions: BTreeMap<String, LitStr>,
+}
+
+/// Add `locale` to the end of `locales` unless it's already there, returning whether it was added
+fn add_locale(locales: &mut Vec<Locale>, locale: &Locale) -> bool {
+    if locales.contains(locale) {
+        return false;
+    }
+    locales.push(locale.clone());
+    true
+}
+
+/// A locale name, like `en`, `en-US`, or `"zh-Hant"`.
+///
+/// Locales are compared by name only, and keep the span they were written at for error messages.
+#[derive(Clone)]
+struct Locale {
+    name: String,
+    span: Span,
+}
+
+impl Locale {
+    /// Check that a locale written as a string is made of letters, digits, `-`, and `_`, and isn't the
+    /// reserved name `_`
+    fn new(name: String, span: Span) -> Result<Self> {
+        if name == "_" {
+            return Err(syn::Error::new(
+                span,
+                "`_` can't be used as a locale, because `\"_\"` is the translation key for default translations",
+            ));
+        }
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(syn::Error::new(span, format!("Invalid locale `{name}`")));
+        }
+        Ok(Self { name, span })
+    }
+
+    /// The name of the generated `LocaleHandle` constant for this locale, like `EN_US` for `en-US`
+    fn const_ident(&self) -> Result<Ident> {
+        let mut name: String = self
+            .name
+            .chars()
+            .flat_map(char::to_uppercase)
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.insert(0, '_');
+        }
+        syn::parse_str::<Ident>(&name)
+            .map(|ident| Ident::new(&ident.to_string(), self.span))
+            .map_err(|_| {
+                syn::Error::new(
+                    self.span,
+                    format!("Locale `{self}` can't be turned into a constant name"),
+                )
+            })
+    }
+
+    /// The name of this locale's variant in the generated locale enum, like `EnUs` for `en-US`
+    fn variant_ident(&self) -> Result<Ident> {
+        let mut name: String = self
+            .name
+            .split(['-', '_'])
+            .flat_map(|segment| {
+                let mut chars = segment.chars();
+                chars
+                    .next()
+                    .into_iter()
+                    .flat_map(char::to_uppercase)
+                    .chain(chars.flat_map(char::to_lowercase))
+            })
+            .collect();
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.insert(0, '_');
+        }
+        syn::parse_str::<Ident>(&name)
+            .map(|ident| Ident::new(&ident.to_string(), self.span))
+            .map_err(|_| {
+                syn::Error::new(
+                    self.span,
+                    format!("Locale `{self}` can't be turned into a variant name"),
+                )
+            })
+    }
+}
+
+impl PartialEq for Locale {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Locale {}
+
+impl std::hash::Hash for Locale {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl PartialOrd for Locale {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Locale {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl ToTokens for Locale {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        LitStr::new(&self.name, self.span).to_tokens(tokens);
+    }
+}
+
+impl Parse for Locale {
+    /// Parse a string literal, or identifiers and numbers joined by dashes like `en-US` and `es-419`
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            let name: LitStr = input.parse()?;
+            return Self::new(name.value(), name.span());
+        }
+        let first = Ident::parse_any(input)?;
+        let mut name = first.unraw().to_string();
+        while input.peek(Token![-]) {
+            let _: Token![-] = input.parse()?;
+            name.push('-');
+            if input.peek(LitInt) {
+                name.push_str(&input.parse::<LitInt>()?.to_string());
+            } else {
+                name.push_str(&Ident::parse_any(input)?.unraw().to_string());
+            }
+        }
+        Self::new(name, first.span())
+    }
+}
+
+/// A translation that only applies in a specific context, like `es(feminine) => "Bienvenida"`
+#[derive(Clone)]
+struct ContextVariant {
+    key: String,
+    locale: Locale,
+    context: Ident,
+    value: LitStr,
+}
+
+impl ContextVariant {
+    /// Build the `ContextVariant` struct for this variant, using `krate` as the path to `localize`
+    fn to_struct(&self, krate: &Path) -> TokenStream2 {
+        let Self {
+            key,
+            locale,
+            context,
+            value,
+        } = self;
+        let context = context.to_string();
+        quote! {
+            #krate::ContextVariant {
+                translation_key: #key,
+                locale: #locale,
+                context: #context,
+                translation: #value,
+            }
+        }
+    }
+}
+
+/// Options set through `#[...]` attributes between the syntax type and the translations
+#[derive(Clone, Default)]
+struct TableOptions {
+    default_locale: Option<Locale>,
+    aliases: Vec<LocaleAlias>,
+    /// The span of the `#[warn_inconsistent_locales]` attribute, if it was given
+    warn_inconsistent_locales: Option<Span>,
+    crate_path: Option<Path>,
+    /// The span of the `#[complete]` attribute, if it was given
+    complete: Option<Span>,
+    locale_meta: Vec<LocaleMetaEntry>,
+    /// The span of the `#[validate_locales]` attribute, if it was given
+    validate_locales: Option<Span>,
+    /// The span of the `#[strings_struct]` attribute, if it was given
+    strings_struct: Option<Span>,
+    /// The locales listed by the `#[locales(...)]` attribute, if it was given
+    expected_locales: Option<Vec<Locale>>,
+    /// The span of the `#[preserve_order]` attribute, if it was given
+    preserve_order: Option<Span>,
+    display_names: Vec<DisplayName>,
+    locale_notes: Vec<LocaleNote>,
+    /// The span of the `#[size_report]` attribute, if it was given
+    size_report: Option<Span>,
+    cfg_locales: Vec<CfgLocale>,
+    /// The number of translation keys from `#[match_threshold(...)]`, if it was given
+    match_threshold: Option<usize>,
+    const_names: Vec<ConstName>,
+    default_keys: Vec<DefaultKey>,
+    /// The string for cells without a translation from `#[placeholder(...)]`, if it was given
+    placeholder: Option<LitStr>,
+    /// The span of the `#[generate_tests]` attribute, if it was given
+    generate_tests: Option<Span>,
+    /// The span of the `#[warn_same_as_key]` attribute, if it was given
+    warn_same_as_key: Option<Span>,
+    /// The translation keys listed by the `#[allow_same_as_key(...)]` attribute, if it was given
+    allow_same_as_key: Option<Vec<LitStr>>,
+}
+
+/// The most translation keys a table can have for `localize` to match on them, without
+/// `#[match_threshold(...)]`
+const DEFAULT_MATCH_THRESHOLD: usize = 16;
+
+/// The translation of cells without a translation or a default, without `#[placeholder(...)]`. This is
+/// the same as `localize::PLACEHOLDER`.
+const DEFAULT_PLACEHOLDER: &str = "<NO TRANSLATION>";
+
+/// An `alias = locale` pair from the `#[alias(...)]` attribute
+#[derive(Clone)]
+struct LocaleAlias {
+    alias: Locale,
+    locale: Locale,
+}
+
+impl ToTokens for LocaleAlias {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Self { alias, locale } = self;
+        tokens.extend(quote! { (#alias, #locale) });
+    }
+}
+
+impl Parse for LocaleAlias {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let alias = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let locale = input.parse()?;
+        Ok(Self { alias, locale })
+    }
+}
+
+/// A `locale = "name"` pair from the `#[display_name(...)]` attribute
+#[derive(Clone)]
+struct DisplayName {
+    locale: Locale,
+    name: LitStr,
+}
+
+impl Parse for DisplayName {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let locale = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let name = input.parse()?;
+        Ok(Self { locale, name })
+    }
+}
+
+/// A `locale = "note"` pair from the `#[locale_note(...)]` attribute
+#[derive(Clone)]
+struct LocaleNote {
+    locale: Locale,
+    note: LitStr,
+}
+
+impl Parse for LocaleNote {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let locale = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let note = input.parse()?;
+        Ok(Self { locale, note })
+    }
+}
+
+/// A `locale = "key"` pair from the `#[default_key(...)]` attribute
+#[derive(Clone)]
+struct DefaultKey {
+    locale: Locale,
+    key: LitStr,
+}
+
+impl Parse for DefaultKey {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let locale = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let key = input.parse()?;
+        Ok(Self { locale, key })
+    }
+}
+
+/// A `locale = NAME` pair from the `#[const_name(...)]` attribute
+#[derive(Clone)]
+struct ConstName {
+    locale: Locale,
+    name: Ident,
+}
+
+impl Parse for ConstName {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let locale = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let name = input.parse()?;
+        Ok(Self { locale, name })
+    }
+}
+
+/// A `locale = { rtl, date_format = "..." }` entry from the `#[locale_meta(...)]` attribute
+#[derive(Clone)]
+struct LocaleMetaEntry {
+    locale: Locale,
+    rtl: bool,
+    date_format: Option<LitStr>,
+}
+
+impl Parse for LocaleMetaEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let locale = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let content;
+        syn::braced!(content in input);
+        let mut entry = Self {
+            locale,
+            rtl: false,
+            date_format: None,
+        };
+        while !content.is_empty() {
+            let name: Ident = content.parse()?;
+            if name == "rtl" {
+                entry.rtl = true;
+            } else if name == "date_format" {
+                let _: Token![=] = content.parse()?;
+                if entry.date_format.replace(content.parse()?).is_some() {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("Duplicate `date_format` for locale `{}`", entry.locale),
+                    ));
+                }
+            } else {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("Unknown locale metadata `{name}`"),
+                ));
+            }
+            if !content.is_empty() {
+                let _: Token![,] = content.parse()?;
+            }
+        }
+        Ok(entry)
+    }
+}
+
+/// Parse an attribute without arguments, like `#[complete]`, by setting `flag` to the span of its name
+fn parse_flag(attr: &Attribute, name: &str, flag: &mut Option<Span>) -> Result<()> {
+    attr.meta.require_path_only()?;
+    if flag.is_some() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            format!("Duplicate `{name}` attribute"),
+        ));
+    }
+    *flag = Some(attr.path().span());
+    Ok(())
+}
+
+/// Parse the comma-separated entries of an attribute like `#[alias(...)]` onto the end of `entries`,
+/// failing with the `duplicate` message for an entry whose locale is already there
+fn parse_entries<T: Parse>(
+    attr: &Attribute,
+    entries: &mut Vec<T>,
+    locale: impl Fn(&T) -> &Locale,
+    duplicate: &str,
+) -> Result<()> {
+    for entry in attr.parse_args_with(Punctuated::<T, Token![,]>::parse_terminated)? {
+        let name = locale(&entry);
+        if entries.iter().any(|other| locale(other) == name) {
+            return Err(syn::Error::new(name.span, format!("{duplicate} `{name}`")));
+        }
+        entries.push(entry);
+    }
+    Ok(())
+}
+
+/// Parse an attribute with arguments that can only be given once, like `#[crate(...)]`, into `value`
+fn parse_once<T>(
+    attr: &Attribute,
+    name: &str,
+    value: &mut Option<T>,
+    parse: impl FnOnce(&Attribute) -> Result<T>,
+) -> Result<()> {
+    if value.is_some() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            format!("Duplicate `{name}` attribute"),
+        ));
+    }
+    *value = Some(parse(attr)?);
+    Ok(())
+}
+
+/// Parse the number of translation keys of a `#[match_threshold(...)]` attribute
+fn parse_match_threshold(attr: &Attribute) -> Result<usize> {
+    attr.parse_args::<LitInt>()
+        .and_then(|threshold| threshold.base10_parse())
+        .map_err(|err| {
+            syn::Error::new(
+                err.span(),
+                "Expected a number of translation keys, like `#[match_threshold(16)]`",
+            )
+        })
+}
+
+/// Parse the locales listed by a `#[locales(...)]` attribute, which can't repeat
+fn parse_expected_locales(attr: &Attribute) -> Result<Vec<Locale>> {
+    let mut expected: Vec<Locale> = Vec::new();
+    for locale in attr.parse_args_with(Punctuated::<Locale, Token![,]>::parse_terminated)? {
+        if expected.contains(&locale) {
+            return Err(syn::Error::new(
+                locale.span,
+                format!("Duplicate locale `{locale}` in `locales`"),
+            ));
+        }
+        expected.push(locale);
+    }
+    Ok(expected)
+}
+
+/// Parse the translation keys listed by an `#[allow_same_as_key(...)]` attribute, which can't repeat
+fn parse_allowed_keys(attr: &Attribute) -> Result<Vec<LitStr>> {
+    let mut allowed: Vec<LitStr> = Vec::new();
+    for key in attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)? {
+        if allowed.iter().any(|other| other.value() == key.value()) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!(
+                    "Duplicate translation key `{}` in `allow_same_as_key`",
+                    key.value()
+                ),
+            ));
+        }
+        allowed.push(key);
+    }
+    Ok(allowed)
+}
+
+impl Parse for TableOptions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut options = Self::default();
+        for attr in input.call(Attribute::parse_outer)? {
+            if options.parse_entry_attr(&attr)? {
+                continue;
+            }
+            if attr.path().is_ident("default_locale") {
+                parse_once(
+                    &attr,
+                    "default_locale",
+                    &mut options.default_locale,
+                    Attribute::parse_args,
+                )?;
+            } else if attr.path().is_ident("match_threshold") {
+                parse_once(
+                    &attr,
+                    "match_threshold",
+                    &mut options.match_threshold,
+                    parse_match_threshold,
+                )?;
+            } else if attr.path().is_ident("placeholder") {
+                parse_once(
+                    &attr,
+                    "placeholder",
+                    &mut options.placeholder,
+                    Attribute::parse_args,
+                )?;
+            } else if attr.path().is_ident("crate") {
+                parse_once(
+                    &attr,
+                    "crate",
+                    &mut options.crate_path,
+                    Attribute::parse_args,
+                )?;
+            } else if attr.path().is_ident("complete") {
+                parse_flag(&attr, "complete", &mut options.complete)?;
+            } else if attr.path().is_ident("generate_tests") {
+                parse_flag(&attr, "generate_tests", &mut options.generate_tests)?;
+            } else if attr.path().is_ident("size_report") {
+                parse_flag(&attr, "size_report", &mut options.size_report)?;
+            } else if attr.path().is_ident("strings_struct") {
+                parse_flag(&attr, "strings_struct", &mut options.strings_struct)?;
+            } else if attr.path().is_ident("locales") {
+                parse_once(
+                    &attr,
+                    "locales",
+                    &mut options.expected_locales,
+                    parse_expected_locales,
+                )?;
+            } else if attr.path().is_ident("preserve_order") {
+                parse_flag(&attr, "preserve_order", &mut options.preserve_order)?;
+            } else if attr.path().is_ident("validate_locales") {
+                parse_flag(&attr, "validate_locales", &mut options.validate_locales)?;
+            } else if attr.path().is_ident("warn_inconsistent_locales") {
+                parse_flag(
+                    &attr,
+                    "warn_inconsistent_locales",
+                    &mut options.warn_inconsistent_locales,
+                )?;
+            } else if attr.path().is_ident("warn_same_as_key") {
+                parse_flag(&attr, "warn_same_as_key", &mut options.warn_same_as_key)?;
+            } else if attr.path().is_ident("allow_same_as_key") {
+                parse_once(
+                    &attr,
+                    "allow_same_as_key",
+                    &mut options.allow_same_as_key,
+                    parse_allowed_keys,
+                )?;
+            } else {
+                return Err(syn::Error::new_spanned(
+                    attr.path(),
+                    "Unknown table attribute",
+                ));
+            }
+        }
+        Ok(options)
+    }
+}
+
+impl TableOptions {
+    /// Parse one of the attributes that take a list of entries, returning whether `attr` was one of them
+    fn parse_entry_attr(&mut self, attr: &Attribute) -> Result<bool> {
+        if attr.path().is_ident("alias") {
+            parse_entries(attr, &mut self.aliases, |e| &e.alias, "Duplicate alias")?;
+        } else if attr.path().is_ident("locale_meta") {
+            parse_entries(
+                attr,
+                &mut self.locale_meta,
+                |e| &e.locale,
+                "Duplicate metadata for locale",
+            )?;
+        } else if attr.path().is_ident("display_name") {
+            parse_entries(
+                attr,
+                &mut self.display_names,
+                |e| &e.locale,
+                "Duplicate display name for locale",
+            )?;
+        } else if attr.path().is_ident("locale_note") {
+            parse_entries(
+                attr,
+                &mut self.locale_notes,
+                |e| &e.locale,
+                "Duplicate note for locale",
+            )?;
+        } else if attr.path().is_ident("default_key") {
+            parse_entries(
+                attr,
+                &mut self.default_keys,
+                |e| &e.locale,
+                "Duplicate default key for locale",
+            )?;
+        } else if attr.path().is_ident("const_name") {
+            parse_entries(
+                attr,
+                &mut self.const_names,
+                |e| &e.locale,
+                "Duplicate const name for locale",
+            )?;
+        } else if attr.path().is_ident("cfg_locale") {
+            parse_entries(
+                attr,
+                &mut self.cfg_locales,
+                |e| &e.locale,
+                "Duplicate `cfg_locale` for locale",
+            )?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// The path to the `localize` crate in the generated code, from `#[crate(...)]`
+    fn crate_path(&self) -> Path {
+        self.crate_path
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::localize))
+    }
+
+    /// Generate `ALIASES` and `resolve_locale`
+    fn alias_functions(&self, krate: &Path) -> TokenStream2 {
+        let aliases = &self.aliases;
+        let num_aliases = aliases.len();
+        quote! {
+            pub const ALIASES: [(&'static str, &'static str); #num_aliases] = [#(#aliases),*];
+
+            #[inline(always)]
+            pub const fn resolve_locale(locale: &str) -> &str {
+                #krate::resolve_alias(&Self::ALIASES, locale)
+            }
+        }
+    }
+
+    /// Generate `localize`. Tables with at most `#[match_threshold]` translation keys match on the locale
+    /// and the key, which the compiler can turn into a jump on their lengths instead of comparing every
+    /// key, and fall back to looking the key up in `KEY_INDEX` for anything else, like aliases and keys
+    /// that aren't part of the table. Larger tables only look up the key in `KEY_INDEX`.
+    fn localize_fn(
+        &self,
+        locales: &[Locale],
+        translation_keys: &[String],
+        shared_rows: bool,
+        krate: &Path,
+    ) -> TokenStream2 {
+        // with `--cfg localize_show_keys`, keys of the table that are identifiers are returned as they are
+        let identifier_keys: Vec<&String> = translation_keys
+            .iter()
+            .filter(|key| key_variant(key).is_some())
+            .collect();
+        let shown_bytes = identifier_keys
+            .iter()
+            .map(|key| LitByteStr::new(key.as_bytes(), Span::call_site()));
+        let show_keys = (!identifier_keys.is_empty()).then(|| {
+            quote! {
+                if #krate::SHOW_KEYS {
+                    match translation_key.as_bytes() {
+                        #(#shown_bytes => return #identifier_keys,)*
+                        _ => {}
+                    }
+                }
+            }
+        });
+        let threshold = self.match_threshold.unwrap_or(DEFAULT_MATCH_THRESHOLD);
+        // an empty table has nothing to match on, which would make the lookup after the matches unreachable
+        if locales.is_empty() || translation_keys.is_empty() {
+            let lookup = self.replace_placeholder(
+                quote! {
+                    Self::TABLE.localize_with_index(&Self::KEY_INDEX, translation_key, Self::resolve_locale(locale), Self::DEFAULT_LOCALE)
+                },
+                krate,
+            );
+            return quote! {
+                #[inline(always)]
+                pub const fn localize(translation_key: &str, locale: &str) -> &'static str {
+                    #show_keys
+                    #lookup
+                }
+            };
+        }
+        // the same as `localize_indices_with_default`, but through the distinct rows, so that a table that's
+        // only used through `localize` doesn't store every row of `TABLE`
+        let row = |locale_idx: TokenStream2| {
+            if shared_rows {
+                quote!(Self::ROWS[Self::ROW_INDEX[#locale_idx]])
+            } else {
+                quote!(Self::ROWS[#locale_idx])
+            }
+        };
+        let (row, default_row) = (row(quote!(locale_idx)), row(quote!(Self::DEFAULT_LOCALE)));
+        let translation = self.replace_placeholder(
+            quote!(#krate::replace_placeholder(#row[key_idx], #default_row[key_idx])),
+            krate,
+        );
+        let lookup = quote! {{
+            let locale_idx = match #krate::find(&Self::TABLE.locales, Self::resolve_locale(locale)) {
+                Some(idx) => idx,
+                None => Self::DEFAULT_LOCALE,
+            };
+            let key_idx = match Self::KEY_INDEX.get(&Self::TABLE.translation_keys, translation_key) {
+                Some(idx) => idx,
+                None => 0,
+            };
+            #translation
+        }};
+        if translation_keys.len() > threshold {
+            return quote! {
+                #[inline(always)]
+                pub const fn localize(translation_key: &str, locale: &str) -> &'static str {
+                    #show_keys
+                    #lookup
+                }
+            };
+        }
+        let locale_bytes = locales
+            .iter()
+            .map(|locale| LitByteStr::new(locale.name.as_bytes(), locale.span));
+        let locale_indices = (0..locales.len()).map(Literal::usize_unsuffixed);
+        let key_bytes = translation_keys
+            .iter()
+            .map(|key| LitByteStr::new(key.as_bytes(), Span::call_site()));
+        let key_indices = (0..translation_keys.len()).map(Literal::usize_unsuffixed);
+        quote! {
+            pub const fn localize(translation_key: &str, locale: &str) -> &'static str {
+                #show_keys
+                let locale_idx = match Self::resolve_locale(locale).as_bytes() {
+                    #(#locale_bytes => #locale_indices,)*
+                    _ => return #lookup,
+                };
+                let key_idx = match translation_key.as_bytes() {
+                    #(#key_bytes => #key_indices,)*
+                    _ => return #lookup,
+                };
+                #translation
+            }
+        }
+    }
+
+    /// Sort the locales, unless the table has `#[preserve_order]`
+    fn order_locales(&self, mut locales: Vec<Locale>) -> Vec<Locale> {
+        if self.preserve_order.is_none() {
+            locales.sort();
+        }
+        locales
+    }
+
+    /// Get the index of the `#[default_locale]` in the locales, or 0 if there isn't one
+    fn default_locale_index(&self, locales: &[Locale]) -> Result<usize> {
+        let Some(default) = &self.default_locale else {
+            return Ok(0);
+        };
+        locales
+            .iter()
+            .position(|loc| loc == default)
+            .ok_or_else(|| {
+                syn::Error::new(default.span, format!("Unknown default locale `{default}`"))
+            })
+    }
+
+    /// Make sure every alias refers to a locale and doesn't shadow one
+    fn validate_aliases(&self, locales: &[Locale]) -> Result<()> {
+        for LocaleAlias { alias, locale } in &self.aliases {
+            if locales.contains(alias) {
+                return Err(syn::Error::new(
+                    alias.span,
+                    format!("Alias `{alias}` collides with the locale of the same name"),
+                ));
+            }
+            if !locales.contains(locale) {
+                return Err(syn::Error::new(
+                    locale.span,
+                    format!("Unknown locale `{locale}` for alias `{alias}`"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the options against the locales of the translations
+    fn validate(&self, locales: &[Locale], translation_keys: &[String]) -> Result<()> {
+        self.check_expected_locales(locales)?;
+        self.validate_aliases(locales)?;
+        self.validate_default_keys(locales, translation_keys)?;
+        self.validate_allowed_keys(translation_keys)?;
+        self.validate_locales(locales)
+    }
+
+    /// Make sure every key of `#[allow_same_as_key(...)]` is one of the table's translation keys
+    fn validate_allowed_keys(&self, translation_keys: &[String]) -> Result<()> {
+        for key in self.allow_same_as_key.iter().flatten() {
+            if !translation_keys.contains(&key.value()) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "Unknown translation key `{}` in `allow_same_as_key`",
+                        key.value()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Make sure every `#[default_key]` is for a locale of the table and names one of its translation keys
+    fn validate_default_keys(&self, locales: &[Locale], translation_keys: &[String]) -> Result<()> {
+        for DefaultKey { locale, key } in &self.default_keys {
+            if !locales.contains(locale) {
+                return Err(syn::Error::new(
+                    locale.span,
+                    format!("Unknown locale `{locale}` in `default_key`"),
+                ));
+            }
+            if !translation_keys.contains(&key.value()) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "Unknown default key `{}` for locale `{locale}`",
+                        key.value()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The translation key that fills in the missing translations of `locale`, from `#[default_key]`, or
+    /// `"_"` if it doesn't have one
+    fn default_key(&self, locale: &Locale) -> String {
+        self.default_keys
+            .iter()
+            .find(|entry| &entry.locale == locale)
+            .map_or_else(|| "_".to_owned(), |entry| entry.key.value())
+    }
+
+    /// Whether `key` is `"_"` or the `#[default_key]` of any locale
+    fn is_default_key(&self, key: &str) -> bool {
+        key == "_"
+            || self
+                .default_keys
+                .iter()
+                .any(|entry| entry.key.value() == key)
+    }
+
+    /// Get the translation of a cell, falling back to the `#[default_key]` of the locale and then the
+    /// `"_"` key, or `None` if the cell is left as a placeholder
+    fn fill_cell<'s>(
+        &self,
+        strings: &'s BTreeMap<String, BTreeMap<Locale, LitStr>>,
+        key: &str,
+        loc: &Locale,
+    ) -> Option<&'s LitStr> {
+        strings
+            .get(key)
+            // get the translation for this locale
+            .and_then(|x| x.get(loc))
+            // but if it's not there, get the default key of the locale
+            .or_else(|| strings.get(&self.default_key(loc))?.get(loc))
+            // and then the special "_" key
+            .or_else(|| strings.get("_")?.get(loc))
+    }
+
+    /// The string for cells without a translation, from `#[placeholder(...)]` or else `PLACEHOLDER`
+    fn placeholder(&self) -> LitStr {
+        self.placeholder
+            .clone()
+            .unwrap_or_else(|| LitStr::new(DEFAULT_PLACEHOLDER, Span::call_site()))
+    }
+
+    /// Wrap a lookup that returns a cell of the table so that it returns the `#[placeholder(...)]` instead
+    /// of `PLACEHOLDER`, which the cells always use. Without the attribute, the lookup is left as it is.
+    fn replace_placeholder(&self, lookup: TokenStream2, krate: &Path) -> TokenStream2 {
+        if self.placeholder.is_none() {
+            return lookup;
+        }
+        quote!(#krate::replace_placeholder(#lookup, Self::PLACEHOLDER))
+    }
+
+    /// Fill in the translation for every locale and key, falling back to the `#[default_key]` of the
+    /// locale, then the `"_"` key, and then `PLACEHOLDER`, even with a `#[placeholder(...)]`.
+    ///
+    /// Every distinct translation string is only emitted once. Untranslated and closely related locales
+    /// often have identical rows, so when it takes up less space, every distinct row is also only
+    /// emitted once, with the index of the row of every locale. This returns the list of distinct
+    /// strings, the rows, which refer to the strings by index, the index of the row of every locale if
+    /// the rows are shared, and whether each cell has its own translation.
+    fn build_translations(
+        &self,
+        strings: &BTreeMap<String, BTreeMap<Locale, LitStr>>,
+        locales: &[Locale],
+        translation_keys: &[String],
+    ) -> (
+        Vec<LitStr>,
+        Vec<TokenStream2>,
+        Option<Vec<usize>>,
+        Vec<TokenStream2>,
+    ) {
+        let mut interned: Vec<LitStr> = Vec::new();
+        let mut interned_ids: HashMap<String, usize> = HashMap::new();
+        // whether each cell has its own translation, rather than one filled in from a default
+        let explicit = locales
+            .iter()
+            .map(|loc| {
+                let cells = translation_keys
+                    .iter()
+                    .map(|key| strings.get(key).is_some_and(|x| x.contains_key(loc)));
+                quote! {[#(#cells),*]}
+            })
+            .collect();
+        let placeholder = LitStr::new(DEFAULT_PLACEHOLDER, Span::call_site());
+        let mut distinct_rows: Vec<Vec<usize>> = Vec::new();
+        let row_index: Vec<usize> = locales
+            // loop through each locale
+            .iter()
+            .map(|loc| {
+                // loop through each translation key
+                let translations: Vec<usize> = translation_keys
+                    .iter()
+                    .map(|key| {
+                        let translation = self
+                            .fill_cell(strings, key, loc)
+                            .unwrap_or(&placeholder)
+                            .clone();
+                        *interned_ids.entry(translation.value()).or_insert_with(|| {
+                            interned.push(translation);
+                            interned.len() - 1
+                        })
+                    })
+                    .collect();
+                distinct_rows
+                    .iter()
+                    .position(|row| *row == translations)
+                    .unwrap_or_else(|| {
+                        distinct_rows.push(translations);
+                        distinct_rows.len() - 1
+                    })
+            })
+            .collect();
+        // every row that's left out saves a `&str` per key, and `ROW_INDEX` costs a `usize` per locale
+        let saved_rows = locales.len() - distinct_rows.len();
+        let shared = 2 * saved_rows * translation_keys.len() > locales.len();
+        let row_tokens = |row: &Vec<usize>| quote! {[#(Self::STRINGS[#row]),*]};
+        if shared {
+            let rows = distinct_rows.iter().map(row_tokens).collect();
+            (interned, rows, Some(row_index), explicit)
+        } else {
+            let rows = row_index
+                .iter()
+                .map(|&idx| row_tokens(&distinct_rows[idx]))
+                .collect();
+            (interned, rows, None, explicit)
+        }
+    }
+
+    /// Make sure the locales of the translations are exactly the ones listed by `#[locales]`, if it was
+    /// given
+    fn check_expected_locales(&self, locales: &[Locale]) -> Result<()> {
+        let Some(expected) = &self.expected_locales else {
+            return Ok(());
+        };
+        if let Some(missing) = expected.iter().find(|locale| !locales.contains(locale)) {
+            return Err(syn::Error::new(
+                missing.span,
+                format!("Locale `{missing}` from `locales` has no translations"),
+            ));
+        }
+        if let Some(extra) = locales.iter().find(|locale| !expected.contains(locale)) {
+            return Err(syn::Error::new(
+                extra.span,
+                format!("Unexpected locale `{extra}`, which isn't listed in `locales`"),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Make sure every locale is a well-formed BCP 47 language tag, if `#[validate_locales]` was given
+    fn validate_locales(&self, locales: &[Locale]) -> Result<()> {
+        if self.validate_locales.is_none() {
+            return Ok(());
+        }
+        for locale in locales {
+            bcp47::validate(&locale.name).map_err(|reason| {
+                syn::Error::new(
+                    locale.span,
+                    format!("Invalid BCP 47 language tag `{locale}`: {reason}"),
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// The constant assertion for the `#[complete]` attribute, if it was given
+    fn completeness(&self, struct_name: &Ident) -> Option<TokenStream2> {
+        self.complete.map(|span| {
+            let message = format!("Localization table `{struct_name}` is missing translations");
+            quote_spanned! {span=>
+                const _: () = ::core::assert!(#struct_name::TABLE.is_complete(), #message);
+            }
+        })
+    }
+
+    /// The test for the `#[generate_tests]` attribute, if it was given, which checks that the table is
+    /// sorted and that none of its cells are the placeholder
+    fn generated_test(&self, struct_name: &Ident, krate: &Path) -> Option<TokenStream2> {
+        let span = self.generate_tests?;
+        let test_name = format_ident!("{struct_name}_is_complete_and_sorted", span = span);
+        // the locales of a `#[preserve_order]` table aren't sorted, so only its keys are checked
+        let (locales, translations) = if self.preserve_order.is_some() {
+            (quote!([]), quote!([]))
+        } else {
+            (quote!(table.locales), quote!(table.translations))
+        };
+        Some(quote_spanned! {span=>
+            #[cfg(test)]
+            #[test]
+            #[allow(non_snake_case)]
+            fn #test_name() {
+                let table = #struct_name::TABLE;
+                if let ::core::result::Result::Err(err) = #krate::LocalizationTable::new_checked(table.translation_keys, #locales, #translations) {
+                    ::core::panic!("Localization table `{}` isn't sorted: {}", ::core::stringify!(#struct_name), err);
+                }
+                for (locale, row) in table.locales.iter().zip(table.translations.iter()) {
+                    for (translation_key, translation) in table.translation_keys.iter().zip(row.iter()) {
+                        ::core::assert!(
+                            *translation != #krate::PLACEHOLDER,
+                            "Localization table `{}` is missing the translation of `{}` in `{}`",
+                            ::core::stringify!(#struct_name),
+                            translation_key,
+                            locale,
+                        );
+                    }
+                }
+            }
+        })
+    }
+
+    /// Build the `DISPLAY_NAMES` constant from the `#[display_name]` entries, using the locale itself for
+    /// locales without one, and the function that looks it up
+    fn display_names(&self, locales: &[Locale]) -> Result<TokenStream2> {
+        if let Some(entry) = self
+            .display_names
+            .iter()
+            .find(|entry| !locales.contains(&entry.locale))
+        {
+            return Err(syn::Error::new(
+                entry.locale.span,
+                format!("Unknown locale `{}` in `display_name`", entry.locale),
+            ));
+        }
+        let names = locales.iter().map(|locale| {
+            self.display_names
+                .iter()
+                .find(|entry| &entry.locale == locale)
+                .map_or_else(
+                    || locale.to_token_stream(),
+                    |entry| entry.name.to_token_stream(),
+                )
+        });
+        let num_locales = locales.len();
+        // a table without locales doesn't have a default locale to fall back to
+        let fallback = if locales.is_empty() {
+            quote!("")
+        } else {
+            quote!(Self::DISPLAY_NAMES[Self::DEFAULT_LOCALE])
+        };
+        Ok(quote! {
+            pub const DISPLAY_NAMES: [&'static str; #num_locales] = [#(#names),*];
+
+            pub const fn display_name(locale: &str) -> &'static str {
+                match Self::TABLE.locale_index(Self::resolve_locale(locale)) {
+                    Some(idx) => Self::DISPLAY_NAMES[idx],
+                    None => #fallback,
+                }
+            }
+        })
+    }
+
+    /// Build the `LOCALE_NOTES` constant from the `#[locale_note]` entries and the function that looks one
+    /// up, along with the doc attribute of each locale's generated items, which is empty for locales
+    /// without a note
+    fn locale_notes(&self, locales: &[Locale]) -> Result<(TokenStream2, Vec<TokenStream2>)> {
+        if let Some(entry) = self
+            .locale_notes
+            .iter()
+            .find(|entry| !locales.contains(&entry.locale))
+        {
+            return Err(syn::Error::new(
+                entry.locale.span,
+                format!("Unknown locale `{}` in `locale_note`", entry.locale),
+            ));
+        }
+        let notes: Vec<Option<&LitStr>> = locales
+            .iter()
+            .map(|locale| {
+                self.locale_notes
+                    .iter()
+                    .find(|entry| &entry.locale == locale)
+                    .map(|entry| &entry.note)
+            })
+            .collect();
+        let docs = notes
+            .iter()
+            .map(|note| note.map(|note| quote!(#[doc = #note])).unwrap_or_default())
+            .collect();
+        let notes = notes.iter().map(|note| {
+            note.map_or_else(
+                || quote!(::core::option::Option::None),
+                |note| quote!(::core::option::Option::Some(#note)),
+            )
+        });
+        let num_locales = locales.len();
+        let tokens = quote! {
+            pub const LOCALE_NOTES: [::core::option::Option<&'static str>; #num_locales] = [#(#notes),*];
+
+            pub const fn locale_note(locale: &str) -> ::core::option::Option<&'static str> {
+                match Self::TABLE.locale_index(Self::resolve_locale(locale)) {
+                    Some(idx) => Self::LOCALE_NOTES[idx],
+                    None => None,
+                }
+            }
+        };
+        Ok((tokens, docs))
+    }
+
+    /// Build the `BYTES` constant for `#[size_report]`: the length of every distinct translation, translation
+    /// key, and locale, plus the size of the references to them in `ROWS`, and of `ROW_INDEX` if the rows
+    /// are shared
+    fn size_report(
+        &self,
+        interned: &[LitStr],
+        translation_keys: &[String],
+        locales: &[Locale],
+        num_rows: usize,
+    ) -> Option<TokenStream2> {
+        self.size_report?;
+        let string_bytes = interned
+            .iter()
+            .map(|string| string.value().len())
+            .chain(translation_keys.iter().map(String::len))
+            .chain(locales.iter().map(|locale| locale.name.len()))
+            .sum::<usize>();
+        let references = (num_rows + 1) * translation_keys.len() + locales.len();
+        let num_indices = if num_rows < locales.len() {
+            locales.len()
+        } else {
+            0
+        };
+        Some(quote! {
+            pub const BYTES: usize = #string_bytes
+                + #references * ::core::mem::size_of::<&'static str>()
+                + #num_indices * ::core::mem::size_of::<usize>();
+        })
+    }
+
+    /// Build the `LOCALE_META` constant from the `#[locale_meta]` entries, and the functions that look it up
+    fn locale_meta(&self, locales: &[Locale], krate: &Path) -> Result<TokenStream2> {
+        if let Some(entry) = self
+            .locale_meta
+            .iter()
+            .find(|entry| !locales.contains(&entry.locale))
+        {
+            return Err(syn::Error::new(
+                entry.locale.span,
+                format!("Unknown locale `{}` in `locale_meta`", entry.locale),
+            ));
+        }
+        let meta = locales.iter().map(|locale| {
+            let entry = self
+                .locale_meta
+                .iter()
+                .find(|entry| &entry.locale == locale);
+            let rtl = entry.is_some_and(|entry| entry.rtl);
+            let date_format = entry
+                .and_then(|entry| entry.date_format.as_ref())
+                .map_or_else(
+                    || quote!(#krate::LocaleMeta::DEFAULT.date_format),
+                    ToTokens::to_token_stream,
+                );
+            quote!(#krate::LocaleMeta { rtl: #rtl, date_format: #date_format })
+        });
+        let num_locales = locales.len();
+        // a table without locales doesn't have a default locale to fall back to
+        let fallback = if locales.is_empty() {
+            quote!(#krate::LocaleMeta::DEFAULT)
+        } else {
+            quote!(Self::LOCALE_META[Self::DEFAULT_LOCALE])
+        };
+        Ok(quote! {
+            pub const LOCALE_META: [#krate::LocaleMeta; #num_locales] = [#(#meta),*];
+
+            pub const fn locale_meta(locale: &str) -> #krate::LocaleMeta {
+                match Self::TABLE.locale_index(Self::resolve_locale(locale)) {
+                    Some(idx) => Self::LOCALE_META[idx],
+                    None => #fallback,
+                }
+            }
+
+            #[inline(always)]
+            pub const fn is_rtl(locale: &str) -> bool {
+                Self::locale_meta(locale).rtl
+            }
+
+            #[inline(always)]
+            pub const fn direction(locale: &str) -> #krate::Direction {
+                Self::locale_meta(locale).direction()
+            }
+
+            #[inline(always)]
+            pub const fn date_format(locale: &str) -> &'static str {
+                Self::locale_meta(locale).date_format
+            }
+        })
+    }
+}
+
+/// A translation key written in LDSL: a string, an identifier, or an integer like `404`
+enum StrOrIdent {
+    Str(LitStr),
+    Ident(Ident),
+    Int(LitInt),
+}
+
+impl StrOrIdent {
+    pub fn value(&self) -> String {
+        match self {
+            Self::Str(l) => l.value(),
+            Self::Ident(i) => i.unraw().to_string(),
+            Self::Int(i) => i.base10_digits().to_owned(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Str(l) => l.span(),
+            Self::Ident(i) => i.span(),
+            Self::Int(i) => i.span(),
+        }
+    }
+}
+
+impl Parse for StrOrIdent {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            input.parse().map(Self::Str)
+        } else if input.peek(LitInt) {
+            let int: LitInt = input.parse()?;
+            if !int.suffix().is_empty() {
+                return Err(syn::Error::new(
+                    int.span(),
+                    format!("Translation key `{int}` can't have a type suffix"),
+                ));
+            }
+            Ok(Self::Int(int))
+        } else {
+            input.parse().map(Self::Ident)
+        }
+    }
+}
+
+/// Contextual keywords of LDSL
+mod kw {
+    syn::custom_keyword!(dedent);
+    syn::custom_keyword!(from);
+    syn::custom_keyword!(env);
+    syn::custom_keyword!(locale);
+}
+
+struct LDSLTranslationItem {
+    key: StrOrIdent,
+    description: Option<LitStr>,
+    body: LDSLTranslationBody,
+}
+
+enum LDSLTranslationBody {
+    /// `{ locale => "translation", ... }`
+    Values(Punctuated<LDSLTranslationValue, Token![,]>),
+    /// `@ "other_key"`, which uses the translations of another key
+    Reference(StrOrIdent),
+}
+
+struct LDSLTranslationValue {
+    /// The locale of the translation, or several from a group like `(en, es) => "OK"`. This is empty for
+    /// a `*` translation.
+    locales: Vec<Locale>,
+    /// The `*` of a translation for every locale that the key doesn't list, if it is one
+    wildcard: Option<Token![*]>,
+    context: Option<Ident>,
+    value: LitStr,
+}
+
+/// The syntax types that the macro supports, in the order they're documented
+const SYNTAX_TYPES: [&str; 7] = [
+    "LDSL",
+    "LDSL_BY_LOCALE",
+    "LDSL_MULTI",
+    "CSV",
+    "PO",
+    "JSON_DIR",
+    "TOML",
+];
+
+/// Parse a `T`, replacing the generic error from `syn` with `message` at the same span, which is the
+/// token where `T` was expected
+fn parse_expecting<T: Parse>(input: ParseStream, message: impl std::fmt::Display) -> Result<T> {
+    input
+        .parse()
+        .map_err(|err| syn::Error::new(err.span(), message))
+}
+
+/// List the supported syntax types for an error message, like "LDSL, CSV, or TOML"
+fn syntax_type_list() -> String {
+    let (last, rest) = SYNTAX_TYPES.split_last().unwrap_or((&"", &[]));
+    format!("{}, or {last}", rest.join(", "))
+}
+
+/// The error for a syntax type that isn't one of [`SYNTAX_TYPES`], suggesting the supported one it's
+/// probably a typo of
+fn unsupported_syntax_type(syntax_type: &Ident) -> syn::Error {
+    let name = syntax_type.to_string();
+    let message = SYNTAX_TYPES
+        .iter()
+        .find(|supported| supported.eq_ignore_ascii_case(&name))
+        .map_or_else(
+            || {
+                format!(
+                    "Unsupported syntax type `{name}`, expected {}",
+                    syntax_type_list()
+                )
+            },
+            |supported| format!("Unsupported syntax type `{name}`, did you mean `{supported}`?"),
+        );
+    syn::Error::new(syntax_type.span(), message)
+}
+
+impl Parse for TranslationInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let struct_name: Ident = input.parse()?;
+        let _: Token![=] = parse_expecting(
+            input,
+            format_args!("Expected `=` after the table name `{struct_name}`"),
+        )?;
+        let syntax_type: Ident = parse_expecting(
+            input,
+            format_args!("Expected a syntax type: {}", syntax_type_list()),
+        )?;
+        // check the syntax type before anything after it, so that a typo is reported as such
+        if !SYNTAX_TYPES.contains(&&*syntax_type.to_string()) {
+            return Err(unsupported_syntax_type(&syntax_type));
+        }
+        let options: TableOptions = input.parse()?;
+        let mut sources = Vec::new();
+        let mut env_vars = Vec::new();
+        let translations = match &*syntax_type.to_string() {
+            "LDSL" if input.peek(kw::from) => {
+                let path = ldsl_path(input, &mut env_vars)?;
+                let (full_path, contents) = read_source(&path)?;
+                sources.push(full_path);
+                Translations::parse_ldsl_file(&contents, &path)?
+            }
+            "LDSL" => Translations::parse_ldsl(input)?,
+            "LDSL_BY_LOCALE" => Translations::parse_ldsl_by_locale(input, false)?,
+            "LDSL_MULTI" => Translations::parse_ldsl_by_locale(input, true)?,
+            "CSV" => {
+                let path: LitStr = input.parse()?;
+                let (full_path, contents) = read_source(&path)?;
+                sources.push(full_path);
+                Translations::parse_csv(&contents, &path)?
+            }
+            "PO" => {
+                let body;
+                syn::braced!(body in input);
+                let files = body.parse_terminated(PoFile::parse, Token![,])?;
+                let mut translations = Translations {
+                    strings: BTreeMap::new(),
+                    contexts: Vec::new(),
+                    locales: Vec::new(),
+                    descriptions: BTreeMap::new(),
+                };
+                for file in files {
+                    let (full_path, contents) = read_source(&file.path)?;
+                    sources.push(full_path);
+                    translations.add_po(&contents, &file)?;
+                }
+                translations
+            }
+            "JSON_DIR" => {
+                let dir: LitStr = input.parse()?;
+                let mut translations = Translations {
+                    strings: BTreeMap::new(),
+                    contexts: Vec::new(),
+                    locales: Vec::new(),
+                    descriptions: BTreeMap::new(),
+                };
+                for file in json_files(&dir)? {
+                    let (full_path, contents) = read_source(&file)?;
+                    sources.push(full_path);
+                    translations.add_json(&contents, &file)?;
+                }
+                translations
+            }
+            "TOML" => {
+                let path: LitStr = input.parse()?;
+                let (full_path, contents) = read_source(&path)?;
+                sources.push(full_path);
+                Translations::parse_toml(&contents, &path)?
+            }
+            _ => return Err(unsupported_syntax_type(&syntax_type)),
+        };
+        Ok(Self {
+            struct_name,
+            options,
+            translations,
+            sources,
+            env_vars,
+        })
+    }
+}
+
+/// Parse the file of `LDSL from "path"`, or of `LDSL from env "VAR"`, which reads the path from an
+/// environment variable while the macro is expanded. The variable is added to `env_vars`.
+fn ldsl_path(input: ParseStream, env_vars: &mut Vec<LitStr>) -> Result<LitStr> {
+    let _: kw::from = input.parse()?;
+    if !input.peek(kw::env) {
+        return parse_expecting(input, "Expected a file path or `env` after `from`");
+    }
+    let _: kw::env = input.parse()?;
+    let var: LitStr = parse_expecting(input, "Expected an environment variable name after `env`")?;
+    let path = std::env::var(var.value()).map_err(|err| {
+        let message = match err {
+            std::env::VarError::NotPresent => {
+                format!("Environment variable `{}` isn't set", var.value())
+            }
+            std::env::VarError::NotUnicode(_) => {
+                format!("Environment variable `{}` isn't valid UTF-8", var.value())
+            }
+        };
+        syn::Error::new(var.span(), message)
+    })?;
+    env_vars.push(var.clone());
+    Ok(LitStr::new(&path, var.span()))
+}
+
+impl Translations {
+    fn parse_ldsl(input: ParseStream) -> Result<Self> {
+        let body;
+        syn::braced!(body in input);
+        Self::parse_ldsl_items(&body)
+    }
+
+    /// Parse the locale-major form of LDSL, where each locale lists its translations, like
+    /// `en { greeting => "Hello" }`. With `keyword`, which is `LDSL_MULTI`, each block starts with
+    /// `locale`, like `locale en { greeting => "Hello" }`.
+    fn parse_ldsl_by_locale(input: ParseStream, keyword: bool) -> Result<Self> {
+        let body;
+        syn::braced!(body in input);
+        let mut strings: BTreeMap<String, BTreeMap<Locale, LitStr>> = BTreeMap::new();
+        let mut locales: Vec<Locale> = Vec::new();
+        while !body.is_empty() {
+            if keyword {
+                let _: kw::locale =
+                    parse_expecting(&body, "Expected `locale` before a block of translations")?;
+            }
+            let locale: Locale = body.parse()?;
+            if !add_locale(&mut locales, &locale) {
+                return Err(syn::Error::new(
+                    locale.span,
+                    format!("Duplicate locale `{locale}`"),
+                ));
+            }
+            let content;
+            syn::braced!(content in body);
+            while !content.is_empty() {
+                let key: StrOrIdent = content.parse()?;
+                let _: Token![=>] = parse_expecting(
+                    &content,
+                    format_args!(
+                        "Expected `=>` after translation key `{}` of locale `{locale}`",
+                        key.value()
+                    ),
+                )?;
+                let value: LitStr = content.parse()?;
+                let translations = strings.entry(key.value()).or_default();
+                if translations.insert(locale.clone(), value).is_some() {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "Duplicate translation key `{}` for locale `{locale}`",
+                            key.value()
+                        ),
+                    ));
+                }
+                if !content.is_empty() {
+                    let _: Token![,] = content.parse()?;
+                }
+            }
+            // the braces already end a locale, so the comma after one is optional
+            let _: Option<Token![,]> = body.parse()?;
+        }
+        Ok(Self {
+            strings,
+            contexts: Vec::new(),
+            locales,
+            descriptions: BTreeMap::new(),
+        })
+    }
+
+    /// Parse the contents of an LDSL file, which are everything that would go inside the braces
+    fn parse_ldsl_file(contents: &str, path: &LitStr) -> Result<Self> {
+        Self::parse_ldsl_items
+            .parse_str(contents)
+            .map_err(|err| syn::Error::new(path.span(), format!("{err} in `{}`", path.value())))
+    }
+
+    fn parse_ldsl_items(body: ParseStream) -> Result<Self> {
+        let mut translations = Vec::new();
+        while !body.is_empty() {
+            let item: LDSLTranslationItem = body.parse()?;
+            // the braces already end a block of translations, so the comma after one is optional
+            let needs_comma = matches!(item.body, LDSLTranslationBody::Reference(_));
+            translations.push(item);
+            if needs_comma && !body.is_empty() {
+                let _: Token![,] = body.parse()?;
+            } else {
+                let _: Option<Token![,]> = body.parse()?;
+            }
+        }
+        let mut strings: BTreeMap<String, BTreeMap<Locale, LitStr>> = BTreeMap::new();
+        let mut contexts: Vec<ContextVariant> = Vec::new();
+        let mut locales: Vec<Locale> = Vec::new();
+        let mut references: Vec<(String, StrOrIdent)> = Vec::new();
+        let mut descriptions: BTreeMap<String, LitStr> = BTreeMap::new();
+        // the `*` translation of each key that has one
+        let mut wildcards: Vec<(String, LitStr)> = Vec::new();
+        for item in translations {
+            let key = item.key.value();
+            if strings.contains_key(&key) || references.iter().any(|(k, _)| *k == key) {
+                return Err(syn::Error::new(
+                    item.key.span(),
+                    format!("Duplicate translation key `{key}`"),
+                ));
+            }
+            if let Some(description) = item.description {
+                descriptions.insert(key.clone(), description);
+            }
+            let values = match item.body {
+                LDSLTranslationBody::Values(values) if values.is_empty() => {
+                    return Err(syn::Error::new(
+                        item.key.span(),
+                        format!("Translation key `{key}` has no translations"),
+                    ));
+                }
+                LDSLTranslationBody::Values(values) => values,
+                LDSLTranslationBody::Reference(target) => {
+                    references.push((key, target));
+                    continue;
+                }
+            };
+            let mut current_string = BTreeMap::new();
+            for translation in values {
+                if let Some(star) = translation.wildcard {
+                    if wildcards.iter().any(|(k, _)| *k == key) {
+                        return Err(syn::Error::new(star.span, "Duplicate `*` translation"));
+                    }
+                    wildcards.push((key.clone(), translation.value));
+                    continue;
+                }
+                for locale in translation.locales {
+                    add_locale(&mut locales, &locale);
+                    if let Some(context) = &translation.context {
+                        let context = context.unraw();
+                        if contexts.iter().any(|variant| {
+                            variant.key == key
+                                && variant.locale == locale
+                                && variant.context == context
+                        }) {
+                            return Err(syn::Error::new(
+                                context.span(),
+                                "Duplicate context identifier in translation",
+                            ));
+                        }
+                        contexts.push(ContextVariant {
+                            key: key.clone(),
+                            locale,
+                            context,
+                            value: translation.value.clone(),
+                        });
+                    } else if let Some(span) = current_string
+                        .insert(locale.clone(), translation.value.clone())
+                        .map(|_| locale.span)
+                    {
+                        return Err(syn::Error::new(
+                            span,
+                            "Duplicate locale identifier in translation",
+                        ));
+                    }
+                }
+            }
+            strings.insert(key, current_string);
+        }
+        for (key, value) in wildcards {
+            let cells = strings.entry(key).or_default();
+            for locale in &locales {
+                cells.entry(locale.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        let mut translations = Self {
+            strings,
+            contexts,
+            locales,
+            descriptions,
+        };
+        translations.resolve_references(&references)?;
+        Ok(translations)
+    }
+
+    /// Copy the translations of the keys referenced with `@`, following chains of references
+    fn resolve_references(&mut self, references: &[(String, StrOrIdent)]) -> Result<()> {
+        let mut resolved = Vec::new();
+        for (key, target) in references {
+            let mut chain = vec![key.clone()];
+            let mut target = target;
+            loop {
+                let name = target.value();
+                if chain.contains(&name) {
+                    chain.push(name);
+                    return Err(syn::Error::new(
+                        target.span(),
+                        format!("Cyclic reference `{}`", chain.join("` -> `")),
+                    ));
+                }
+                if let Some((_, next)) = references.iter().find(|(k, _)| *k == name) {
+                    chain.push(name);
+                    target = next;
+                } else if self.strings.contains_key(&name) {
+                    resolved.push((key.clone(), name));
+                    break;
+                } else {
+                    return Err(syn::Error::new(
+                        target.span(),
+                        format!("Unknown translation key `{name}` referenced by `{key}`"),
+                    ));
+                }
+            }
+        }
+        for (key, target) in resolved {
+            let copied = self.strings[&target].clone();
+            let copied_contexts: Vec<_> = self
+                .contexts
+                .iter()
+                .filter(|variant| variant.key == target)
+                .map(|variant| ContextVariant {
+                    key: key.clone(),
+                    locale: variant.locale.clone(),
+                    context: variant.context.clone(),
+                    value: variant.value.clone(),
+                })
+                .collect();
+            self.strings.insert(key, copied);
+            self.contexts.extend(copied_contexts);
+        }
+        Ok(())
+    }
+
+    /// Make sure no two locales are the same except for ASCII case, like `en` and `En`. Locales are
+    /// case-sensitive, so these would be separate columns of the table, which is almost never intended.
+    fn check_locale_case(&self) -> Result<()> {
+        for (idx, locale) in self.locales.iter().enumerate() {
+            if let Some(other) = self.locales[..idx]
+                .iter()
+                .find(|other| other.name.eq_ignore_ascii_case(&locale.name))
+            {
+                return Err(syn::Error::new(
+                    locale.span,
+                    format!(
+                        "Locales `{other}` and `{locale}` only differ by case, but locales are case-sensitive"
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove whitespace from around every translation key, which usually comes from copying the key
+    /// along with a space next to it and would make it impossible to look up. Returns each key that was
+    /// changed, the key it became, and the span of one of its translations to warn at.
+    fn trim_keys(&mut self) -> Result<Vec<(String, String, Span)>> {
+        let untrimmed: Vec<String> = self
+            .strings
+            .keys()
+            .filter(|key| key.trim() != key.as_str())
+            .cloned()
+            .collect();
+        let mut trimmed = Vec::new();
+        for key in untrimmed {
+            let translations = self.strings.remove(&key).unwrap_or_default();
+            let span = translations
+                .values()
+                .next()
+                .map_or_else(Span::call_site, LitStr::span);
+            let new_key = key.trim().to_owned();
+            if self.strings.contains_key(&new_key) {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "Translation keys `{key}` and `{new_key}` are the same after trimming whitespace"
+                    ),
+                ));
+            }
+            for variant in &mut self.contexts {
+                if variant.key == key {
+                    variant.key.clone_from(&new_key);
+                }
+            }
+            if let Some(description) = self.descriptions.remove(&key) {
+                self.descriptions.insert(new_key.clone(), description);
+            }
+            self.strings.insert(new_key.clone(), translations);
+            trimmed.push((key, new_key, span));
+        }
+        Ok(trimmed)
+    }
+
+    /// Read a CSV file whose header row is the locales (after a column for the keys), and whose other
+    /// rows are a translation key followed by its translations. Empty cells are left out.
+    fn parse_csv(contents: &str, path: &LitStr) -> Result<Self> {
+        let file = path.value();
+        let malformed = |line: usize, message: &dyn std::fmt::Display| {
+            syn::Error::new(
+                path.span(),
+                format!("Malformed row on line {line} of `{file}`: {message}"),
+            )
+        };
+        let mut records = csv::parse(contents)
+            .map_err(|err| malformed(err.line, &err.message))?
+            .into_iter();
+        let Some(header) = records.next() else {
+            return Err(syn::Error::new(path.span(), format!("`{file}` is empty")));
+        };
+        let mut locales: Vec<Locale> = Vec::new();
+        for cell in header.cells.iter().skip(1) {
+            let locale = Locale::new(cell.trim().to_owned(), path.span())
+                .map_err(|_| malformed(header.line, &format!("`{cell}` isn't a valid locale")))?;
+            if locales.contains(&locale) {
+                return Err(malformed(
+                    header.line,
+                    &format!("duplicate locale `{locale}`"),
+                ));
+            }
+            locales.push(locale);
+        }
+        let mut strings: BTreeMap<String, BTreeMap<Locale, LitStr>> = BTreeMap::new();
+        for record in records {
+            if record.cells.len() != header.cells.len() {
+                return Err(malformed(
+                    record.line,
+                    &format!(
+                        "expected {} cells, found {}",
+                        header.cells.len(),
+                        record.cells.len()
+                    ),
+                ));
+            }
+            let mut cells = record.cells.into_iter();
+            let key = cells.next().unwrap_or_default();
+            if strings.contains_key(&key) {
+                return Err(malformed(
+                    record.line,
+                    &format!("duplicate translation key `{key}`"),
+                ));
+            }
+            let translations = locales
+                .iter()
+                .zip(cells)
+                .filter(|(_, cell)| !cell.is_empty())
+                .map(|(locale, cell)| (locale.clone(), LitStr::new(&cell, path.span())))
+                .collect();
+            strings.insert(key, translations);
+        }
+        Ok(Self {
+            strings,
+            contexts: Vec::new(),
+            locales,
+            descriptions: BTreeMap::new(),
+        })
+    }
+
+    /// Add the translations of one locale from a `.po` file, using each `msgid` as a translation key.
+    /// Empty and fuzzy translations are left out.
+    fn add_po(&mut self, contents: &str, file: &PoFile) -> Result<()> {
+        let path = file.path.value();
+        let malformed = |line: usize, message: &dyn std::fmt::Display| {
+            syn::Error::new(
+                file.path.span(),
+                format!("Malformed entry on line {line} of `{path}`: {message}"),
+            )
+        };
+        let locale = &file.locale;
+        if !add_locale(&mut self.locales, locale) {
+            return Err(syn::Error::new(
+                locale.span,
+                format!("Duplicate locale `{locale}`"),
+            ));
+        }
+        let mut msgids = HashSet::new();
+        for entry in po::parse(contents).map_err(|err| malformed(err.line, &err.message))? {
+            // the entry with an empty msgid holds the metadata of the file
+            if entry.msgid.is_empty() {
+                continue;
+            }
+            if !msgids.insert(entry.msgid.clone()) {
+                return Err(malformed(
+                    entry.line,
+                    &format!("duplicate msgid `{}`", entry.msgid),
+                ));
+            }
+            let translations = self.strings.entry(entry.msgid).or_default();
+            if !entry.fuzzy && !entry.msgstr.is_empty() {
+                translations.insert(locale.clone(), LitStr::new(&entry.msgstr, file.path.span()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Translations {
+    /// Add the translations of one locale from a JSON file containing an object of translation keys and
+    /// translations. The locale is the name of the file without the extension.
+    fn add_json(&mut self, contents: &str, file: &LitStr) -> Result<()> {
+        let path = file.value();
+        let stem = std::path::Path::new(&path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let locale = Locale::new(stem, file.span())?;
+        let JsonEntries(entries) = serde_json::from_str(contents).map_err(|err| {
+            syn::Error::new(file.span(), format!("Couldn't parse `{path}`: {err}"))
+        })?;
+        for (key, value) in entries {
+            let serde_json::Value::String(value) = value else {
+                return Err(syn::Error::new(
+                    file.span(),
+                    format!("Expected a string for translation key `{key}` in `{path}`, found `{value}`"),
+                ));
+            };
+            let translations = self.strings.entry(key.clone()).or_default();
+            if translations.contains_key(&locale) {
+                return Err(syn::Error::new(
+                    file.span(),
+                    format!("Duplicate translation key `{key}` in `{path}`"),
+                ));
+            }
+            translations.insert(locale.clone(), LitStr::new(&value, file.span()));
+        }
+        add_locale(&mut self.locales, &locale);
+        Ok(())
+    }
+
+    fn parse_toml(contents: &str, path: &LitStr) -> Result<Self> {
+        let file = path.value();
+        let error = |message: String| syn::Error::new(path.span(), message);
+        let document: toml::Table = contents
+            .parse()
+            .map_err(|err| error(format!("Couldn't parse `{file}`: {err}")))?;
+        let mut strings: BTreeMap<String, BTreeMap<Locale, LitStr>> = BTreeMap::new();
+        let mut locales: Vec<Locale> = Vec::new();
+        for (key, value) in document {
+            let toml::Value::Table(table) = value else {
+                return Err(error(format!(
+                    "Expected a table for translation key `{key}` in `{file}`, found `{value}`"
+                )));
+            };
+            let mut current_string = BTreeMap::new();
+            for (locale, value) in table {
+                let toml::Value::String(value) = value else {
+                    return Err(error(format!(
+                        "Expected a string for locale `{locale}` of translation key `{key}` in `{file}`, found `{value}`"
+                    )));
+                };
+                let locale = Locale::new(locale, path.span()).map_err(|err| {
+                    error(format!("{err} in translation key `{key}` of `{file}`"))
+                })?;
+                add_locale(&mut locales, &locale);
+                current_string.insert(locale, LitStr::new(&value, path.span()));
+            }
+            strings.insert(key, current_string);
+        }
+        Ok(Self {
+            strings,
+            contexts: Vec::new(),
+            locales,
+            descriptions: BTreeMap::new(),
+        })
+    }
+}
+
+/// The members of a JSON object in the order they were written, including repeated keys that
+/// `serde_json::Map` would silently merge
+struct JsonEntries(Vec<(String, serde_json::Value)>);
+
+impl<'de> serde::Deserialize<'de> for JsonEntries {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for EntriesVisitor {
+            type Value = JsonEntries;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an object of translations")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<JsonEntries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(JsonEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// List the `.json` files in a directory named in the macro input, sorted by name, as paths relative to
+/// the manifest directory like the directory itself
+fn json_files(dir: &LitStr) -> Result<Vec<LitStr>> {
+    let dir_path = dir.value();
+    let entries = std::fs::read_dir(manifest_path(&dir_path))
+        .map_err(|err| syn::Error::new(dir.span(), format!("Couldn't read `{dir_path}`: {err}")))?;
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if !path.is_file() || path.extension()? != "json" {
+                return None;
+            }
+            let file = std::path::Path::new(&dir_path).join(path.file_name()?);
+            Some(file.to_string_lossy().into_owned())
+        })
+        .collect();
+    files.sort();
+    Ok(files
+        .iter()
+        .map(|file| LitStr::new(file, dir.span()))
+        .collect())
+}
+
+/// A locale and the `.po` file containing its translations, like `es => "locales/es.po"`
+struct PoFile {
+    locale: Locale,
+    path: LitStr,
+}
+
+impl Parse for PoFile {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let locale = input.parse()?;
+        let _: Token![=>] = input.parse()?;
+        let path = input.parse()?;
+        Ok(Self { locale, path })
+    }
+}
+
+/// Read a file named in the macro input, relative to the manifest directory of the crate being compiled.
+///
+/// Returns the full path of the file, so that the generated code can `include_str!` it and be rebuilt
+/// when it changes, along with its contents.
+fn read_source(path: &LitStr) -> Result<(LitStr, String)> {
+    let full_path = manifest_path(&path.value());
+    let contents = std::fs::read_to_string(&full_path).map_err(|err| {
+        syn::Error::new(
+            path.span(),
+            format!("Couldn't read `{}`: {err}", path.value()),
+        )
+    })?;
+    Ok((
+        LitStr::new(&full_path.to_string_lossy(), path.span()),
+        contents,
+    ))
+}
+
+/// Resolve a path relative to the manifest directory of the crate being compiled
+fn manifest_path(path: &str) -> PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map_or_else(PathBuf::new, PathBuf::from)
+        .join(path)
+}
+
+impl Parse for LDSLTranslationItem {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key: StrOrIdent = input.parse()?;
+        let _: Token![=] = parse_expecting(
+            input,
+            format_args!("Expected `=` after translation key `{}`", key.value()),
+        )?;
+        let description: Option<LitStr> = if input.peek(LitStr) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let body = if input.peek(Token![@]) {
+            let _: Token![@] = input.parse()?;
+            LDSLTranslationBody::Reference(input.parse()?)
+        } else {
+            if !input.peek(syn::token::Brace) {
+                return Err(input.error(format!(
+                    "Expected `{{` with the translations of translation key `{}`, or `@` followed by another key",
+                    key.value()
+                )));
+            }
+            let content;
+            syn::braced!(content in input);
+            LDSLTranslationBody::Values(
+                content.parse_terminated(LDSLTranslationValue::parse, Token![,])?,
+            )
+        };
+        Ok(Self {
+            key,
+            description,
+            body,
+        })
+    }
+}
+
+impl Parse for LDSLTranslationValue {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let wildcard: Option<Token![*]> = input.parse()?;
+        let locales: Vec<Locale> = if wildcard.is_some() {
+            Vec::new()
+        } else if input.peek(syn::token::Paren) {
+            let content;
+            let group = syn::parenthesized!(content in input);
+            let locales: Punctuated<Locale, Token![,]> =
+                content.parse_terminated(Locale::parse, Token![,])?;
+            if locales.is_empty() {
+                return Err(syn::Error::new(
+                    group.span.join(),
+                    "Expected at least one locale in the group",
+                ));
+            }
+            locales.into_iter().collect()
+        } else {
+            vec![input.parse()?]
+        };
+        let locale = locales
+            .first()
+            .map_or_else(|| String::from("*"), ToString::to_string);
+        let context: Option<Ident> = if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            Some(content.parse()?)
+        } else {
+            None
+        };
+        if let (Some(star), Some(_)) = (wildcard, &context) {
+            return Err(syn::Error::new(
+                star.span,
+                "A `*` translation can't have a context",
+            ));
+        }
+        let _: Token![=>] =
+            parse_expecting(input, format_args!("Expected `=>` after locale `{locale}`"))?;
+        let dedent: Option<kw::dedent> = input.parse()?;
+        let mut value: LitStr = parse_expecting(
+            input,
+            format_args!("Expected a string literal with the translation for locale `{locale}`"),
+        )?;
+        if dedent.is_some() {
+            value = LitStr::new(&dedent_str(&value.value()), value.span());
+        }
+        Ok(Self {
+            locales,
+            wildcard,
+            context,
+            value,
+        })
+    }
+}
+
+/// Remove the indentation of a `dedent` translation: drop the first line and the last line if they're
+/// blank, remove the leading whitespace that every line that isn't blank has in common, and empty the
+/// blank lines
+fn dedent_str(value: &str) -> String {
+    let mut lines: Vec<&str> = value.split('\n').collect();
+    if lines.len() > 1 && lines[0].trim().is_empty() {
+        lines.remove(0);
+    }
+    if lines.len() > 1 && lines[lines.len() - 1].trim().is_empty() {
+        lines.pop();
+    }
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| {
+            if line.trim().is_empty() {
+                ""
+            } else {
+                line.get(indent..).unwrap_or_else(|| line.trim_start())
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Expand the input of the `localization_table!` macro into the items of the table.
+///
+/// # Errors
+///
+/// Returns an error if the input is malformed or describes an invalid table, which the macro reports
+/// as a compile error.
+pub fn expand_tokens(input: TokenStream2) -> Result<TokenStream2> {
+    expand(syn::parse2(input)?)
+}
+
+/// Expand the input of the `merge_tables!` macro into the items of every table it names, followed by
+/// the combined table.
+///
+/// # Errors
+///
+/// Returns an error if any of the tables is invalid, or if two of them have a translation for the same
+/// translation key and locale.
+pub fn expand_merge_tokens(input: TokenStream2) -> Result<TokenStream2> {
+    merge::expand_merge(syn::parse2(input)?)
+}
+
+/// Generate the Rust source of a table from the input of the `localization_table!` macro, like
+/// `Spanglish = LDSL { ... }`, for a build script to write to a file and `include!`.
+///
+/// # Errors
+///
+/// Returns an error if the input isn't valid Rust tokens, is malformed, or describes an invalid table,
+/// with the message the macro would give.
+///
+/// # Example
+///
+/// ```
+/// let source = localize_codegen::generate(
+///     r#"Spanglish = LDSL {
+///         greeting = {
+///             en => "Hello",
+///             es => "Hola",
+///         }
+///     }"#,
+/// )
+/// .unwrap();
+/// assert!(source.contains("pub struct Spanglish"));
+///
+/// let err = localize_codegen::generate("Spanglish = YAML {}").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Unsupported syntax type `YAML`, expected LDSL, LDSL_BY_LOCALE, LDSL_MULTI, CSV, PO, JSON_DIR, or TOML"
+/// );
+/// ```
+pub fn generate(input: &str) -> Result<String> {
+    Ok(expand(syn::parse_str(input)?)?.to_string())
+}
+
+fn expand(input: TranslationInput) -> Result<TokenStream2> {
+    if input.options.cfg_locales.is_empty() {
+        expand_table(input)
+    } else {
+        cfg_locale::expand_cfg_locales(&input)
+    }
+}
+
+/// Generate a table, without any `#[cfg_locale(...)]` conditions
+fn expand_table(input: TranslationInput) -> Result<TokenStream2> {
+    let TranslationInput {
+        struct_name,
+        options,
+        mut translations,
+        sources,
+        env_vars,
+    } = input;
+    translations.check_locale_case()?;
+    let trimmed_keys = translations.trim_keys()?;
+    let Translations {
+        strings,
+        contexts,
+        locales,
+        descriptions,
+    } = translations;
+    let locales = options.order_locales(locales);
+    let default_locale = options.default_locale_index(&locales)?;
+    let translation_keys: Vec<String> = strings.keys().cloned().collect();
+    options.validate(&locales, &translation_keys)?;
+
+    let krate = options.crate_path();
+    let alias_functions = options.alias_functions(&krate);
+    let completeness = options.completeness(&struct_name);
+    let generated_test = options.generated_test(&struct_name, &krate);
+    let key_index = build_key_index(&translation_keys, &krate);
+    let locale_meta = options.locale_meta(&locales, &krate)?;
+    let display_names = options.display_names(&locales)?;
+    let (locale_notes, note_docs) = options.locale_notes(&locales)?;
+    let warnings = table_warnings(
+        &options,
+        &strings,
+        &locales,
+        &translation_keys,
+        &trimmed_keys,
+    );
+
+    let num_keys = translation_keys.len();
+    let num_locales = locales.len();
+    let (interned, rows, row_index, explicit) =
+        options.build_translations(&strings, &locales, &translation_keys);
+    let placeholder = options.placeholder();
+    let size_report = options.size_report(&interned, &translation_keys, &locales, rows.len());
+    let localize_fn = options.localize_fn(&locales, &translation_keys, row_index.is_some(), &krate);
+    let (cells, table_rows) = cell_constants(&interned, &rows, row_index.as_deref(), num_keys);
+    let context_functions = context_functions(&contexts, &krate);
+    let locale_handles =
+        locale_handles(&locales, &options.const_names, &note_docs, num_keys, &krate)?;
+    let key_enum_name = format_ident!("{struct_name}Key");
+    let key_enum = build_key_enum(&key_enum_name, &translation_keys, &descriptions);
+    let description_functions = description_functions(&descriptions, &translation_keys);
+    let locale_enum_name = format_ident!("{struct_name}Locale");
+    let locale_enum = build_locale_enum(
+        &locale_enum_name,
+        &struct_name,
+        &locales,
+        &note_docs,
+        num_keys,
+        &krate,
+    )?;
+    let lookup_functions = lookup_functions(&krate, num_keys, &key_enum_name);
+    let localizable = localizable_impl(&struct_name, &krate, num_keys);
+    let strings_struct = options
+        .strings_struct
+        .map(|_| build_strings_struct(&struct_name, &translation_keys, &descriptions))
+        .transpose()?;
+    Ok(quote! {
+        #(const _: &str = include_str!(#sources);)*
+
+        #(const _: &str = env!(#env_vars);)*
+
+        #(#warnings)*
+
+        #completeness
+
+        #generated_test
+
+        #key_enum
+
+        #locale_enum
+
+        #strings_struct
+
+        pub struct #struct_name;
+
+        #localizable
+
+        impl #struct_name {
+            #cells
+
+            pub const TABLE: #krate::LocalizationTable<'static, #num_locales, #num_keys> = #krate::LocalizationTable {
+                translation_keys: [#(#translation_keys),*],
+                locales: [#(#locales),*],
+                translations: #table_rows,
+            };
+
+            pub const PLACEHOLDER: &'static str = #placeholder;
+
+            pub const EXPLICIT: [[bool; #num_keys]; #num_locales] = [#(#explicit),*];
+
+            pub const DEFAULT_LOCALE: usize = #default_locale;
+
+            pub const DEFAULTING_TABLE: #krate::DefaultingTable<'static, #num_locales, #num_keys, #default_locale> = #krate::DefaultingTable::new(Self::TABLE);
+
+            pub const KEY_INDEX: #krate::KeyIndex<'static> = #krate::KeyIndex {
+                slots: &[#(#key_index),*],
+            };
+
+            #alias_functions
+
+            #localize_fn
+
+            #context_functions
+
+            #lookup_functions
+
+            #description_functions
+
+            #locale_meta
+
+            #display_names
+
+            #locale_notes
+
+            #size_report
+
+            #locale_handles
+        }
+    })
+}
+
+/// Generate the `STRINGS` and `ROWS` constants, and `ROW_INDEX` if the rows are shared between locales,
+/// along with the `translations` of `TABLE`, which are built from them
+fn cell_constants(
+    interned: &[LitStr],
+    rows: &[TokenStream2],
+    row_index: Option<&[usize]>,
+    num_keys: usize,
+) -> (TokenStream2, TokenStream2) {
+    let num_interned = interned.len();
+    let num_rows = rows.len();
+    let rows = quote! {
+        const STRINGS: [&'static str; #num_interned] = [#(#interned),*];
+
+        const ROWS: [[&'static str; #num_keys]; #num_rows] = [#(#rows),*];
+    };
+    match row_index {
+        Some(row_index) => {
+            let num_locales = row_index.len();
+            (
+                quote! {
+                    #rows
+
+                    pub const ROW_INDEX: [usize; #num_locales] = [#(#row_index),*];
+                },
+                quote!([#(Self::ROWS[#row_index]),*]),
+            )
+        }
+        None => (rows, quote!(Self::ROWS)),
+    }
+}
+
+/// Emit the warnings of a table: those of `#[warn_inconsistent_locales]` and `#[warn_same_as_key]`, and
+/// one for every translation key that was trimmed
+fn table_warnings(
+    options: &TableOptions,
+    strings: &BTreeMap<String, BTreeMap<Locale, LitStr>>,
+    locales: &[Locale],
+    translation_keys: &[String],
+    trimmed_keys: &[(String, String, Span)],
+) -> Vec<TokenStream2> {
+    let mut warnings = options
+        .warn_inconsistent_locales
+        .map(|span| inconsistent_locale_warnings(options, strings, locales, translation_keys, span))
+        .unwrap_or_default();
+    if options.warn_same_as_key.is_some() {
+        warnings.extend(same_as_key_warnings(options, strings, translation_keys));
+    }
+    warnings.extend(trimmed_keys.iter().map(|(key, new_key, span)| {
+        warning(
+            *span,
+            "untrimmed_key",
+            &format!("Translation key `{key}` was trimmed to `{new_key}`"),
+        )
+    }));
+    warnings
+}
+
+/// Emit a warning for every translation key that doesn't have a translation for all of the table's locales,
+/// except for the default keys.
+///
+/// Each warning is spanned to the translations of the key (or the attribute, for keys without
+/// translations).
+fn inconsistent_locale_warnings(
+    options: &TableOptions,
+    strings: &BTreeMap<String, BTreeMap<Locale, LitStr>>,
+    locales: &[Locale],
+    translation_keys: &[String],
+    attr_span: Span,
+) -> Vec<TokenStream2> {
+    translation_keys
+        .iter()
+        .filter(|key| !options.is_default_key(key))
+        .filter_map(|key| {
+            let translations = &strings[key];
+            let missing: Vec<String> = locales
+                .iter()
+                .filter(|locale| !translations.contains_key(*locale))
+                .map(|locale| format!("`{locale}`"))
+                .collect();
+            if missing.is_empty() {
+                return None;
+            }
+            let span = locales
+                .iter()
+                .find_map(|locale| translations.get(locale))
+                .map_or(attr_span, LitStr::span);
+            let note = format!(
+                "Translation key `{key}` is missing translations for {}, which other keys have",
+                missing.join(", ")
+            );
+            Some(warning(span, "inconsistent_locales", &note))
+        })
+        .collect()
+}
+
+/// Emit a warning for every explicit translation that is the same as its key, which usually means it was
+/// copied without being translated. Default keys and the keys of `#[allow_same_as_key(...)]` are skipped.
+fn same_as_key_warnings(
+    options: &TableOptions,
+    strings: &BTreeMap<String, BTreeMap<Locale, LitStr>>,
+    translation_keys: &[String],
+) -> Vec<TokenStream2> {
+    let allowed = options.allow_same_as_key.as_deref().unwrap_or_default();
+    translation_keys
+        .iter()
+        .filter(|key| !options.is_default_key(key))
+        .filter(|key| !allowed.iter().any(|allowed| allowed.value() == **key))
+        .flat_map(|key| {
+            strings[key]
+                .iter()
+                .filter(|(_, translation)| translation.value() == *key)
+                .map(move |(locale, translation)| {
+                    let note = format!(
+                        "Translation of `{key}` in `{locale}` is the same as its key, so it may be untranslated"
+                    );
+                    warning(translation.span(), "same_as_key", &note)
+                })
+        })
+        .collect()
+}
+
+/// Emit a compiler warning at `span`.
+///
+/// There's no stable API for warnings from procedural macros, so the warning is the use of a deprecated
+/// constant called `name`, which can be silenced with `#[allow(deprecated)]`.
+fn warning(span: Span, name: &str, note: &str) -> TokenStream2 {
+    let name = Ident::new(name, span);
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #name: () = ();
+            #name
+        };
+    }
+}
+
+/// Get the names of the `LocaleHandle` constants for each locale, from `#[const_name(...)]` or
+/// [`Locale::const_ident`], making sure that no two locales get the same name, like `en-US` and `en_US`
+/// would
+fn locale_const_idents(locales: &[Locale], const_names: &[ConstName]) -> Result<Vec<Ident>> {
+    if let Some(entry) = const_names
+        .iter()
+        .find(|entry| !locales.contains(&entry.locale))
+    {
+        return Err(syn::Error::new(
+            entry.locale.span,
+            format!("Unknown locale `{}` in `const_name`", entry.locale),
+        ));
+    }
+    unique_locale_idents(locales, "constant", |locale| {
+        const_names
+            .iter()
+            .find(|entry| &entry.locale == locale)
+            .map_or_else(|| locale.const_ident(), |entry| Ok(entry.name.clone()))
+    })
+}
+
+/// Generate a `LocaleHandle` constant for every locale, documented with its `#[locale_note]` if it has one
+fn locale_handles(
+    locales: &[Locale],
+    const_names: &[ConstName],
+    docs: &[TokenStream2],
+    num_keys: usize,
+    krate: &Path,
+) -> Result<TokenStream2> {
+    let locales_upper = locale_const_idents(locales, const_names)?;
+    Ok(quote! {
+        #(
+            #docs
+            pub const #locales_upper: #krate::LocaleHandle<'static, #num_keys> = Self::TABLE.get_locale_with_default(#locales, Self::DEFAULT_LOCALE).with_placeholder(Self::PLACEHOLDER);
+        )*
+    })
+}
+
+/// The names of the variants of the generated locale enum, like `EnUs` for `en-US`
+fn locale_variants(locales: &[Locale]) -> Result<Vec<Ident>> {
+    unique_locale_idents(locales, "variant", Locale::variant_ident)
+}
+
+/// Generate an identifier for every locale, making sure that no two locales get the same one
+fn unique_locale_idents(
+    locales: &[Locale],
+    kind: &str,
+    to_ident: impl Fn(&Locale) -> Result<Ident>,
+) -> Result<Vec<Ident>> {
+    let mut idents: Vec<Ident> = Vec::with_capacity(locales.len());
+    for locale in locales {
+        let ident = to_ident(locale)?;
+        if let Some(other) = idents.iter().position(|other| *other == ident) {
+            return Err(syn::Error::new(
+                locale.span,
+                format!(
+                    "Locales `{}` and `{locale}` both generate the {kind} `{ident}`",
+                    locales[other]
+                ),
+            ));
+        }
+        idents.push(ident);
+    }
+    Ok(idents)
+}
+
+/// Generate an enum with a variant for every translation key that is a valid identifier.
+///
+/// Variants are named in `UpperCamelCase`, and their discriminant is the index of their key in the table.
+/// If several keys have the same variant name, only the first one gets a variant.
+fn build_key_enum(
+    enum_name: &Ident,
+    translation_keys: &[String],
+    descriptions: &BTreeMap<String, LitStr>,
+) -> TokenStream2 {
+    let mut variants: Vec<Ident> = Vec::new();
+    let mut variant_keys: Vec<&str> = Vec::new();
+    let mut indices: Vec<Literal> = Vec::new();
+    for (idx, key) in translation_keys.iter().enumerate() {
+        let Some(variant) = key_variant(key) else {
+            continue;
+        };
+        if variants.contains(&variant) {
+            continue;
+        }
+        variants.push(variant);
+        variant_keys.push(key);
+        indices.push(Literal::usize_unsuffixed(idx));
+    }
+    let docs = variant_keys.iter().map(|key| {
+        descriptions
+            .get(*key)
+            .map(|description| quote!(#[doc = #description]))
+    });
+    let num_variants = variants.len();
+    // an enum without variants can't have a `repr`, which happens when no key is an identifier
+    let repr = (num_variants > 0).then(|| quote!(#[repr(usize)]));
+    quote! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #repr
+        pub enum #enum_name {
+            #(#docs #variants = #indices),*
+        }
+
+        impl #enum_name {
+            const ALL: [Self; #num_variants] = [#(Self::#variants),*];
+
+            pub const fn all() -> &'static [Self] {
+                &Self::ALL
+            }
+
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(Self::#variants => #variant_keys),*
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    }
+}
+
+/// Generate the functions that create locale handles and look up translations, respecting the aliases
+/// and default locale of the table
+fn lookup_functions(krate: &Path, num_keys: usize, key_enum_name: &Ident) -> TokenStream2 {
+    quote! {
+        #[inline(always)]
+        pub const fn get_locale(locale: &str) -> #krate::LocaleHandle<'static, #num_keys> {
+            Self::TABLE
+                .get_locale_with_default(Self::resolve_locale(locale), Self::DEFAULT_LOCALE)
+                .with_placeholder(Self::PLACEHOLDER)
+        }
+
+        #[inline(always)]
+        pub const fn try_get_locale(locale: &str) -> ::core::option::Option<#krate::LocaleHandle<'static, #num_keys>> {
+            match Self::TABLE.try_get_locale_with_default(Self::resolve_locale(locale), Self::DEFAULT_LOCALE) {
+                Some(handle) => Some(handle.with_placeholder(Self::PLACEHOLDER)),
+                None => None,
+            }
+        }
+
+        #[inline(always)]
+        pub const fn with_locale(locale: &str) -> #krate::LocaleHandle<'static, #num_keys> {
+            Self::get_locale(locale)
+        }
+
+        pub fn localize_in(translation_key: &str, locale: impl #krate::AsLocale) -> &'static str {
+            Self::localize(translation_key, locale.as_locale())
+        }
+
+        pub fn localize_resolved(translation_key: &str, locale: &str) -> (&'static str, #krate::Resolution<'static>) {
+            let (translation, resolution) = Self::TABLE.localize_resolved_with_explicit(translation_key, Self::resolve_locale(locale), Self::DEFAULT_LOCALE, &Self::EXPLICIT);
+            (#krate::replace_placeholder(translation, Self::PLACEHOLDER), resolution)
+        }
+
+        pub fn localize_ns(namespace: &str, translation_key: &str, locale: &str) -> &'static str {
+            Self::get_locale(locale).namespace(namespace).localize(translation_key)
+        }
+
+        pub fn localize_count(translation_key: &str, locale: &str, count: u64) -> &'static str {
+            Self::get_locale(locale).localize_count(translation_key, count)
+        }
+
+        pub const fn is_explicit(translation_key: &str, locale: &str) -> bool {
+            let Some(locale_idx) = Self::TABLE.locale_index(Self::resolve_locale(locale)) else {
+                return false;
+            };
+            match Self::KEY_INDEX.get(&Self::TABLE.translation_keys, translation_key) {
+                Some(key_idx) => Self::EXPLICIT[locale_idx][key_idx],
+                None => false,
+            }
+        }
+
+        pub fn locales_with_translation(translation_key: &str) -> impl ::core::iter::Iterator<Item = &'static str> {
+            let key_idx = Self::KEY_INDEX.get(&Self::TABLE.translation_keys, translation_key);
+            Self::TABLE
+                .locales
+                .into_iter()
+                .zip(Self::EXPLICIT)
+                .filter_map(move |(locale, explicit)| match key_idx {
+                    Some(idx) if explicit[idx] => Some(locale),
+                    _ => None,
+                })
+        }
+
+        pub fn localize_first(translation_keys: &[&str], locale: &str) -> ::core::option::Option<&'static str> {
+            let locale_idx = Self::TABLE.locale_index(Self::resolve_locale(locale))?;
+            translation_keys.iter().find_map(|translation_key| {
+                let key_idx = Self::KEY_INDEX.get(&Self::TABLE.translation_keys, translation_key)?;
+                Self::EXPLICIT[locale_idx][key_idx].then_some(Self::TABLE.translations[locale_idx][key_idx])
+            })
+        }
+
+        pub const fn localize_key(translation_key: #key_enum_name, locale: &str) -> &'static str {
+            let locale_idx = match Self::TABLE.locale_index(Self::resolve_locale(locale)) {
+                Some(idx) => idx,
+                None => Self::DEFAULT_LOCALE,
+            };
+            #krate::replace_placeholder(
+                Self::TABLE.localize_indices_with_default(locale_idx, translation_key as usize, Self::DEFAULT_LOCALE),
+                Self::PLACEHOLDER,
+            )
+        }
+    }
+}
+
+/// Generate `CONTEXTS`, with every context variant of the table, and the `localize_ctx` function that
+/// looks one up
+fn context_functions(contexts: &[ContextVariant], krate: &Path) -> TokenStream2 {
+    let num_contexts = contexts.len();
+    let contexts = contexts.iter().map(|variant| variant.to_struct(krate));
+    quote! {
+        pub const CONTEXTS: [#krate::ContextVariant<'static>; #num_contexts] = [#(#contexts),*];
+
+        pub const fn localize_ctx(translation_key: &str, locale: &str, context: &str) -> &'static str {
+            let locale = Self::resolve_locale(locale);
+            match #krate::ContextVariant::find(&Self::CONTEXTS, translation_key, locale, context) {
+                Some(translation) => translation,
+                None => Self::localize(translation_key, locale),
+            }
+        }
+    }
+}
+
+/// Generate `DESCRIPTIONS`, with the description of every translation key in the same order as
+/// `translation_keys`, and the `description` function to look one up by key
+fn description_functions(
+    descriptions: &BTreeMap<String, LitStr>,
+    translation_keys: &[String],
+) -> TokenStream2 {
+    let num_keys = translation_keys.len();
+    let descriptions = translation_keys.iter().map(|key| {
+        descriptions.get(key).map_or_else(
+            || quote!(::core::option::Option::None),
+            |description| quote!(::core::option::Option::Some(#description)),
+        )
+    });
+    quote! {
+        pub const DESCRIPTIONS: [::core::option::Option<&'static str>; #num_keys] = [#(#descriptions),*];
+
+        pub const fn description(translation_key: &str) -> ::core::option::Option<&'static str> {
+            match Self::KEY_INDEX.get(&Self::TABLE.translation_keys, translation_key) {
+                Some(idx) => Self::DESCRIPTIONS[idx],
+                None => None,
+            }
+        }
+    }
+}
+
+/// Implement `Localizable` for the table with its inherent functions
+fn localizable_impl(struct_name: &Ident, krate: &Path, num_keys: usize) -> TokenStream2 {
+    quote! {
+        impl #krate::Localizable for #struct_name {
+            type Handle = #krate::LocaleHandle<'static, #num_keys>;
+
+            fn localize(translation_key: &str, locale: &str) -> &'static str {
+                Self::localize(translation_key, locale)
+            }
+
+            fn try_localize(translation_key: &str, locale: &str) -> ::core::option::Option<&'static str> {
+                Self::TABLE.try_localize(translation_key, Self::resolve_locale(locale))
+            }
+
+            fn get_locale(locale: &str) -> Self::Handle {
+                Self::get_locale(locale)
+            }
+
+            fn locales() -> &'static [&'static str] {
+                &Self::TABLE.locales
+            }
+
+            fn translation_keys() -> &'static [&'static str] {
+                &Self::TABLE.translation_keys
+            }
+        }
+    }
+}
+
+/// Generate the enum with a variant for every locale of the table, in the same order as its `locales`
+fn build_locale_enum(
+    enum_name: &Ident,
+    struct_name: &Ident,
+    locales: &[Locale],
+    docs: &[TokenStream2],
+    num_keys: usize,
+    krate: &Path,
+) -> Result<TokenStream2> {
+    let variants = locale_variants(locales)?;
+    let indices = (0..locales.len()).map(Literal::usize_unsuffixed);
+    let num_locales = locales.len();
+    let repr = (num_locales > 0).then(|| quote!(#[repr(usize)]));
+    Ok(quote! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #repr
+        pub enum #enum_name {
+            #(#docs #variants = #indices),*
+        }
+
+        impl #enum_name {
+            const ALL: [Self; #num_locales] = [#(Self::#variants),*];
+
+            pub const fn all() -> &'static [Self] {
+                &Self::ALL
+            }
+
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    #(Self::#variants => #locales),*
+                }
+            }
+
+            pub const fn handle(self) -> #krate::LocaleHandle<'static, #num_keys> {
+                #struct_name::TABLE.get_locale(self.as_str())
+            }
+
+            pub fn from_str_fallback(locale: &str) -> ::core::option::Option<Self> {
+                let locale = #struct_name::TABLE.negotiate(&[#struct_name::resolve_locale(locale)])?;
+                #struct_name::TABLE.locale_index(locale).map(|idx| Self::ALL[idx])
+            }
+        }
+
+        impl #krate::AsLocale for #enum_name {
+            fn as_locale(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for #enum_name {
+            type Error = #krate::UnknownLocale;
+
+            fn try_from(locale: &str) -> ::core::result::Result<Self, Self::Error> {
+                match #struct_name::TABLE.locale_index(#struct_name::resolve_locale(locale)) {
+                    ::core::option::Option::Some(idx) => ::core::result::Result::Ok(Self::ALL[idx]),
+                    ::core::option::Option::None => ::core::result::Result::Err(#krate::UnknownLocale),
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for #enum_name {
+            type Err = #krate::UnknownLocale;
+
+            fn from_str(locale: &str) -> ::core::result::Result<Self, Self::Err> {
+                <Self as ::core::convert::TryFrom<&str>>::try_from(locale)
+            }
+        }
+    })
+}
+
+/// Generate the struct for the `#[strings_struct]` attribute, with a field for every translation key except
+/// `"_"`, and the `localize_struct` function that fills it in for a locale
+fn build_strings_struct(
+    struct_name: &Ident,
+    translation_keys: &[String],
+    descriptions: &BTreeMap<String, LitStr>,
+) -> Result<TokenStream2> {
+    let strings_name = format_ident!("{struct_name}Strings");
+    let mut fields: Vec<Ident> = Vec::new();
+    let mut field_keys: Vec<&str> = Vec::new();
+    let mut indices: Vec<Literal> = Vec::new();
+    for (idx, key) in translation_keys.iter().enumerate() {
+        if key == "_" {
+            continue;
+        }
+        let field = key_field(key);
+        if let Some(other) = fields.iter().position(|other| *other == field) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "Translation keys `{}` and `{key}` both generate the field `{field}`",
+                    field_keys[other]
+                ),
+            ));
+        }
+        fields.push(field);
+        field_keys.push(key);
+        indices.push(Literal::usize_unsuffixed(idx));
+    }
+    let docs = field_keys.iter().map(|key| {
+        descriptions
+            .get(*key)
+            .map_or_else(|| format!("The translation of `{key}`"), LitStr::value)
+    });
+    Ok(quote! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct #strings_name {
+            #(
+                #[doc = #docs]
+                pub #fields: &'static str,
+            )*
+        }
+
+        impl #struct_name {
+            pub const fn localize_struct(locale: &str) -> #strings_name {
+                let locale_idx = match Self::TABLE.locale_index(Self::resolve_locale(locale)) {
+                    Some(idx) => idx,
+                    None => Self::DEFAULT_LOCALE,
+                };
+                #strings_name {
+                    #(#fields: Self::TABLE.localize_indices_with_default(locale_idx, #indices, Self::DEFAULT_LOCALE),)*
+                }
+            }
+        }
+    })
+}
+
+/// The `snake_case` field name for a translation key, with every character that can't be part of an
+/// identifier replaced by `_`, like `menu_open` for `"menu.open"`
+fn key_field(translation_key: &str) -> Ident {
+    let mut name: String = translation_key
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) || name.chars().all(|c| c == '_') {
+        name.insert(0, 'k');
+    }
+    syn::parse_str::<Ident>(&name).unwrap_or_else(|_| Ident::new_raw(&name, Span::call_site()))
+}
+
+/// The `UpperCamelCase` variant name for a translation key, if the key is a valid identifier
+fn key_variant(translation_key: &str) -> Option<Ident> {
+    let ident = Ident::parse_any.parse_str(translation_key).ok()?;
+    if ident != translation_key {
+        return None;
+    }
+    let name: String = translation_key
+        .split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect();
+    syn::parse_str(&name).ok()
+}
+
+/// Lay out the slots of a `localize::KeyIndex` for the given (sorted) translation keys.
+///
+/// This must stay in sync with `KeyIndex::get` and `localize::hash_key`.
+fn build_key_index(translation_keys: &[String], krate: &Path) -> Vec<TokenStream2> {
+    let len = (translation_keys.len() * 2).next_power_of_two();
+    let mask = len - 1;
+    let mut slots: Vec<Option<usize>> = vec![None; len];
+    for (idx, key) in translation_keys.iter().enumerate() {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut slot = hash_key(key) as usize & mask;
+        while slots[slot].is_some() {
+            slot = (slot + 1) & mask;
+        }
+        slots[slot] = Some(idx);
+    }
+    slots
+        .into_iter()
+        .map(|slot| slot.map_or_else(|| quote! { #krate::KeyIndex::EMPTY }, |idx| quote! { #idx }))
+        .collect()
+}
+
+/// The 64-bit FNV-1a hash, matching `localize::hash_key`
+fn hash_key(translation_key: &str) -> u64 {
+    translation_key
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(src: &str) -> Result<TokenStream2> {
+        expand(syn::parse_str(src)?)
+    }
+
+    fn error_message(src: &str) -> String {
+        match expand_str(src) {
+            Ok(_) => panic!("expected `{src}` to fail to expand"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_locale() {
+        assert!(expand_str(
+            r#"T = LDSL #[default_locale(es)] { greeting = { en => "Hello", es => "Hola" } }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(r#"T = LDSL #[default_locale(de)] { greeting = { en => "Hello" } }"#),
+            "Unknown default locale `de`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[default_locale(en)] #[default_locale(en)] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate `default_locale` attribute"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL #[fallback(en)] { greeting = { en => "Hello" } }"#),
+            "Unknown table attribute"
+        );
+    }
+
+    #[test]
+    fn test_contexts() {
+        assert!(expand_str(
+            r#"T = LDSL { welcome = { es => "Bienvenido", es(feminine) => "Bienvenida", es(masculine) => "Bienvenido" } }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(
+                r#"T = LDSL { welcome = { es(feminine) => "Bienvenida", es(feminine) => "Bienvenida" } }"#
+            ),
+            "Duplicate context identifier in translation"
+        );
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert!(expand_str(
+            r#"T = LDSL #[alias(english = en, spanish = es)] { greeting = { en => "Hello", es => "Hola" } }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[alias(en = es)] { greeting = { en => "Hello", es => "Hola" } }"#
+            ),
+            "Alias `en` collides with the locale of the same name"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL #[alias(german = de)] { greeting = { en => "Hello" } }"#),
+            "Unknown locale `de` for alias `german`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[alias(english = en)] #[alias(english = en)] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate alias `english`"
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert!(expand_str(r#"T = CSV "../tests/fixtures/strings.csv""#).is_ok());
+        assert_eq!(
+            error_message(r#"T = CSV "../tests/fixtures/malformed.csv""#),
+            "Malformed row on line 3 of `../tests/fixtures/malformed.csv`: expected 3 cells, found 2"
+        );
+        assert!(error_message(r#"T = CSV "../tests/fixtures/missing.csv""#)
+            .starts_with("Couldn't read `../tests/fixtures/missing.csv`"));
+        assert_eq!(
+            csv::parse("key,en\ngreeting,\"Hello\n").err(),
+            Some(csv::CsvError {
+                line: 2,
+                message: "unterminated quoted cell"
+            })
+        );
+        assert_eq!(
+            csv::parse("key,en\r\n\r\ngreeting,\"Hello, \"\"friend\"\"\"\r\n").map(|records| {
+                records
+                    .into_iter()
+                    .map(|r| (r.line, r.cells))
+                    .collect::<Vec<_>>()
+            }),
+            Ok(vec![
+                (1, vec!["key".to_owned(), "en".to_owned()]),
+                (
+                    3,
+                    vec!["greeting".to_owned(), "Hello, \"friend\"".to_owned()]
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_po() {
+        assert!(expand_str(
+            r#"T = PO { en => "../tests/fixtures/en.po", es => "../tests/fixtures/es.po" }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(
+                r#"T = PO { en => "../tests/fixtures/en.po", en => "../tests/fixtures/en.po" }"#
+            ),
+            "Duplicate locale `en`"
+        );
+        let entry_error = |source| po::parse(source).err().map(|err| (err.line, err.message));
+        assert_eq!(
+            entry_error("msgid \"greeting\"\n\nmsgid \"farewell\"\nmsgstr \"Goodbye\""),
+            Some((1, "`msgid` without a `msgstr`".to_owned()))
+        );
+        assert_eq!(
+            entry_error("msgctxt \"menu\"\nmsgid \"open\"\nmsgstr \"Open\""),
+            Some((1, "unsupported keyword `msgctxt`".to_owned()))
+        );
+        assert_eq!(
+            entry_error("msgid \"greeting\"\nmsgstr \"Hello\\q\""),
+            Some((2, "unknown escape sequence `\\q`".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_key_variant() {
+        let variant = |key| key_variant(key).map(|ident| ident.to_string());
+        assert_eq!(variant("greeting"), Some("Greeting".to_owned()));
+        assert_eq!(
+            variant("farewell_message"),
+            Some("FarewellMessage".to_owned())
+        );
+        assert_eq!(variant("type"), Some("Type".to_owned()));
+        assert_eq!(variant("_"), None);
+        assert_eq!(variant("menu.open"), None);
+        assert_eq!(variant("1st"), None);
+    }
+
+    #[test]
+    fn test_hyphenated_locales() {
+        assert!(expand_str(
+            r#"T = LDSL #[default_locale(en-US)] #[alias(american = en-US)] { color = { en-US => "Color", "en-GB" => "Colour", es-419 => "Color" } }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(r#"T = LDSL { color = { en-US => "Color", en_US => "Color" } }"#),
+            "Locales `en-US` and `en_US` both generate the constant `EN_US`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { color = { "en US" => "Color" } }"#),
+            "Invalid locale `en US`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { color = { en-US => "Color", "en-US" => "Color" } }"#),
+            "Duplicate locale identifier in translation"
+        );
+    }
+
+    #[test]
+    fn test_inconsistent_locales() {
+        let warnings = expand_str(
+            r#"T = LDSL #[warn_inconsistent_locales] { "_" = { en => "?" }, apple = { en => "Apple", fr => "Pomme" }, greeting = { en => "Hello", es => "Hola" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(warnings.contains(
+            "Translation key `apple` is missing translations for `es`, which other keys have"
+        ));
+        assert!(warnings.contains(
+            "Translation key `greeting` is missing translations for `fr`, which other keys have"
+        ));
+        assert!(!warnings.contains("Translation key `_`"));
+        assert!(!expand_str(
+            r#"T = LDSL { apple = { en => "Apple", fr => "Pomme" }, greeting = { en => "Hello" } }"#
+        )
+        .unwrap()
+        .to_string()
+        .contains("deprecated"));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[warn_inconsistent_locales] #[warn_inconsistent_locales] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate `warn_inconsistent_locales` attribute"
+        );
+    }
+
+    #[test]
+    fn test_crate_path() {
+        let expanded = expand_str(
+            r#"T = LDSL #[crate(crate::deps::localize)] { greeting = { en => "Hello", en(formal) => "Good day" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(expanded.contains("crate :: deps :: localize :: LocalizationTable"));
+        assert!(!expanded
+            .replace("crate :: deps :: localize ::", "")
+            .contains("localize ::"));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[crate(my_localize)] #[crate(my_localize)] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate `crate` attribute"
+        );
+    }
+
+    #[test]
+    fn test_json_dir() {
+        assert!(expand_str(r#"T = JSON_DIR "../tests/fixtures/i18n""#).is_ok());
+        assert!(error_message(r#"T = JSON_DIR "../tests/fixtures/missing""#)
+            .starts_with("Couldn't read `../tests/fixtures/missing`"));
+        let mut translations = Translations {
+            strings: BTreeMap::new(),
+            contexts: Vec::new(),
+            locales: Vec::new(),
+            descriptions: BTreeMap::new(),
+        };
+        let file = LitStr::new("i18n/en.json", Span::call_site());
+        assert_eq!(
+            translations
+                .add_json(r#"{ "count": 5 }"#, &file)
+                .unwrap_err()
+                .to_string(),
+            "Expected a string for translation key `count` in `i18n/en.json`, found `5`"
+        );
+        assert!(translations
+            .add_json(r#"{ "greeting": "Hello" "#, &file)
+            .unwrap_err()
+            .to_string()
+            .starts_with("Couldn't parse `i18n/en.json`"));
+        assert_eq!(
+            translations
+                .add_json(r#"{ "greeting": "Hello", "greeting": "Hi" }"#, &file)
+                .unwrap_err()
+                .to_string(),
+            "Duplicate translation key `greeting` in `i18n/en.json`"
+        );
+        let es = LitStr::new("i18n/es.json", Span::call_site());
+        assert!(translations
+            .add_json(r#"{ "greeting": "Hola" }"#, &es)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_complete() {
+        assert!(expand_str(
+            r#"T = LDSL #[complete] { greeting = { en => "Hello", es => "Hola" } }"#
+        )
+        .unwrap()
+        .to_string()
+        .contains("T :: TABLE . is_complete ()"));
+        assert_eq!(
+            error_message(r#"T = LDSL #[complete] #[complete] { greeting = { en => "Hello" } }"#),
+            "Duplicate `complete` attribute"
+        );
+    }
+
+    #[test]
+    fn test_locale_meta() {
+        let tokens = expand_str(
+            r#"T = LDSL #[locale_meta(ar = { rtl, date_format = "%d/%m/%Y" }, he = { rtl })] { greeting = { ar => "مرحبا", en => "Hello", he => "שלום" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(
+            "LOCALE_META : [:: localize :: LocaleMeta ; 3usize] = [:: localize :: LocaleMeta { rtl : true , date_format : \"%d/%m/%Y\" } , :: localize :: LocaleMeta { rtl : false , date_format : :: localize :: LocaleMeta :: DEFAULT . date_format } , :: localize :: LocaleMeta { rtl : true , date_format : :: localize :: LocaleMeta :: DEFAULT . date_format }]"
+        ));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locale_meta(ar = { rtl })] { greeting = { en => "Hello" } }"#
+            ),
+            "Unknown locale `ar` in `locale_meta`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locale_meta(ar = { ltr })] { greeting = { ar => "مرحبا" } }"#
+            ),
+            "Unknown locale metadata `ltr`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locale_meta(ar = { rtl })] #[locale_meta(ar = {})] { greeting = { ar => "مرحبا" } }"#
+            ),
+            "Duplicate metadata for locale `ar`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locale_meta(ar = { date_format = "%d/%m/%Y", date_format = "%Y" })] { greeting = { ar => "مرحبا" } }"#
+            ),
+            "Duplicate `date_format` for locale `ar`"
+        );
+    }
+
+    #[test]
+    fn test_toml() {
+        assert!(expand_str(r#"T = TOML "../tests/fixtures/strings.toml""#).is_ok());
+        assert!(
+            error_message(r#"T = TOML "../tests/fixtures/missing.toml""#)
+                .starts_with("Couldn't read `../tests/fixtures/missing.toml`")
+        );
+        let path = LitStr::new("strings.toml", Span::call_site());
+        let error = |contents: &str| {
+            Translations::parse_toml(contents, &path)
+                .err()
+                .unwrap()
+                .to_string()
+        };
+        assert!(error("[greeting\nen = 1").starts_with("Couldn't parse `strings.toml`"));
+        assert_eq!(
+            error("title = \"Hello\""),
+            "Expected a table for translation key `title` in `strings.toml`, found `\"Hello\"`"
+        );
+        assert_eq!(
+            error("[greeting]\nen = \"Hello\"\nes = 5"),
+            "Expected a string for locale `es` of translation key `greeting` in `strings.toml`, found `5`"
+        );
+        assert_eq!(
+            error("[greeting]\n\"e n\" = \"Hello\""),
+            "Invalid locale `e n` in translation key `greeting` of `strings.toml`"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        assert_eq!(
+            error_message(
+                r#"T = LDSL { greeting = { en => "Hello" }, "greeting" = { es => "Hola" } }"#
+            ),
+            "Duplicate translation key `greeting`"
+        );
+        let path = LitStr::new("strings.csv", Span::call_site());
+        assert_eq!(
+            Translations::parse_csv("key,en\ngreeting,Hello\ngreeting,Hi\n", &path)
+                .err()
+                .unwrap()
+                .to_string(),
+            "Malformed row on line 3 of `strings.csv`: duplicate translation key `greeting`"
+        );
+    }
+
+    #[test]
+    fn test_key_references() {
+        let translations = Translations::parse_ldsl
+            .parse_str(
+                r#"{ submit = { en => "Submit", es => "Enviar" }, submit_alt = @ submit, "send" = @ "submit_alt" }"#,
+            )
+            .unwrap();
+        for key in ["submit_alt", "send"] {
+            assert_eq!(
+                translations.strings[key]
+                    .iter()
+                    .map(|(locale, value)| (locale.name.clone(), value.value()))
+                    .collect::<Vec<_>>(),
+                [
+                    ("en".to_owned(), "Submit".to_owned()),
+                    ("es".to_owned(), "Enviar".to_owned())
+                ]
+            );
+        }
+        assert_eq!(
+            error_message(r#"T = LDSL { submit_alt = @ "submit" }"#),
+            "Unknown translation key `submit` referenced by `submit_alt`"
+        );
+        assert_eq!(
+            error_message("T = LDSL { a = @ b, b = @ a }"),
+            "Cyclic reference `a` -> `b` -> `a`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { a = { en => "A" }, a = @ a }"#),
+            "Duplicate translation key `a`"
+        );
+    }
+
+    #[test]
+    fn test_validate_locales() {
+        for tag in [
+            "en",
+            "en-US",
+            "zh-Hant",
+            "zh-Hant-TW",
+            "es-419",
+            "zh-yue-HK",
+            "sl-rozaj-biske",
+            "de-CH-1901",
+            "en-US-u-ca-buddhist",
+            "en-x-pirate",
+            "x-klingon",
+        ] {
+            assert_eq!(bcp47::validate(tag), Ok(()), "{tag}");
+        }
+        for (tag, reason) in [
+            (
+                "english123",
+                "`english123` isn't a language subtag of 2-3 or 5-8 letters",
+            ),
+            (
+                "en_US",
+                "`en_US` isn't a language subtag of 2-3 or 5-8 letters",
+            ),
+            ("en--US", "found an empty subtag"),
+            ("en-USA1", "unexpected subtag `USA1`"),
+            (
+                "en-u",
+                "the extension `u` must be followed by subtags of 2-8 characters",
+            ),
+            ("en-x", "`x` must be followed by private use subtags"),
+        ] {
+            assert_eq!(bcp47::validate(tag), Err(reason.to_owned()), "{tag}");
+        }
+        assert!(expand_str(
+            r#"T = LDSL #[validate_locales] { greeting = { en-US => "Hello", "zh-Hant" => "你好" } }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(r#"T = LDSL #[validate_locales] { greeting = { english123 => "Hello" } }"#),
+            "Invalid BCP 47 language tag `english123`: `english123` isn't a language subtag of 2-3 or 5-8 letters"
+        );
+        // without the attribute, any locale is allowed
+        assert!(expand_str(r#"T = LDSL { greeting = { english123 => "Hello" } }"#).is_ok());
+    }
+
+    #[test]
+    fn test_ldsl_commas() {
+        let keys = |src: &str| {
+            Translations::parse_ldsl
+                .parse_str(src)
+                .unwrap()
+                .strings
+                .into_keys()
+                .collect::<Vec<_>>()
+        };
+        let expected = ["farewell", "greeting", "welcome"];
+        assert_eq!(
+            keys(
+                r#"{ greeting = { en => "Hello" } farewell = { en => "Bye" } welcome = @ greeting }"#
+            ),
+            expected
+        );
+        assert_eq!(
+            keys(
+                r#"{ greeting = { en => "Hello", }, farewell = { en => "Bye", es => "Adiós", }, welcome = @ greeting, }"#
+            ),
+            expected
+        );
+        // references still need a comma, since the next key would be ambiguous to read
+        assert!(Translations::parse_ldsl
+            .parse_str(r#"{ welcome = @ greeting greeting = { en => "Hello" } }"#)
+            .is_err());
+    }
+
+    #[test]
+    fn test_empty_key_block() {
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting = { en => "Hello" }, "orphan" = {} }"#),
+            "Translation key `orphan` has no translations"
+        );
+        // a key with only context variants isn't empty
+        assert!(expand_str(r#"T = LDSL { welcome = { es(feminine) => "Bienvenida" } }"#).is_ok());
+    }
+
+    #[test]
+    fn test_locale_enum() {
+        let tokens = expand_str(r#"T = LDSL { greeting = { en-US => "Hello", "zh-Hant" => "你好", es-419 => "Hola" } }"#)
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains("pub enum TLocale { EnUs = 0 , Es419 = 1 , ZhHant = 2 }"));
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting = { "en--us" => "Hello", "en-us" => "Hello" } }"#),
+            "Locales `en--us` and `en-us` both generate the variant `EnUs`"
+        );
+    }
+
+    #[test]
+    fn test_strings_struct() {
+        assert_eq!(key_field("menu.open"), "menu_open");
+        assert_eq!(key_field("Farewell Message"), "farewell_message");
+        assert_eq!(key_field("404"), "k404");
+        assert_eq!(key_field("type"), "r#type");
+        assert!(expand_str(
+            r#"T = LDSL #[strings_struct] { "_" = { en => "?" }, greeting = { en => "Hello" } }"#
+        )
+        .unwrap()
+        .to_string()
+        .contains("pub struct TStrings { # [doc = \"The translation of `greeting`\"] pub greeting : & 'static str , }"));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[strings_struct] { "menu.open" = { en => "Open" }, menu_open = { en => "Open" } }"#
+            ),
+            "Translation keys `menu.open` and `menu_open` both generate the field `menu_open`"
+        );
+    }
+
+    #[test]
+    fn test_trim_keys() {
+        let output = expand_str(
+            r#"T = LDSL { "greeting " = { en => "Hello", es(formal) => "Buenos días" }, farewell = { en => "Goodbye" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(output.contains("Translation key `greeting ` was trimmed to `greeting`"));
+        assert!(output.contains(r#"translation_keys : ["farewell" , "greeting"]"#));
+        assert!(output.contains(r#"key : "greeting""#));
+        assert!(!expand_str(r#"T = LDSL { greeting = { en => "Hello" } }"#)
+            .unwrap()
+            .to_string()
+            .contains("deprecated"));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL { greeting = { en => "Hello" }, " greeting" = { en => "Hi" } }"#
+            ),
+            "Translation keys ` greeting` and `greeting` are the same after trimming whitespace"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            error_message(r#"T LDSL { greeting = { en => "Hello" } }"#),
+            "Expected `=` after the table name `T`"
+        );
+        assert_eq!(
+            error_message(r#"T = { greeting = { en => "Hello" } }"#),
+            "Expected a syntax type: LDSL, LDSL_BY_LOCALE, LDSL_MULTI, CSV, PO, JSON_DIR, or TOML"
+        );
+        assert_eq!(
+            error_message(r#"T = YAML "strings.yaml""#),
+            "Unsupported syntax type `YAML`, expected LDSL, LDSL_BY_LOCALE, LDSL_MULTI, CSV, PO, JSON_DIR, or TOML"
+        );
+        assert_eq!(
+            error_message(r#"T = YAML #[complete] "strings.yaml""#),
+            "Unsupported syntax type `YAML`, expected LDSL, LDSL_BY_LOCALE, LDSL_MULTI, CSV, PO, JSON_DIR, or TOML"
+        );
+        assert_eq!(
+            error_message(r#"T = ldsl { greeting = { en => "Hello" } }"#),
+            "Unsupported syntax type `ldsl`, did you mean `LDSL`?"
+        );
+        assert_eq!(
+            error_message(r#"T = Json_Dir "locales""#),
+            "Unsupported syntax type `Json_Dir`, did you mean `JSON_DIR`?"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting { en => "Hello" } }"#),
+            "Expected `=` after translation key `greeting`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting = en => "Hello" }"#),
+            "Expected `{` with the translations of translation key `greeting`, or `@` followed by another key"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting = { en "Hello" } }"#),
+            "Expected `=>` after locale `en`"
+        );
+        assert_eq!(
+            error_message("T = LDSL { greeting = { en-US => Hello } }"),
+            "Expected a string literal with the translation for locale `en-US`"
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let source = generate(
+            r#"Spanglish = LDSL #[default_locale(en)] { greeting = { en => "Hello", es => "Hola" } }"#,
+        )
+        .unwrap();
+        let file = syn::parse_file(&source).unwrap();
+        assert!(file.items.iter().any(|item| matches!(
+            item,
+            syn::Item::Struct(item) if item.ident == "Spanglish"
+        )));
+        assert_eq!(
+            generate("Spanglish = LDSL { greeting = { en => } }")
+                .unwrap_err()
+                .to_string(),
+            "Expected a string literal with the translation for locale `en`"
+        );
+        assert!(generate("Spanglish = LDSL { \"unclosed }").is_err());
+    }
+
+    #[test]
+    fn test_integer_keys() {
+        let output = expand_str(
+            r#"T = LDSL { 404 = { en => "Not Found" }, 0x1F4 = { en => "Internal Server Error" }, ok = @ 200, 200 = { en => "OK" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(output.contains(r#"translation_keys : ["200" , "404" , "500" , "ok"]"#));
+        assert_eq!(
+            error_message(r#"T = LDSL { 404u16 = { en => "Not Found" } }"#),
+            "Translation key `404u16` can't have a type suffix"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL { 404 = { en => "Not Found" }, "404" = { en => "Missing" } }"#
+            ),
+            "Duplicate translation key `404`"
+        );
+    }
+
+    #[test]
+    fn test_expected_locales() {
+        assert!(expand_str(
+            r#"T = LDSL #[locales(es, en)] { greeting = { en => "Hello", es => "Hola" } }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locales(en, es, fr)] { greeting = { en => "Hello", es => "Hola" } }"#
+            ),
+            "Locale `fr` from `locales` has no translations"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locales(en)] { greeting = { en => "Hello", es => "Hola" } }"#
+            ),
+            "Unexpected locale `es`, which isn't listed in `locales`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL #[locales(en, en)] { greeting = { en => "Hello" } }"#),
+            "Duplicate locale `en` in `locales`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locales(en)] #[locales(en)] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate `locales` attribute"
+        );
+    }
+
+    #[test]
+    fn test_underscore_locale() {
+        let message = "`_` can't be used as a locale, because `\"_\"` is the translation key for default translations";
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting = { _ => "x" } }"#),
+            message
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting = { en => "Hello", "_" => "x" } }"#),
+            message
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL #[default_locale(_)] { greeting = { en => "Hello" } }"#),
+            message
+        );
+    }
+
+    #[test]
+    fn test_preserve_order() {
+        let input = r#"T = LDSL #[preserve_order] #[default_locale(en)] {
+            greeting = { fr => "Bonjour", en => "Hello" }
+        }"#;
+        let tokens = expand_str(input).unwrap().to_string();
+        assert!(tokens.contains(r#"locales : ["fr" , "en"]"#));
+        assert!(tokens.contains("pub const DEFAULT_LOCALE : usize = 1usize ;"));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[preserve_order] #[preserve_order] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate `preserve_order` attribute"
+        );
+    }
+
+    fn merge_error_message(src: &str) -> String {
+        match expand_merge_tokens(src.parse().unwrap()) {
+            Ok(_) => panic!("expected `{src}` to fail to expand"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_merge_tables() {
+        assert!(expand_merge_tokens(
+            r#"App = { A = LDSL { greeting = { en => "Hello" } }, B = LDSL { greeting = { es => "Hola" } } }"#
+                .parse()
+                .unwrap()
+        )
+        .is_ok());
+        assert_eq!(
+            merge_error_message(
+                r#"App = { A = LDSL { greeting = { en => "Hello" } }, B = LDSL { greeting = { en => "Hi" } } }"#
+            ),
+            "Translation key `greeting` has a translation for locale `en` in both `A` and `B`"
+        );
+        assert_eq!(
+            merge_error_message(
+                r#"App = {
+                    A = LDSL { welcome = { es => "Bienvenido", es(feminine) => "Bienvenida" } },
+                    B = LDSL { welcome = { en => "Welcome", es(feminine) => "Bienvenida" } },
+                }"#
+            ),
+            "Translation key `welcome` has a translation for locale `es` in context `feminine` in both `A` and `B`"
+        );
+        assert_eq!(
+            merge_error_message("App = {}"),
+            "`App` doesn't have any tables to merge"
+        );
+    }
+
+    #[test]
+    fn test_display_names() {
+        let tokens = expand_str(
+            r#"T = LDSL #[display_name(es = "Español")] { greeting = { en => "Hello", es => "Hola" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(r#"DISPLAY_NAMES : [& 'static str ; 2usize] = ["en" , "Español"]"#));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[display_name(fr = "Français")] { greeting = { en => "Hello" } }"#
+            ),
+            "Unknown locale `fr` in `display_name`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[display_name(en = "English")] #[display_name(en = "Anglais")] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate display name for locale `en`"
+        );
+    }
+
+    #[test]
+    fn test_dedent() {
+        assert_eq!(
+            dedent_str(
+                "\n    Usage:\n        app [options]\n\n    Options:\n        -h  Help\n    "
+            ),
+            "Usage:\n    app [options]\n\nOptions:\n    -h  Help"
+        );
+        assert_eq!(dedent_str("  one line  "), "one line  ");
+        assert_eq!(dedent_str("first\n  second"), "first\n  second");
+        assert_eq!(dedent_str("\n\ttabbed\n\t\tmore\n"), "tabbed\n\tmore");
+        assert_eq!(dedent_str("\n  a\n      \n  b\n"), "a\n\nb");
+        assert_eq!(dedent_str(""), "");
+        let tokens = expand_str(
+            "T = LDSL { help = { en => dedent \"\n    Line one\n      Line two\n    \" } }",
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(r#""Line one\n  Line two""#));
+    }
+
+    #[test]
+    fn test_locale_case() {
+        assert_eq!(
+            error_message(
+                r#"T = LDSL { greeting = { en => "Hello" }, farewell = { En => "Goodbye" } }"#
+            ),
+            "Locales `en` and `En` only differ by case, but locales are case-sensitive"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { greeting = { en-US => "Hello", "en-us" => "Howdy" } }"#),
+            "Locales `en-US` and `en-us` only differ by case, but locales are case-sensitive"
+        );
+        assert!(
+            expand_str(r#"T = LDSL { greeting = { en => "Hello", en-US => "Howdy" } }"#).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_large_input() {
+        // written in reverse order, so that every key has to be moved to be sorted
+        let keys: Vec<String> = (0..5000).rev().map(|i| format!("key{i}")).collect();
+        let body = keys
+            .iter()
+            .map(|key| {
+                format!(r#""{key}" = {{ fr => "{key} fr", en => "{key} en", es => "{key} es" }}"#)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let tokens = expand_str(&format!("T = LDSL {{ {body} }}"))
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains("LocalizationTable < 'static , 3usize , 5000usize >"));
+        assert!(tokens.contains(r#"locales : ["en" , "es" , "fr"]"#));
+        let mut sorted = keys;
+        sorted.sort();
+        let sorted: Vec<String> = sorted.iter().map(|key| format!("{key:?}")).collect();
+        assert!(tokens.contains(&format!("translation_keys : [{}]", sorted.join(" , "))));
+    }
+
+    #[test]
+    fn test_descriptions() {
+        let tokens = expand_str(
+            r#"T = LDSL #[strings_struct] {
+                greeting = "The top-of-page hello" { en => "Hello" },
+                " farewell " = "Shown when logging out" { en => "Goodbye" },
+                welcome = { en => "Welcome" },
+            }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(r#"# [doc = "The top-of-page hello"] Greeting"#));
+        assert!(tokens.contains(r#"# [doc = "The top-of-page hello"] pub greeting"#));
+        assert!(tokens.contains(r#"# [doc = "The translation of `welcome`"] pub welcome"#));
+        assert!(tokens.contains(
+            r#"[:: core :: option :: Option :: Some ("Shown when logging out") , :: core :: option :: Option :: Some ("The top-of-page hello") , :: core :: option :: Option :: None]"#
+        ));
+        assert_eq!(
+            merge_error_message(
+                r#"App = {
+                    A = LDSL { greeting = "Hello" { en => "Hello" } },
+                    B = LDSL { greeting = "Hola" { es => "Hola" } },
+                }"#
+            ),
+            "Translation key `greeting` has a description in both `A` and `B`"
+        );
+    }
+
+    #[test]
+    fn test_cfg_locale() {
+        let tokens = expand_str(
+            r#"T = LDSL #[cfg_locale(fr, feature = "fr")] { greeting = { en => "Hello", fr => "Bonjour" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(r#"# [cfg (all (feature = "fr"))] pub struct T ;"#));
+        assert!(tokens.contains(r#"# [cfg (all (not (feature = "fr")))] pub struct T ;"#));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[cfg_locale(de, feature = "de")] { greeting = { en => "Hello" } }"#
+            ),
+            "Unknown locale `de` in `cfg_locale`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[default_locale(fr)] #[cfg_locale(fr, feature = "fr")] { greeting = { en => "Hello", fr => "Bonjour" } }"#
+            ),
+            "The default locale `fr` can't have a `cfg_locale` condition"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[cfg_locale(fr, feature = "a")] #[cfg_locale(fr, feature = "b")] { greeting = { en => "Hello", fr => "Bonjour" } }"#
+            ),
+            "Duplicate `cfg_locale` for locale `fr`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[cfg_locale(fr)] { greeting = { en => "Hello", fr => "Bonjour" } }"#
+            ),
+            "Expected `,` followed by a `cfg` condition after locale `fr`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[cfg_locale(a, feature = "a")] #[cfg_locale(b, feature = "b")]
+                    #[cfg_locale(c, feature = "c")] #[cfg_locale(d, feature = "d")] #[cfg_locale(e, feature = "e")]
+                    { greeting = { en => "Hello", a => "a", b => "b", c => "c", d => "d", e => "e" } }"#
+            ),
+            "A table can only have 4 different `cfg_locale` conditions"
+        );
+    }
+
+    #[test]
+    fn test_match_threshold() {
+        let small =
+            r#"{ greeting = { en => "Hello", es => "Hola" }, farewell = { en => "Goodbye" } }"#;
+        let tokens = expand_str(&format!("T = LDSL {small}"))
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains(
+            r#"match translation_key . as_bytes () { b"farewell" => 0 , b"greeting" => 1 ,"#
+        ));
+        assert!(tokens.contains(r#"b"en" => 0 , b"es" => 1 ,"#));
+        let tokens = expand_str(&format!("T = LDSL #[match_threshold(1)] {small}"))
+            .unwrap()
+            .to_string();
+        assert!(!tokens.contains(r#"b"en" => 0"#));
+        let keys = (0..=DEFAULT_MATCH_THRESHOLD)
+            .map(|idx| format!(r#"key_{idx} = {{ en => "{idx}" }}"#))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let tokens = expand_str(&format!("T = LDSL {{ {keys} }}"))
+            .unwrap()
+            .to_string();
+        assert!(!tokens.contains(r#"b"en" => 0"#));
+        assert_eq!(
+            error_message(&format!(
+                "T = LDSL #[match_threshold(1)] #[match_threshold(2)] {small}"
+            )),
+            "Duplicate `match_threshold` attribute"
+        );
+        assert_eq!(
+            error_message(&format!("T = LDSL #[match_threshold(-1)] {small}")),
+            "Expected a number of translation keys, like `#[match_threshold(16)]`"
+        );
+        assert_eq!(
+            error_message(&format!("T = LDSL #[match_threshold(many)] {small}")),
+            "Expected a number of translation keys, like `#[match_threshold(16)]`"
+        );
+    }
+
+    #[test]
+    fn test_const_name() {
+        let table = r#"{ greeting = { en-US => "Hello", es => "Hola" } }"#;
+        let tokens = expand_str(&format!("T = LDSL #[const_name(en-US = US)] {table}"))
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains("pub const US :"));
+        assert!(tokens.contains("pub const ES :"));
+        assert!(!tokens.contains("EN_US"));
+        assert_eq!(
+            error_message(&format!("T = LDSL #[const_name(fr = FR)] {table}")),
+            "Unknown locale `fr` in `const_name`"
+        );
+        assert_eq!(
+            error_message(&format!(
+                "T = LDSL #[const_name(en-US = A, en-US = B)] {table}"
+            )),
+            "Duplicate const name for locale `en-US`"
+        );
+        assert_eq!(
+            error_message(&format!("T = LDSL #[const_name(en-US = ES)] {table}")),
+            "Locales `en-US` and `es` both generate the constant `ES`"
+        );
+    }
+
+    #[test]
+    fn test_default_key() {
+        let table = r#"{ "_" = { en => "N/A" }, "_cjk" = { ja => "なし" }, greeting = { en => "Hello", ja => "こんにちは" } }"#;
+        let tokens = expand_str(&format!(
+            "T = LDSL #[default_key(ja = \"_cjk\")] #[warn_inconsistent_locales] {table}"
+        ))
+        .unwrap()
+        .to_string();
+        // only `"_"` is missing a translation, which doesn't warn since it's a default key
+        assert!(!tokens.contains("inconsistent_locales"));
+        assert_eq!(
+            error_message(&format!("T = LDSL #[default_key(ko = \"_cjk\")] {table}")),
+            "Unknown locale `ko` in `default_key`"
+        );
+        assert_eq!(
+            error_message(&format!("T = LDSL #[default_key(ja = \"_asia\")] {table}")),
+            "Unknown default key `_asia` for locale `ja`"
+        );
+        assert_eq!(
+            error_message(&format!(
+                "T = LDSL #[default_key(ja = \"_cjk\", ja = \"_\")] {table}"
+            )),
+            "Duplicate default key for locale `ja`"
+        );
+    }
+
+    #[test]
+    fn test_explicit() {
+        let tokens = expand_str(
+            r#"T = LDSL { "_" = { es => "N/D" }, farewell = { en => "Goodbye" }, greeting = { en => "Hello", es => "Hola" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        // keys are `_`, `farewell`, `greeting`; `es` only has `farewell` from `"_"`
+        assert!(tokens.contains("= [[false , true , true] , [true , false , true]] ;"));
+    }
+
+    #[test]
+    fn test_placeholder() {
+        let tokens = expand_str(
+            r#"T = LDSL #[placeholder("???")] #[complete] { greeting = { en => "Hello", es => "Hola" }, farewell = { en => "Goodbye" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(r#"const PLACEHOLDER : & 'static str = "???""#));
+        // the cells still use `PLACEHOLDER`, so `TABLE.is_complete()` finds them
+        assert!(tokens.contains("T :: TABLE . is_complete ()"));
+        assert!(!tokens.contains(r#""???" ,"#));
+        let tokens = expand_str(r#"T = LDSL #[complete] { greeting = { en => "Hello" } }"#)
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains(r#"const PLACEHOLDER : & 'static str = "<NO TRANSLATION>""#));
+        assert!(tokens.contains("T :: TABLE . is_complete ()"));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[placeholder("")] #[placeholder("?")] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate `placeholder` attribute"
+        );
+    }
+
+    #[test]
+    fn test_grouped_locales() {
+        assert!(expand_str(
+            r#"T = LDSL { ok = { (en, es, it) => "OK", fr => "D'accord" }, yes = { (en, fr,) => "Yes" } }"#
+        )
+        .is_ok());
+        assert_eq!(
+            error_message(r#"T = LDSL { ok = { (en, es) => "OK", es => "Vale" } }"#),
+            "Duplicate locale identifier in translation"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { ok = { (en, es) => "OK", (it, en) => "Okay" } }"#),
+            "Duplicate locale identifier in translation"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { ok = { () => "OK" } }"#),
+            "Expected at least one locale in the group"
+        );
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let tokens = expand_str(
+            r#"T = LDSL #[warn_inconsistent_locales] { brand = { fr => "Marque", * => "Brand" }, greeting = { en => "Hello", es => "Hola", fr => "Bonjour" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(!tokens.contains("inconsistent_locales"));
+        assert_eq!(
+            error_message(r#"T = LDSL { brand = { * => "Brand", * => "Marque" } }"#),
+            "Duplicate `*` translation"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { brand = { *(formal) => "Brand" } }"#),
+            "A `*` translation can't have a context"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL { brand = { * "Brand" } }"#),
+            "Expected `=>` after locale `*`"
+        );
+    }
+
+    #[test]
+    fn test_generate_tests() {
+        let table = r#"{ greeting = { en => "Hello", es => "Hola" } }"#;
+        let tokens = expand_str(&format!("T = LDSL #[generate_tests] {table}"))
+            .unwrap()
+            .to_string();
+        assert!(
+            tokens.contains("# [test] # [allow (non_snake_case)] fn T_is_complete_and_sorted ()")
+        );
+        assert!(tokens.contains(
+            "new_checked (table . translation_keys , table . locales , table . translations)"
+        ));
+        let tokens = expand_str(&format!(
+            "T = LDSL #[generate_tests] #[preserve_order] {table}"
+        ))
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("new_checked (table . translation_keys , [] , [])"));
+        let tokens = expand_str(&format!("T = LDSL {table}"))
+            .unwrap()
+            .to_string();
+        assert!(!tokens.contains("is_complete_and_sorted"));
+        assert_eq!(
+            error_message(&format!(
+                "T = LDSL #[generate_tests] #[generate_tests] {table}"
+            )),
+            "Duplicate `generate_tests` attribute"
+        );
+    }
+
+    #[test]
+    fn test_locale_notes() {
+        let tokens = expand_str(
+            r#"T = LDSL #[locale_note(es = "Use informal 'tú'")] { greeting = { en => "Hello", es => "Hola" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(r#"# [doc = "Use informal 'tú'"] pub const ES"#));
+        assert!(tokens.contains(r#"# [doc = "Use informal 'tú'"] Es"#));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locale_note(fr = "Vouvoyer")] { greeting = { en => "Hello" } }"#
+            ),
+            "Unknown locale `fr` in `locale_note`"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[locale_note(en = "US")] #[locale_note(en = "UK")] { greeting = { en => "Hello" } }"#
+            ),
+            "Duplicate note for locale `en`"
+        );
+    }
+
+    #[test]
+    fn test_ldsl_from() {
+        let from_file = expand_str(r#"T = LDSL from "../tests/fixtures/strings.ldsl""#)
+            .unwrap()
+            .to_string();
+        assert!(from_file.contains(r#"translation_keys : ["_" , "farewell" , "greeting"]"#));
+        let from_env = expand_str(r#"T = LDSL from env "LOCALIZE_TEST_LDSL""#)
+            .unwrap()
+            .to_string();
+        assert!(from_env.contains(r#"env ! ("LOCALIZE_TEST_LDSL")"#));
+        assert!(from_env.contains(r#"translation_keys : ["_" , "farewell" , "greeting"]"#));
+        assert_eq!(
+            error_message(r#"T = LDSL from env "LOCALIZE_CODEGEN_UNSET""#),
+            "Environment variable `LOCALIZE_CODEGEN_UNSET` isn't set"
+        );
+        assert!(
+            error_message(r#"T = LDSL from "../tests/fixtures/missing.ldsl""#)
+                .starts_with("Couldn't read `../tests/fixtures/missing.ldsl`")
+        );
+        assert_eq!(
+            error_message("T = LDSL from 5"),
+            "Expected a file path or `env` after `from`"
+        );
+    }
+
+    #[test]
+    fn test_ldsl_by_locale() {
+        let by_locale = expand_str(
+            r#"T = LDSL_BY_LOCALE { en { greeting => "Hello", farewell => "Goodbye" }, es { greeting => "Hola" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        let by_key = expand_str(
+            r#"T = LDSL { greeting = { en => "Hello", es => "Hola" }, farewell = { en => "Goodbye" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert_eq!(by_locale, by_key);
+        assert_eq!(
+            error_message(
+                r#"T = LDSL_BY_LOCALE { en { greeting => "Hello" } en { farewell => "Bye" } }"#
+            ),
+            "Duplicate locale `en`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL_BY_LOCALE { en { greeting => "Hello", greeting => "Hi" } }"#),
+            "Duplicate translation key `greeting` for locale `en`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL_BY_LOCALE { en { greeting = "Hello" } }"#),
+            "Expected `=>` after translation key `greeting` of locale `en`"
+        );
+    }
+
+    #[test]
+    fn test_same_as_key() {
+        let warnings = expand_str(
+            r#"T = LDSL #[warn_same_as_key] #[allow_same_as_key("Email")] { "_" = { en => "_" }, OK = { en => "OK", de => "OK", fr => "D'accord" }, Email = { en => "Email", de => "Email" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(warnings.contains(
+            "Translation of `OK` in `de` is the same as its key, so it may be untranslated"
+        ));
+        assert!(warnings.contains(
+            "Translation of `OK` in `en` is the same as its key, so it may be untranslated"
+        ));
+        assert!(!warnings.contains("Translation of `OK` in `fr`"));
+        assert!(!warnings.contains("Translation of `Email`"));
+        assert!(!warnings.contains("Translation of `_`"));
+        assert!(!expand_str(r#"T = LDSL { OK = { en => "OK" } }"#)
+            .unwrap()
+            .to_string()
+            .contains("deprecated"));
+        assert_eq!(
+            error_message(
+                r#"T = LDSL #[warn_same_as_key] #[warn_same_as_key] { OK = { en => "OK" } }"#
+            ),
+            "Duplicate `warn_same_as_key` attribute"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL #[allow_same_as_key("OK", "OK")] { OK = { en => "OK" } }"#),
+            "Duplicate translation key `OK` in `allow_same_as_key`"
+        );
+        assert_eq!(
+            error_message(r#"T = LDSL #[allow_same_as_key("Cancel")] { OK = { en => "OK" } }"#),
+            "Unknown translation key `Cancel` in `allow_same_as_key`"
+        );
+    }
+
+    #[test]
+    fn test_ldsl_multi() {
+        let multi = expand_str(
+            r#"T = LDSL_MULTI { locale en { greeting => "Hello", farewell => "Goodbye" } locale es { greeting => "Hola" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        let by_key = expand_str(
+            r#"T = LDSL { greeting = { en => "Hello", es => "Hola" }, farewell = { en => "Goodbye" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert_eq!(multi, by_key);
+        assert_eq!(
+            error_message(r#"T = LDSL_MULTI { en { greeting => "Hello" } }"#),
+            "Expected `locale` before a block of translations"
+        );
+        assert_eq!(
+            error_message(
+                r#"T = LDSL_MULTI { locale en { greeting => "Hello" } locale en { farewell => "Bye" } }"#
+            ),
+            "Duplicate locale `en`"
+        );
+    }
+
+    #[test]
+    fn test_show_keys() {
+        let tokens = expand_str(
+            r#"T = LDSL { "_" = { en => "?" }, "apples.one" = { en => "One apple" }, greeting = { en => "Hello" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains(r#"b"greeting" => return "greeting" ,"#));
+        assert!(!tokens.contains(r#"return "apples.one""#));
+        assert!(!tokens.contains(r#"return "_""#));
+        assert!(
+            !expand_str(r#"T = LDSL { "apples.one" = { en => "One apple" } }"#)
+                .unwrap()
+                .to_string()
+                .contains("SHOW_KEYS")
+        );
+    }
+
+    #[test]
+    fn test_key_enum_without_identifier_keys() {
+        let tokens = expand_str(
+            r#"T = LDSL { "_" = { en => "?" }, "menu.open" = { en => "Open" }, 404 = { en => "Not found" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        // an enum without variants can't have a `repr`
+        assert!(tokens.contains("pub enum TKey { }"));
+        assert!(!tokens.contains("# [repr (usize)] pub enum TKey"));
+        let tokens = expand_str(r#"T = LDSL { greeting = { en => "Hello" } }"#)
+            .unwrap()
+            .to_string();
+        assert!(tokens.contains("# [repr (usize)] pub enum TKey { Greeting = 0 }"));
+    }
+
+    #[test]
+    fn test_shared_rows() {
+        // three locales share a row, which saves more than `ROW_INDEX` costs
+        let shared = expand_str(
+            r#"T = LDSL { a = { en => "A", en-AU => "A2", en-GB => "A2", en-NZ => "A2" }, b = { en => "B" } }"#,
+        )
+        .unwrap()
+        .to_string();
+        assert!(shared.contains(
+            "pub const ROW_INDEX : [usize ; 4usize] = [0usize , 1usize , 1usize , 1usize]"
+        ));
+        assert!(shared.contains("translations : [Self :: ROWS [0usize] , Self :: ROWS [1usize]"));
+        assert!(shared.contains("Self :: ROWS [Self :: ROW_INDEX [locale_idx]] [key_idx]"));
+        // a single shared row of a single key doesn't
+        let separate =
+            expand_str(r#"T = LDSL { a = { en => "A", en-AU => "A2", en-GB => "A2" } }"#)
+                .unwrap()
+                .to_string();
+        assert!(!separate.contains("ROW_INDEX"));
+        assert!(separate.contains("translations : Self :: ROWS"));
+        assert!(separate.contains("Self :: ROWS [locale_idx] [key_idx]"));
+    }
+}
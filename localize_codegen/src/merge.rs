@@ -0,0 +1,144 @@
+//! Combining several tables into one, for the `merge_tables!` macro.
+
+use crate::{add_locale, expand, TableOptions, TranslationInput, Translations};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use std::collections::{BTreeMap, HashMap};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, Result, Token,
+};
+
+/// The input of `merge_tables!`, like `App = #[default_locale(en)] { Core = LDSL { ... }, ... }`
+pub struct MergeInput {
+    struct_name: Ident,
+    options: TableOptions,
+    parts: Vec<TranslationInput>,
+}
+
+impl Parse for MergeInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let struct_name: Ident = input.parse()?;
+        let _: Token![=] = crate::parse_expecting(
+            input,
+            format_args!("Expected `=` after the table name `{struct_name}`"),
+        )?;
+        let options: TableOptions = input.parse()?;
+        let body;
+        syn::braced!(body in input);
+        let parts: Vec<TranslationInput> =
+            Punctuated::<TranslationInput, Token![,]>::parse_terminated(&body)?
+                .into_iter()
+                .collect();
+        if parts.is_empty() {
+            return Err(syn::Error::new(
+                struct_name.span(),
+                format!("`{struct_name}` doesn't have any tables to merge"),
+            ));
+        }
+        Ok(Self {
+            struct_name,
+            options,
+            parts,
+        })
+    }
+}
+
+/// Generate every part of the input as its own table, followed by the combined table
+pub fn expand_merge(input: MergeInput) -> Result<TokenStream2> {
+    let MergeInput {
+        struct_name,
+        options,
+        parts,
+    } = input;
+    let translations = merge_translations(&parts)?;
+    let sources = parts
+        .iter()
+        .flat_map(|part| part.sources.iter().cloned())
+        .collect();
+    let env_vars = parts
+        .iter()
+        .flat_map(|part| part.env_vars.iter().cloned())
+        .collect();
+    let parts = parts.into_iter().map(expand).collect::<Result<Vec<_>>>()?;
+    let merged = expand(TranslationInput {
+        struct_name,
+        options,
+        translations,
+        sources,
+        env_vars,
+    })?;
+    Ok(quote! {
+        #(#parts)*
+
+        #merged
+    })
+}
+
+/// Combine the translations of every part, failing if two parts translate the same key in the same
+/// locale (and context)
+fn merge_translations(parts: &[TranslationInput]) -> Result<Translations> {
+    let mut merged = Translations {
+        strings: BTreeMap::new(),
+        contexts: Vec::new(),
+        locales: Vec::new(),
+        descriptions: BTreeMap::new(),
+    };
+    // the part that each cell came from, for error messages
+    let mut owners = HashMap::new();
+    let mut context_owners = Vec::new();
+    let mut description_owners = HashMap::new();
+    for part in parts {
+        let translations = &part.translations;
+        for locale in &translations.locales {
+            add_locale(&mut merged.locales, locale);
+        }
+        for (key, values) in &translations.strings {
+            let cells = merged.strings.entry(key.clone()).or_default();
+            for (locale, value) in values {
+                if let Some(owner) = owners.insert((key.clone(), locale.clone()), &part.struct_name)
+                {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!(
+                            "Translation key `{key}` has a translation for locale `{locale}` in both `{owner}` and `{}`",
+                            part.struct_name
+                        ),
+                    ));
+                }
+                cells.insert(locale.clone(), value.clone());
+            }
+        }
+        for (key, description) in &translations.descriptions {
+            if let Some(owner) = description_owners.insert(key.clone(), &part.struct_name) {
+                return Err(syn::Error::new(
+                    description.span(),
+                    format!(
+                        "Translation key `{key}` has a description in both `{owner}` and `{}`",
+                        part.struct_name
+                    ),
+                ));
+            }
+            merged.descriptions.insert(key.clone(), description.clone());
+        }
+        for variant in &translations.contexts {
+            if let Some(idx) = merged.contexts.iter().position(|other| {
+                other.key == variant.key
+                    && other.locale == variant.locale
+                    && other.context == variant.context
+            }) {
+                return Err(syn::Error::new(
+                    variant.context.span(),
+                    format!(
+                        "Translation key `{}` has a translation for locale `{}` in context `{}` in both `{}` and `{}`",
+                        variant.key, variant.locale, variant.context, context_owners[idx], part.struct_name
+                    ),
+                ));
+            }
+            merged.contexts.push(variant.clone());
+            context_owners.push(&part.struct_name);
+        }
+    }
+    Ok(merged)
+}
@@ -0,0 +1,146 @@
+//! A small reader for gettext `.po` files, for the `PO` syntax.
+
+/// A translation from a `.po` file
+pub struct Entry {
+    /// The line the entry's `msgid` is on, counting from 1
+    pub line: usize,
+    pub msgid: String,
+    pub msgstr: String,
+    /// Whether the entry is flagged as `fuzzy`, meaning that the translation needs review
+    pub fuzzy: bool,
+}
+
+/// A problem with the syntax of a `.po` file
+#[derive(Debug, PartialEq, Eq)]
+pub struct PoError {
+    /// The line of the problem, counting from 1
+    pub line: usize,
+    pub message: String,
+}
+
+/// The string of an entry that a continuation line is added to
+#[derive(Clone, Copy)]
+enum Field {
+    Id,
+    Str,
+}
+
+#[derive(Default)]
+struct PartialEntry {
+    line: usize,
+    msgid: String,
+    msgstr: Option<String>,
+    fuzzy: bool,
+}
+
+/// Read the entries of a `.po` file.
+///
+/// Comments are skipped, except for `#,` flag comments, which mark the next entry as fuzzy if they include
+/// the `fuzzy` flag. Strings can be continued on following lines and use C escape sequences. Message
+/// contexts (`msgctxt`) and plural forms aren't supported.
+pub fn parse(source: &str) -> Result<Vec<Entry>, PoError> {
+    let mut entries = Vec::new();
+    let mut current: Option<PartialEntry> = None;
+    let mut field = None;
+    let mut fuzzy = false;
+    for (idx, line) in source.lines().enumerate() {
+        let line_number = idx + 1;
+        let error = |message: String| PoError {
+            line: line_number,
+            message,
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            if let Some(entry) = current.take() {
+                entries.push(finish(entry)?);
+            }
+            field = None;
+            if let Some(flags) = line.strip_prefix("#,") {
+                fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            }
+        } else if let Some(rest) = line.strip_prefix("msgid ") {
+            if let Some(entry) = current.take() {
+                entries.push(finish(entry)?);
+            }
+            current = Some(PartialEntry {
+                line: line_number,
+                msgid: unquote(rest).map_err(error)?,
+                msgstr: None,
+                fuzzy: std::mem::take(&mut fuzzy),
+            });
+            field = Some(Field::Id);
+        } else if let Some(rest) = line.strip_prefix("msgstr ") {
+            let Some(entry) = current.as_mut().filter(|entry| entry.msgstr.is_none()) else {
+                return Err(error("`msgstr` without a `msgid`".to_owned()));
+            };
+            entry.msgstr = Some(unquote(rest).map_err(error)?);
+            field = Some(Field::Str);
+        } else if line.starts_with('"') {
+            let value = unquote(line).map_err(error)?;
+            match (current.as_mut(), field) {
+                (Some(entry), Some(Field::Id)) => entry.msgid.push_str(&value),
+                (
+                    Some(PartialEntry {
+                        msgstr: Some(msgstr),
+                        ..
+                    }),
+                    Some(Field::Str),
+                ) => msgstr.push_str(&value),
+                _ => return Err(error("string without a `msgid` or `msgstr`".to_owned())),
+            }
+        } else {
+            let keyword = line.split([' ', '[']).next().unwrap_or(line);
+            return Err(error(format!("unsupported keyword `{keyword}`")));
+        }
+    }
+    if let Some(entry) = current {
+        entries.push(finish(entry)?);
+    }
+    Ok(entries)
+}
+
+fn finish(entry: PartialEntry) -> Result<Entry, PoError> {
+    let Some(msgstr) = entry.msgstr else {
+        return Err(PoError {
+            line: entry.line,
+            message: "`msgid` without a `msgstr`".to_owned(),
+        });
+    };
+    Ok(Entry {
+        line: entry.line,
+        msgid: entry.msgid,
+        msgstr,
+        fuzzy: entry.fuzzy,
+    })
+}
+
+/// Read a double-quoted string, processing its escape sequences
+fn unquote(quoted: &str) -> Result<String, String> {
+    let inner = quoted
+        .trim()
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .ok_or_else(|| format!("expected a quoted string, found `{quoted}`"))?;
+    let mut value = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => value.push(match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('"') => '"',
+                Some('\\') => '\\',
+                other => {
+                    return Err(format!(
+                        "unknown escape sequence `\\{}`",
+                        other.map(String::from).unwrap_or_default()
+                    ))
+                }
+            }),
+            '"' => return Err(format!("unescaped `\"` in `{quoted}`")),
+            _ => value.push(ch),
+        }
+    }
+    Ok(value)
+}
@@ -1,116 +1,6 @@
 #![warn(clippy::pedantic, clippy::nursery)]
 
-use proc_macro::{Span, TokenStream};
-use quote::quote;
-use std::collections::{HashMap, HashSet};
-use syn::{
-    ext::IdentExt,
-    parse::{Parse, ParseStream},
-    parse_macro_input,
-    punctuated::Punctuated,
-    Ident, LitStr, Result, Token,
-};
-
-struct TranslationInput {
-    struct_name: Ident,
-    strings: HashMap<String, HashMap<Ident, LitStr>>,
-    locales: HashSet<Ident>,
-}
-
-enum StrOrIdent {
-    Str(LitStr),
-    Ident(Ident),
-}
-
-impl StrOrIdent {
-    pub fn value(&self) -> String {
-        match self {
-            Self::Str(l) => l.value(),
-            Self::Ident(i) => i.unraw().to_string(),
-        }
-    }
-}
-
-impl Parse for StrOrIdent {
-    fn parse(input: ParseStream) -> Result<Self> {
-        if let Ok(s) = input.parse() {
-            Ok(Self::Str(s))
-        } else {
-            input.parse().map(Self::Ident)
-        }
-    }
-}
-
-struct LDSLTranslationItem {
-    key: StrOrIdent,
-    values: Punctuated<LDSLTranslationValue, Token![,]>,
-}
-
-struct LDSLTranslationValue {
-    locale: Ident,
-    value: LitStr,
-}
-
-impl Parse for TranslationInput {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let struct_name: Ident = input.parse()?;
-        let _: Token![=] = input.parse()?;
-        let syntax_type: Ident = input.parse()?;
-        match &*syntax_type.to_string() {
-            "LDSL" => {
-                let body;
-                syn::braced!(body in input);
-                let translations = body.parse_terminated(LDSLTranslationItem::parse, Token![,])?;
-                let mut strings: HashMap<String, HashMap<Ident, LitStr>> = HashMap::new();
-                let mut locales: HashSet<Ident> = HashSet::new();
-                for item in translations {
-                    let key = item.key.value();
-                    let mut current_string = HashMap::new();
-                    for translation in item.values {
-                        if current_string
-                            .insert(translation.locale.unraw(), translation.value)
-                            .is_some()
-                        {
-                            return Err(syn::Error::new(
-                                translation.locale.span(),
-                                "Duplicate locale identifier in translation",
-                            ));
-                        }
-                        locales.insert(translation.locale.unraw());
-                    }
-                    strings.insert(key, current_string);
-                }
-
-                Ok(Self {
-                    struct_name,
-                    strings,
-                    locales,
-                })
-            }
-            _ => todo!(),
-        }
-    }
-}
-
-impl Parse for LDSLTranslationItem {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let key = input.parse()?;
-        let _: Token![=] = input.parse()?;
-        let content;
-        syn::braced!(content in input);
-        let values = content.parse_terminated(LDSLTranslationValue::parse, Token![,])?;
-        Ok(Self { key, values })
-    }
-}
-
-impl Parse for LDSLTranslationValue {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let locale: Ident = input.parse()?;
-        let _: Token![=>] = input.parse()?;
-        let value: LitStr = input.parse()?;
-        Ok(Self { locale, value })
-    }
-}
+use proc_macro::TokenStream;
 
 #[proc_macro]
 /// Generates a `LocalizationTabe` struct from a custom set of translations.
@@ -118,8 +8,15 @@ impl Parse for LDSLTranslationValue {
 /// # Syntax
 ///
 /// The macro invocation always starts with an identifier for the translation table, an equals sign,
-/// and an identifier corresponding to the translation syntax to use. Currently, the only supported
-/// syntax is LDSL, described below.
+/// and an identifier corresponding to the translation syntax to use: LDSL, `LDSL_BY_LOCALE`,
+/// `LDSL_MULTI`, CSV, PO, `JSON_DIR`, or TOML, described below. Any other syntax type is a compile error
+/// that lists the supported ones:
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = YAML "locales/strings.yaml"}
+/// ```
 ///
 /// ## LDSL (Localization Domain-Specific Language)
 ///
@@ -141,8 +38,576 @@ impl Parse for LDSLTranslationValue {
 /// The DSL allows you to specify translation keys and their corresponding translations
 /// for different locales in a structured and readable format.
 ///
-/// - Each translation key is a string literal.
+/// - Each translation key is a string literal, an identifier like `greeting`, or an integer literal like
+///   `404`, which is the same as the key `"404"`. Integers are written out in base 10, so `0x194` is also
+///   `"404"`, and can't have a type suffix.
+/// - Commas are optional between translation keys, and trailing commas are allowed both after the last
+///   key and after the last translation of a key. `//` and `/* */` comments can go anywhere.
 /// - Each locale is an identifier followed by `=>` and a string literal representing the translation.
+///   Locales with dashes can be written directly, like `en-US` or `es-419`, or as a string literal like
+///   `"zh-Hant"`. The generated `LocaleHandle` constant replaces the dashes with underscores, so `en-US`
+///   becomes `EN_US`. `_` can't be used as a locale in any syntax, since `"_"` is the translation key for
+///   default translations.
+/// - Several locales that share a translation can be grouped in parentheses, like
+///   `(en, es, it) => "OK"`, which is the same as giving each of them the translation separately. A locale
+///   still can't have two translations for the same key, whether they're grouped or not.
+/// - `*` can be used instead of a locale to give a key the same translation in every locale it doesn't
+///   list, like `brand = { fr => "Marque", * => "Brand" }`. Unlike the `"_"` key, it only applies to its
+///   own key, and the locales it fills in count as translated, so they aren't reported by
+///   `#[warn_inconsistent_locales]`. Each key can have one `*` translation, without a context, and it
+///   fills in every locale of the table, even those that only appear in later keys.
+/// - A locale can be followed by a context in parentheses, like `es(feminine) => "Bienvenida"`. These
+///   translations are only used by the generated `localize_ctx` function when that context is requested,
+///   which falls back to the translation without a context otherwise. Context variants are available as
+///   the generated `CONTEXTS` constant.
+/// - Translations follow the normal Rust string literal rules: escape sequences like `\n` and `\"` are
+///   processed, and raw string literals (`r"..."`, `r#"..."#`) can be used for text full of backslashes.
+/// - A translation can be written as `dedent` followed by a string literal, like
+///   `en => dedent r"..."`, to spread long text over indented lines. The text is dedented when the table is
+///   generated: if the first line is blank (usually the line break right after the opening quote), it's
+///   removed, and so is the last line if it's blank (usually the indentation before the closing quote).
+///   Then the leading whitespace that every remaining line has in common is removed, counting only lines
+///   that aren't blank, and blank lines are emptied. Spaces and tabs each count as one character, so the
+///   lines should be indented consistently. Line breaks between the lines are kept, but there's no line
+///   break at the end.
+/// - A translation key can reuse every translation of another key with `@`, like
+///   `"submit_alt" = @ "submit"`. The translations (and context variants) are copied when the table is
+///   generated, and references can point at other references. A reference to a key that doesn't exist, or
+///   a cycle of references, is a compile error.
+/// - A translation key can have a description between the `=` and its translations (or `@`), like
+///   `greeting = "The top-of-page hello" { ... }`. Descriptions are the doc comments of the key's variant
+///   in the generated key enum and its field in the `#[strings_struct]`, and can be looked up with the
+///   generated `description` function, which returns `None` for keys without one. They're also available
+///   as the generated `DESCRIPTIONS` constant, in the same order as the table's `translation_keys`.
+/// - A translation key without any translations, like `"orphan" = {}`, is a compile error, since it would
+///   silently fall back to the `"_"` key in every locale.
+/// - A table without any translation keys, like `Empty = LDSL {}`, is allowed. Its lookups return
+///   `PLACEHOLDER`, and `get_locale` returns a handle for the locale `""` that does the same. Without a
+///   default locale, using its `DEFAULTING_TABLE` constant is a compile error.
+/// - Each translation key can only be defined once. Repeating a key is a compile error rather than
+///   silently replacing its earlier translations:
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL {
+///     "greeting" = {
+///         en => "Hello",
+///     },
+///     "greeting" = {
+///         es => "Hola",
+///     }
+/// }}
+/// ```
+///
+/// Locales are case-sensitive, so `en` and `EN` would be different locales. Since that's almost always a
+/// typo, locales that only differ by ASCII case are a compile error:
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL {
+///     "greeting" = {
+///         en => "Hello",
+///     },
+///     "farewell" = {
+///         En => "Goodbye",
+///     }
+/// }}
+/// ```
+///
+/// A locale named `_` is also a compile error:
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL {
+///     greeting = {
+///         _ => "x",
+///     }
+/// }}
+/// ```
+///
+/// The translations can also be read from a file at compile time, with everything that would go inside
+/// the braces. Like CSV, the path is relative to the directory containing the crate's `Cargo.toml`, and
+/// the table is rebuilt whenever the file changes:
+///
+/// ```ignore
+/// localization_table! {MyLocalizationTable = LDSL from "translations/strings.ldsl"}
+/// ```
+///
+/// With `from env`, the path is read from an environment variable instead, so that a build can choose
+/// its translations without editing the source. It's a compile error if the variable isn't set, and the
+/// table is rebuilt whenever the variable changes:
+///
+/// ```ignore
+/// localization_table! {MyLocalizationTable = LDSL from env "TRANSLATIONS_FILE"}
+/// ```
+///
+/// ## `LDSL_BY_LOCALE`
+///
+/// The same translations can be written locale by locale instead of key by key, which suits teams where
+/// each translator owns a locale. Each locale lists its translations as `key => "translation"` pairs,
+/// and the table is the same as if it were written in LDSL:
+///
+/// ```
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL_BY_LOCALE {
+///     en {
+///         greeting => "Hello",
+///         farewell => "Goodbye",
+///     }
+///     es {
+///         greeting => "Hola",
+///         farewell => "Adiós",
+///     }
+/// }}
+///
+/// assert_eq!(Spanglish::localize("farewell", "es"), "Adiós");
+/// ```
+///
+/// Keys that a locale doesn't list fall back to the `"_"` key like missing LDSL translations. A locale
+/// listed twice, or a key listed twice for the same locale, is a compile error. Contexts, descriptions,
+/// and references aren't supported in this form.
+///
+/// ## `LDSL_MULTI`
+///
+/// `LDSL_MULTI` is the same as `LDSL_BY_LOCALE`, except that every block starts with the `locale`
+/// keyword, which makes the start of each locale easier to find in tables with many keys. The keys of
+/// the table are all of the keys that any block lists:
+///
+/// ```
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL_MULTI {
+///     locale en {
+///         "_" => "Hello",
+///         greeting => "Hello",
+///     }
+///     locale es {
+///         greeting => "Hola",
+///         farewell => "Adiós",
+///     }
+/// }}
+///
+/// assert_eq!(Spanglish::localize("farewell", "es"), "Adiós");
+/// assert_eq!(Spanglish::localize("farewell", "en"), "Hello");
+/// ```
+///
+/// ## CSV
+///
+/// ```ignore
+/// localization_table! {MyLocalizationTable = CSV "translations/strings.csv"}
+/// ```
+///
+/// Reads the translations from a CSV file at compile time. The path is relative to the directory containing
+/// the crate's `Cargo.toml`, and the table is rebuilt whenever the file changes.
+///
+/// ```text
+/// key,en,es
+/// _,<Unknown Translation>,<No Savo>
+/// greeting,Hello,Hola
+/// farewell,"Goodbye, friend",
+/// ```
+///
+/// - The first row is a header. Its first cell is ignored, and every other cell is a locale.
+/// - Every other row is a translation key followed by its translation for each locale. Empty cells fall
+///   back to the `"_"` key like missing LDSL translations.
+/// - Cells can be quoted to contain commas and line breaks, with `""` for a literal quote. No escape
+///   sequences are processed.
+/// - A row with the wrong number of cells, or a translation key that was already used, is a compile error
+///   that names its line.
+///
+/// ## PO
+///
+/// ```ignore
+/// localization_table! {MyLocalizationTable = PO {
+///     en => "locales/en.po",
+///     es => "locales/es.po",
+/// }}
+/// ```
+///
+/// Reads the translations for each locale from a gettext `.po` file at compile time. Like CSV, the paths
+/// are relative to the directory containing the crate's `Cargo.toml`.
+///
+/// - Each `msgid` is a translation key, and its `msgstr` is the translation for the file's locale. The
+///   header entry (with an empty `msgid`) is skipped.
+/// - Entries with an empty `msgstr` or a `#, fuzzy` flag count as missing, and fall back to the `"_"` key
+///   if there is one.
+/// - Strings can span several lines and use the escape sequences `\n`, `\t`, `\r`, `\"`, and `\\`.
+/// - `msgctxt` and plural forms (`msgid_plural`) aren't supported.
+///
+/// ## `JSON_DIR`
+///
+/// ```ignore
+/// localization_table! {MyLocalizationTable = JSON_DIR "i18n/"}
+/// ```
+///
+/// Reads every `.json` file in a directory at compile time, with one file per locale: `i18n/en.json`
+/// holds the `en` translations. Like CSV, the path is relative to the directory containing the crate's
+/// `Cargo.toml`.
+///
+/// - Each file is a flat object mapping translation keys to translations, like
+///   `{ "greeting": "Hello" }`. Other values, like numbers or nested objects, are a compile error,
+///   and so is a key that appears twice in the same file.
+/// - The table has every key from every file, and keys missing from a file fall back to the `"_"` key
+///   like missing LDSL translations.
+/// - Files other than `.json` files are ignored.
+/// - The table is rebuilt whenever one of the files changes. Files added to the directory are picked up
+///   the next time the crate is rebuilt.
+///
+/// ## TOML
+///
+/// ```ignore
+/// localization_table! {MyLocalizationTable = TOML "translations/strings.toml"}
+/// ```
+///
+/// Reads the translations from a TOML file at compile time. Like CSV, the path is relative to the directory
+/// containing the crate's `Cargo.toml`, and the table is rebuilt whenever the file changes.
+///
+/// ```text
+/// [_]
+/// en = "<Unknown Translation>"
+/// es = "<No Savo>"
+///
+/// [greeting]
+/// en = "Hello"
+/// es = "Hola"
+///
+/// ["menu.open"]
+/// en = "Open"
+/// ```
+///
+/// - Each table is a translation key, and each of its entries is the translation for a locale. Keys with
+///   dots have to be quoted, since `[menu.open]` is a table nested in `[menu]`.
+/// - Every value must be a string; anything else is a compile error that names the table. Locales
+///   missing from a table fall back to the `"_"` key like missing LDSL translations.
+///
+/// # Default translations
+///
+/// The translation key `"_"` fills in the cells of its locales that don't have a translation, so a table
+/// with `"_" = { en => "N/A", es => "N/D" }` gives `"N/D"` for any key that's missing in `es`. Cells that
+/// `"_"` doesn't have a translation for either are left as `PLACEHOLDER`, which lookups replace with the
+/// translation of the default locale. If the default locale doesn't have one either, lookups return the
+/// placeholder, which can be changed with `#[placeholder(...)]`.
+///
+/// Locales can be given a different default key with the `#[default_key(locale = "key", ...)]`
+/// attribute, to share a default between a group of locales, like `"_cjk"` for `ja`, `zh`, and `ko`. The
+/// missing cells of those locales are filled in from their default key, then from `"_"`. A default key is
+/// an ordinary translation key, so it can also be looked up, but `#[warn_inconsistent_locales]` doesn't
+/// warn about the locales it's missing:
+///
+/// ```
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Groups = LDSL #[default_key(ja = "_cjk", zh = "_cjk")] {
+///     "_" = {
+///         en => "N/A",
+///         es => "N/D",
+///     },
+///     "_cjk" = {
+///         ja => "なし",
+///         zh => "无",
+///     },
+///     greeting = {
+///         en => "Hello",
+///         es => "Hola",
+///         ja => "こんにちは",
+///         zh => "你好",
+///     },
+///     farewell = {
+///         en => "Goodbye",
+///     }
+/// }}
+/// ```
+///
+/// The generated `EXPLICIT` constant has a `bool` for every cell of the table, in the same order as its
+/// `translations`, which is `true` for the cells with their own translation and `false` for those filled
+/// in from a default key or left as `PLACEHOLDER`. The generated `is_explicit` function looks up a single
+/// cell, and is `false` for an unknown key or locale. The generated `locales_with_translation` function
+/// lists the locales that have their own translation of a key, in table order, or none for an unknown key.
+/// The generated `localize_first` function takes several candidate keys, like a branded override before
+/// a generic key, and returns the translation of the first one that has its own translation in a locale,
+/// or `None` if none of them do:
+///
+/// ```
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Partial = LDSL {
+///     "_" = {
+///         es => "N/D",
+///     },
+///     greeting = {
+///         en => "Hello",
+///         es => "Hola",
+///     },
+///     farewell = {
+///         en => "Goodbye",
+///     }
+/// }}
+///
+/// assert_eq!(Partial::locales_with_translation("greeting").collect::<Vec<_>>(), ["en", "es"]);
+/// assert_eq!(Partial::locales_with_translation("farewell").collect::<Vec<_>>(), ["en"]);
+/// assert!(Partial::is_explicit("greeting", "es"));
+/// assert!(!Partial::is_explicit("farewell", "es"));
+/// assert_eq!(Partial::localize_first(&["farewell", "greeting"], "es"), Some("Hola"));
+/// assert_eq!(Partial::localize_first(&["farewell"], "es"), None);
+/// ```
+///
+/// # Translation keys
+///
+/// In every syntax, whitespace around a translation key is removed when the table is generated, so
+/// `"greeting "` is looked up as `"greeting"`. Since a key with surrounding whitespace is usually a
+/// copy-paste mistake, this also emits a warning (the use of a deprecated item, which can be silenced with
+/// `#[allow(deprecated)]`). Two keys that are the same after trimming are a compile error. Keys are
+/// otherwise kept exactly as written, and the keys passed to `localize` at runtime aren't normalized at
+/// all. Locales can't contain whitespace, apart from the cells of a CSV header, which are trimmed.
+///
+/// # Key enum
+///
+/// Alongside the table, the macro generates an enum named after it with a `Key` suffix, which has a
+/// variant for every translation key that is a valid Rust identifier. Variants are named in
+/// `UpperCamelCase` (`"farewell_message"` becomes `FarewellMessage`), and can be passed to the generated
+/// `localize_key` function, which skips searching for the key. The enum implements `Copy` and `Display`
+/// (which prints the original key), and `all()` lists every variant.
+///
+/// Keys that aren't identifiers, like `"_"` or `"menu.open"`, are left out of the enum but can still be
+/// used with `localize`. If several keys map to the same variant name, only the first in sorted order
+/// gets a variant.
+///
+/// ```
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL {
+///     greeting = {
+///         en => "Hello",
+///         es => "Hola",
+///     },
+///     "farewell message" = {
+///         en => "Goodbye",
+///         es => "Adiós",
+///     }
+/// }}
+///
+/// assert_eq!(Spanglish::localize_key(SpanglishKey::Greeting, "es"), "Hola");
+/// assert_eq!(SpanglishKey::Greeting.to_string(), "greeting");
+/// assert_eq!(SpanglishKey::all(), [SpanglishKey::Greeting]);
+/// assert_eq!(Spanglish::localize("farewell message", "es"), "Adiós");
+/// ```
+///
+/// # Locale enum
+///
+/// The macro also generates an enum with a `Locale` suffix, with a variant for every locale in
+/// `UpperCamelCase` (`en-US` becomes `EnUs`). It can be parsed from a string with `TryFrom<&str>`
+/// or `FromStr`, which resolve aliases and return `UnknownLocale` instead of falling back to the
+/// default locale, and `handle()` gets its `LocaleHandle`. `from_str_fallback` parses dynamic input
+/// like a config value or an `Accept-Language` entry more leniently: it also tries the locale
+/// without its last subtags like `LocalizationTable::negotiate`, so `en-GB` becomes `En`, and
+/// returns `None` if nothing matches. Like the key enum, it implements `Copy` and `Display`, and
+/// `all()` lists every variant. It also implements `AsLocale`, like strings and `LocaleHandle`, so
+/// any of them can be passed to the generated `localize_in` function.
+///
+/// The generated `try_get_locale` function similarly returns `None` for unknown locales, where
+/// `get_locale` would fall back to the default locale.
+///
+/// ```
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL {
+///     greeting = {
+///         en-US => "Hello",
+///         es => "Hola",
+///     }
+/// }}
+///
+/// let locale = SpanglishLocale::try_from("es").unwrap();
+/// assert_eq!(locale, SpanglishLocale::Es);
+/// assert_eq!(locale.handle().localize("greeting"), "Hola");
+/// assert_eq!("en-US".parse(), Ok(SpanglishLocale::EnUs));
+/// assert!(SpanglishLocale::try_from("de").is_err());
+/// assert_eq!(SpanglishLocale::from_str_fallback("es-MX"), Some(SpanglishLocale::Es));
+/// assert_eq!(SpanglishLocale::from_str_fallback("de"), None);
+/// assert_eq!(Spanglish::localize_in("greeting", SpanglishLocale::EnUs), "Hello");
+///
+/// assert!(Spanglish::try_get_locale("es").is_some());
+/// assert!(Spanglish::try_get_locale("de").is_none());
+/// ```
+///
+/// # Generic code
+///
+/// The table struct implements `localize::Localizable`, so that functions can be generic over any
+/// generated table, like `fn greet<T: Localizable>(locale: &str) -> &'static str`.
+///
+/// # Attributes
+///
+/// Attributes can be placed between the syntax type and the translations to configure the table.
+///
+/// - `#[default_locale(locale)]` selects the locale to fall back to when a requested locale isn't
+///   part of the table. Without it, the first locale (in sorted order, unless the table has
+///   `#[preserve_order]`) is used. The index of the default locale is available as the generated
+///   `DEFAULT_LOCALE` constant, and the generated `DEFAULTING_TABLE` constant is a
+///   `DefaultingTable` that carries it in its type.
+/// - `#[alias(alias = locale, ...)]` lets `alias` be used anywhere a locale is expected, so that
+///   `localize("greeting", "english")` resolves to the `en` translations. Every alias must refer to a
+///   locale of the table and can't have the same name as one. Aliases are available as the generated
+///   `ALIASES` constant, and `resolve_locale` maps an alias to its locale.
+/// - `#[cfg_locale(locale, condition)]` only includes a locale in the table when a `cfg` condition holds,
+///   like `#[cfg_locale(fr, feature = "fr")]`, to leave locales out of region-specific builds. Everything
+///   else about the locale is left out too, including aliases to it and its `display_name` and
+///   `locale_meta`, and lookups fall back to the default locale as if it had never been written. The
+///   macro can't evaluate the conditions, so it generates the table for every combination of them behind
+///   `#[cfg(...)]`, which is why a table can only have 4 different conditions. The default locale can't
+///   have a condition.
+/// - `#[const_name(locale = NAME, ...)]` sets the name of the generated `LocaleHandle` constant of a
+///   locale, like `#[const_name(zh-Hant = ZH)]` for `Table::ZH` instead of `Table::ZH_HANT`, to shorten
+///   long names or avoid a collision between two locales that would get the same one. Other locales keep
+///   the default name. Two locales with the same constant name are a compile error.
+/// - `#[crate(path)]` sets the path to the `localize` crate used by the generated code, which is
+///   `::localize` by default. This is needed when the crate is renamed in `Cargo.toml` or only reachable
+///   through a re-export, like `#[crate(::my_localize)]` or `#[crate(crate::deps::localize)]`.
+/// - `#[default_key(locale = "key", ...)]` sets the translation key that fills in the missing translations
+///   of a locale instead of `"_"`, as described under [Default translations](#default-translations).
+/// - `#[display_name(locale = "name", ...)]` sets the name that a locale is shown as in a language picker,
+///   usually written in the locale's own language, like `es = "Español"`. The names of every locale are
+///   available as the generated `DISPLAY_NAMES` constant, in the same order as the table's `locales`, and
+///   the generated `display_name` function looks one up by locale. Locales without a display name are
+///   shown as the locale itself, and locales that aren't part of the table use the name of the default
+///   locale.
+/// - `#[locale_note(locale = "note", ...)]` attaches a note for translators to a locale, like
+///   `de = "Use formal 'Sie'"`. The notes are available as the generated `LOCALE_NOTES` constant, in the
+///   same order as the table's `locales`, and the generated `locale_note` function looks one up by locale,
+///   returning `None` for locales without a note. Each note also becomes the doc comment of the locale's
+///   constant and `Locale` enum variant, so it shows up in the generated docs.
+/// - `#[complete]` makes it a compile error for any cell of the table to be missing, so that every key
+///   must have a translation in every locale (or a `"_"` default). This is checked by a constant
+///   assertion on `LocalizationTable::is_complete`.
+/// - `#[generate_tests]` generates a `#[test]` next to the table, named after it with an
+///   `_is_complete_and_sorted` suffix, so that `cargo test` fails when the table is missing a translation
+///   (a cell is its `PLACEHOLDER`) or isn't sorted the way `LocalizationTable::new_checked` expects. The
+///   locales of a `#[preserve_order]` table aren't checked for order. Unlike `#[complete]`, this doesn't
+///   stop the crate from building, so a table can be incomplete during development.
+/// - `#[locales(en, es, ...)]` makes it a compile error for the translations to have any locale that
+///   isn't listed, or for a listed locale to have no translations, so that adding or removing a locale
+///   has to be done on purpose. The order of the list doesn't matter.
+/// - `#[locale_meta(locale = { ... }, ...)]` attaches metadata to locales: `rtl` marks a locale as written
+///   right-to-left, and `date_format = "%d/%m/%Y"` sets the pattern its dates are written with. The
+///   metadata of every locale is available as the generated `LOCALE_META` constant, and the generated
+///   `locale_meta`, `is_rtl`, `direction`, and `date_format` functions look it up by locale. Locales
+///   without an entry are left-to-right with ISO 8601 dates (`%Y-%m-%d`), and locales that aren't part of
+///   the table use the metadata of the default locale.
+/// - `#[match_threshold(n)]` sets the most translation keys a table can have for the generated `localize`
+///   to `match` on the locale and the translation key, which the compiler can turn into a jump on their
+///   lengths, instead of hashing the key to find it in `KEY_INDEX`. This is faster for small tables, but
+///   the generated code grows with every key, so larger tables only use `KEY_INDEX`. Both give the same
+///   translations. The default is 16, and `#[match_threshold(0)]` always uses `KEY_INDEX`.
+/// - `#[placeholder("...")]` sets the string for cells that have neither a translation nor a default, like
+///   `#[placeholder("")]` to show nothing, instead of `PLACEHOLDER`. It's available as the generated
+///   `PLACEHOLDER` constant either way. The cells of `TABLE` still hold `PLACEHOLDER`, so that lookups
+///   fall back to the default locale first and `LocalizationTable::is_complete` and `to_map` still find
+///   missing cells; the generated lookups and locale handles return the placeholder in their place.
+/// - `#[preserve_order]` keeps the locales in the order they first appear in the input instead of sorting
+///   them, for tables whose `locales` are shown to users in a curated order. Without `#[default_locale]`,
+///   the default is then the first locale written. The table no longer upholds the sorted order that
+///   `LocalizationTable` documents, so `debug_assert_sorted` panics on it and binary search can't be used
+///   on its `locales`. The lookups of this crate scan the locales, so they aren't affected. Translation
+///   keys are still sorted, and TOML files are read with their locales already sorted.
+/// - `#[size_report]` generates a `BYTES` constant with an estimate of the static memory the table takes
+///   up, to catch tables that grow too large with a test or a constant assertion. It is the length in
+///   bytes of every distinct translation (each is only stored once), translation key, and locale, plus
+///   the size of the `&str` references to them in the rows that the generated `localize` reads, which
+///   is `(ROWS * KEYS + KEYS + LOCALES) * size_of::<&str>()`, and of `ROW_INDEX` if there is one.
+///   `ROWS` is the number of locales, unless locales with identical translations, like untranslated or
+///   closely related ones, share a row: when that takes up less space, every distinct row is only
+///   stored once, and the generated `ROW_INDEX` constant has the index of the row of every locale.
+///   `TABLE`, which the other lookups and the locale handles read, still has a row for every locale.
+///   Other generated constants, like `KEY_INDEX` and `CONTEXTS`, aren't counted.
+/// - `#[strings_struct]` generates a struct named after the table with a `Strings` suffix, with a
+///   `&'static str` field for every translation key except `"_"`, and a `localize_struct(locale)` function
+///   that fills in every field for a locale at once. Field names are the keys in `snake_case`, with
+///   characters that can't be part of an identifier replaced by `_` (`"menu.open"` becomes `menu_open`).
+/// - `#[validate_locales]` makes it a compile error for a locale not to be a well-formed BCP 47 language
+///   tag, like `en`, `en-US`, `zh-Hant`, or `es-419`, to catch typos like `english123` or `en_US`. Only the
+///   syntax of the tag is checked, not whether its subtags are registered.
+/// - `#[warn_inconsistent_locales]` emits a compiler warning for every translation key that is missing
+///   a translation for a locale that other keys have, which is usually a forgotten translation. Keys
+///   that fall back to the `"_"` key still count as missing. The warning is reported as the use of a
+///   deprecated item, so it can be silenced with `#[allow(deprecated)]`.
+/// - `#[warn_same_as_key]` emits a compiler warning, the same way, for every translation that is
+///   identical to its translation key, like `ok = { en => "ok", fr => "ok" }`, which usually means it was
+///   copied without being translated. Keys whose translations are meant to be the same, like brand names,
+///   can be listed in `#[allow_same_as_key("ok", ...)]`; listing a key that isn't part of the table is a
+///   compile error.
+///
+/// ```
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL #[default_locale(en)] #[alias(english = en, spanish = es)] {
+///     "greeting" = {
+///         en => "Hello",
+///         es => "Hola",
+///     }
+/// }}
+///
+/// assert_eq!(Spanglish::localize("greeting", "de"), "Hello");
+/// assert_eq!(Spanglish::TABLE.locales[Spanglish::DEFAULT_LOCALE], "en");
+/// assert_eq!(Spanglish::localize("greeting", "spanish"), "Hola");
+/// assert_eq!(Spanglish::resolve_locale("english"), "en");
+/// ```
+///
+/// With `#[complete]`, a missing translation fails the build:
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL #[complete] {
+///     "greeting" = {
+///         en => "Hello",
+///         es => "Hola",
+///     },
+///     "farewell" = {
+///         en => "Goodbye",
+///     }
+/// }}
+/// ```
+///
+/// With `#[locales]`, a locale that isn't listed fails the build, and so does a listed locale without
+/// translations:
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL #[locales(en, es)] {
+///     "greeting" = {
+///         en => "Hello",
+///         es => "Hola",
+///         fr => "Bonjour",
+///     }
+/// }}
+/// ```
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL #[locales(en, es, fr)] {
+///     "greeting" = {
+///         en => "Hello",
+///         es => "Hola",
+///     }
+/// }}
+/// ```
+///
+/// With `#[validate_locales]`, a malformed locale fails the build:
+///
+/// ```compile_fail
+/// # use localize_macros::localization_table;
+///
+/// localization_table! {Spanglish = LDSL #[validate_locales] {
+///     "greeting" = {
+///         english123 => "Hello",
+///     }
+/// }}
+/// ```
 ///
 /// # Example
 ///
@@ -173,74 +638,71 @@ impl Parse for LDSLTranslationValue {
 /// assert_eq!(farewell_es, "Adiós");
 /// ```
 pub fn localization_table(table: TokenStream) -> TokenStream {
-    let TranslationInput {
-        struct_name,
-        strings,
-        locales,
-    } = parse_macro_input!(table as TranslationInput);
-    let mut locales = locales.into_iter().collect::<Vec<_>>();
-    locales.sort();
-    let locales = locales;
-    let mut translation_keys: Vec<String> = strings.keys().cloned().collect();
-    translation_keys.sort();
-    let translation_keys = translation_keys;
-
-    let num_keys = translation_keys.len();
-    let num_locales = locales.len();
-    let translations: Vec<_> = locales
-        // loop through each locale
-        .iter()
-        .map(|loc| {
-            // loop through each translation key
-            let translations: Vec<LitStr> = translation_keys
-                .iter()
-                .map(|key| {
-                    // get the map of locale to translation for this key
-                    strings
-                        .get(key)
-                        .and_then(|x| {
-                            // get the translation for this locale
-                            x.get(loc)
-                        })
-                        // but if it's not there, get the special "_" key
-                        .or_else(|| strings.get("_")?.get(loc))
-                        .cloned()
-                        .unwrap_or_else(|| {
-                            LitStr::new("<NO TRANSLATION>", Span::call_site().into())
-                        })
-                })
-                .collect();
-            quote! {[#(#translations),*]}
-        })
-        .collect();
-    let locale_strs: Vec<String> = locales.iter().map(Ident::to_string).collect();
-    let locales_upper: Vec<Ident> = locales
-        .iter()
-        .map(|loc| Ident::new(&loc.to_string().to_uppercase(), loc.span()))
-        .collect();
-    quote! {
-        pub struct #struct_name;
-
-        impl #struct_name {
-            pub const TABLE: ::localize::LocalizationTable<'static, #num_locales, #num_keys> = ::localize::LocalizationTable {
-                translation_keys: [#(#translation_keys),*],
-                locales: [#(#locale_strs),*],
-                translations: [#(#translations),*],
-            };
-
-            #[inline(always)]
-            pub const fn localize(translation_key: &str, locale: &str) -> &'static str {
-                Self::TABLE.localize(translation_key, locale)
-            }
-
-            #[inline(always)]
-            pub const fn get_locale(locale: &str) -> ::localize::LocaleHandle<'static, #num_keys> {
-                Self::TABLE.get_locale(locale)
-            }
+    localize_codegen::expand_tokens(table.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-            #(
-                pub const #locales_upper: ::localize::LocaleHandle<'static, #num_keys> = Self::TABLE.get_locale(#locale_strs);
-            )*
-        }
-    }.into()
+#[proc_macro]
+/// Generates several tables and a table that combines all of them.
+///
+/// The input is the name of the combined table, an equals sign, optional attributes for the combined
+/// table, and braces around a comma-separated list of tables written like the input of
+/// `localization_table`. Every listed table is generated as usual, and the combined table has every
+/// translation key and locale of all of them, like merging them with `OwnedLocalizationTable::merge`
+/// but at compile time.
+///
+/// The attributes of each listed table only apply to that table, and the attributes of the combined
+/// table only apply to it. A `"_"` key from any listed table fills in the missing translations of the
+/// combined table.
+///
+/// # Example
+///
+/// ```
+/// # use localize_macros::merge_tables;
+///
+/// merge_tables! {App = #[default_locale(en)] {
+///     Core = LDSL {
+///         "greeting" = {
+///             en => "Hello",
+///             es => "Hola",
+///         }
+///     },
+///     Plugin = LDSL {
+///         "plugin_name" = {
+///             en => "Spell Checker",
+///             fr => "Correcteur",
+///         }
+///     },
+/// }}
+///
+/// assert_eq!(App::TABLE.locales, ["en", "es", "fr"]);
+/// assert_eq!(App::localize("greeting", "es"), "Hola");
+/// assert_eq!(App::localize("plugin_name", "fr"), "Correcteur");
+/// assert_eq!(Core::TABLE.locales, ["en", "es"]);
+/// ```
+///
+/// Two tables can have the same translation key, as long as they don't both have a translation for it
+/// in the same locale (and context), since it isn't clear which one should win:
+///
+/// ```compile_fail
+/// # use localize_macros::merge_tables;
+///
+/// merge_tables! {App = {
+///     Core = LDSL {
+///         "greeting" = {
+///             en => "Hello",
+///         }
+///     },
+///     Plugin = LDSL {
+///         "greeting" = {
+///             en => "Hi",
+///         }
+///     },
+/// }}
+/// ```
+pub fn merge_tables(tables: TokenStream) -> TokenStream {
+    localize_codegen::expand_merge_tokens(tables.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
@@ -0,0 +1,49 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Anything that names a locale.
+///
+/// Lookups like [`LocalizationTable::localize_in`](crate::LocalizationTable::localize_in) take any of
+/// them, so a locale can be passed as a string, a [`LocaleHandle`](crate::LocaleHandle), or the locale
+/// enum generated by the `localization_table` macro.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, AsLocale};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// assert_eq!(Spanglish::localize_in("greeting", "es"), "Hola");
+/// assert_eq!(Spanglish::localize_in("greeting", Spanglish::ES), "Hola");
+/// assert_eq!(Spanglish::localize_in("greeting", SpanglishLocale::Es), "Hola");
+/// assert_eq!(SpanglishLocale::Es.as_locale(), "es");
+/// ```
+pub trait AsLocale {
+    /// Get the name of the locale
+    fn as_locale(&self) -> &str;
+}
+
+impl AsLocale for str {
+    fn as_locale(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsLocale for String {
+    fn as_locale(&self) -> &str {
+        self
+    }
+}
+
+impl<T: AsLocale + ?Sized> AsLocale for &T {
+    fn as_locale(&self) -> &str {
+        (**self).as_locale()
+    }
+}
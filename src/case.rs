@@ -0,0 +1,61 @@
+use alloc::string::String;
+
+/// Languages that write the dotted and dotless `i` as separate letters, so that `i` pairs with `İ` and
+/// `ı` pairs with `I`, instead of the default `i` and `I`
+const DOTTED_I_LANGUAGES: [&str; 2] = ["tr", "az"];
+
+/// Whether the language of `locale` is one of [`DOTTED_I_LANGUAGES`]. Only the language part of the
+/// locale is used, so `tr-CY` is cased like `tr`.
+fn has_dotted_i(locale: &str) -> bool {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    DOTTED_I_LANGUAGES
+        .iter()
+        .any(|lang| language.eq_ignore_ascii_case(lang))
+}
+
+/// Convert `s` to uppercase with the casing rules of the given locale.
+///
+/// Turkish (`tr`) and Azerbaijani (`az`) uppercase `i` to `İ` (and `ı` to `I`), so that a dotted `i`
+/// keeps its dot. Every other locale uses the default Unicode casing of [`str::to_uppercase`].
+///
+/// # Example
+///
+/// ```
+/// use localize::to_locale_upper;
+///
+/// assert_eq!(to_locale_upper("en", "istanbul"), "ISTANBUL");
+/// assert_eq!(to_locale_upper("tr", "istanbul"), "İSTANBUL");
+/// assert_eq!(to_locale_upper("tr-TR", "ılık"), "ILIK");
+/// ```
+#[must_use]
+pub fn to_locale_upper(locale: &str, s: &str) -> String {
+    if has_dotted_i(locale) {
+        s.replace('i', "İ").to_uppercase()
+    } else {
+        s.to_uppercase()
+    }
+}
+
+/// Convert `s` to lowercase with the casing rules of the given locale.
+///
+/// Turkish (`tr`) and Azerbaijani (`az`) lowercase `I` to `ı` and `İ` to `i`. Every other locale uses
+/// the default Unicode casing of [`str::to_lowercase`], which lowercases `İ` to `i` followed by a
+/// combining dot.
+///
+/// # Example
+///
+/// ```
+/// use localize::to_locale_lower;
+///
+/// assert_eq!(to_locale_lower("en", "ISTANBUL"), "istanbul");
+/// assert_eq!(to_locale_lower("tr", "ISPARTA"), "ısparta");
+/// assert_eq!(to_locale_lower("az", "İSTANBUL"), "istanbul");
+/// ```
+#[must_use]
+pub fn to_locale_lower(locale: &str, s: &str) -> String {
+    if has_dotted_i(locale) {
+        s.replace('I', "ı").replace('İ', "i").to_lowercase()
+    } else {
+        s.to_lowercase()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::strcmp;
+
+/// A translation that only applies to a key and locale in a specific context.
+///
+/// Context variants are declared in the `localization_table` macro by following a locale with a context in
+/// parentheses, and are looked up with the generated `localize_ctx` function. This is useful for languages
+/// where a translation depends on grammatical gender or similar context.
+///
+/// # Example
+///
+/// ```
+/// # use localize::localization_table;
+///
+/// localization_table!{Welcome = LDSL {
+///     "welcome" = {
+///         en => "Welcome",
+///         es => "Bienvenido",
+///         es(feminine) => "Bienvenida",
+///     }
+/// }}
+///
+/// assert_eq!(Welcome::localize_ctx("welcome", "es", "feminine"), "Bienvenida");
+/// assert_eq!(Welcome::localize_ctx("welcome", "es", "masculine"), "Bienvenido");
+/// assert_eq!(Welcome::localize_ctx("welcome", "en", "feminine"), "Welcome");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContextVariant<'a> {
+    pub translation_key: &'a str,
+    pub locale: &'a str,
+    pub context: &'a str,
+    pub translation: &'a str,
+}
+
+impl<'a> ContextVariant<'a> {
+    /// Find the translation for the given key, locale, and context in a list of variants
+    #[must_use]
+    pub const fn find(
+        variants: &[Self],
+        translation_key: &str,
+        locale: &str,
+        context: &str,
+    ) -> Option<&'a str> {
+        let mut i = 0;
+        while i < variants.len() {
+            let variant = &variants[i];
+            if strcmp(variant.translation_key, translation_key)
+                && strcmp(variant.locale, locale)
+                && strcmp(variant.context, context)
+            {
+                return Some(variant.translation);
+            }
+            i += 1;
+        }
+        None
+    }
+}
@@ -0,0 +1,18 @@
+use crate::namespace::strip_segment;
+
+/// The suffix of the sibling key for `count`: `zero`, `one`, or `many`
+pub const fn bucket(count: u64) -> &'static str {
+    match count {
+        0 => "zero",
+        1 => "one",
+        _ => "many",
+    }
+}
+
+/// Find the index of the sibling of `translation_key` for `count`, like `messages.one`
+pub fn find_bucket(translation_keys: &[&str], translation_key: &str, count: u64) -> Option<usize> {
+    let bucket = bucket(count);
+    translation_keys
+        .iter()
+        .position(|key| strip_segment(key, translation_key) == Some(bucket))
+}
@@ -0,0 +1,104 @@
+use crate::{KeyIndex, LocaleHandle, LocalizationTable};
+
+/// A [`LocalizationTable`] that carries the index of its default locale in its type, so that falling
+/// back to the default locale is a constant index instead of a value passed around at runtime.
+///
+/// Tables generated by the `localization_table` macro have a `DEFAULTING_TABLE` constant whose `DEFAULT`
+/// is the index of the `#[default_locale]` (or `0` without the attribute), like their `DEFAULT_LOCALE`
+/// constant.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, DefaultingTable};
+///
+/// localization_table!{Spanglish = LDSL #[default_locale(es)] {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// let spanglish: DefaultingTable<'static, 2, 1, 1> = Spanglish::DEFAULTING_TABLE;
+/// assert_eq!(spanglish.localize("greeting", "en"), "Hello");
+/// assert_eq!(spanglish.localize("greeting", "de"), "Hola");
+/// assert_eq!(spanglish.default_locale(), "es");
+///
+/// // the default can also be chosen for any table
+/// let english = DefaultingTable::<2, 1, 0>::new(Spanglish::TABLE);
+/// assert_eq!(english.localize("greeting", "de"), "Hello");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DefaultingTable<'a, const LOCALES: usize, const KEYS: usize, const DEFAULT: usize> {
+    table: LocalizationTable<'a, LOCALES, KEYS>,
+}
+
+impl<'a, const LOCALES: usize, const KEYS: usize, const DEFAULT: usize>
+    DefaultingTable<'a, LOCALES, KEYS, DEFAULT>
+{
+    /// Wrap `table`, using the locale at index `DEFAULT` as its default locale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `DEFAULT` isn't in `0..LOCALES`. When the table is created in a constant, this is a
+    /// compile error instead.
+    #[must_use]
+    pub const fn new(table: LocalizationTable<'a, LOCALES, KEYS>) -> Self {
+        assert!(
+            DEFAULT < LOCALES,
+            "the default locale of a `DefaultingTable` must be one of its locales"
+        );
+        Self { table }
+    }
+
+    /// Get the table without the default locale
+    #[must_use]
+    pub const fn table(&self) -> &LocalizationTable<'a, LOCALES, KEYS> {
+        &self.table
+    }
+
+    /// Get the name of the default locale
+    #[must_use]
+    pub const fn default_locale(&self) -> &'a str {
+        self.table.locales[DEFAULT]
+    }
+
+    /// Translates a given key like [`LocalizationTable::localize`], but falls back to the locale at index
+    /// `DEFAULT` when `locale` isn't part of the table.
+    #[inline]
+    #[must_use]
+    pub const fn localize(&self, translation_key: &str, locale: &str) -> &'a str {
+        self.table
+            .localize_with_default(translation_key, locale, DEFAULT)
+    }
+
+    /// Translates a given key like [`localize`](Self::localize), but uses `index` to find the translation
+    /// key instead of scanning every key, like [`LocalizationTable::localize_with_index`].
+    #[inline]
+    #[must_use]
+    pub const fn localize_with_index(
+        &self,
+        index: &KeyIndex<'_>,
+        translation_key: &str,
+        locale: &str,
+    ) -> &'a str {
+        self.table
+            .localize_with_index(index, translation_key, locale, DEFAULT)
+    }
+
+    /// Create a reference to the specified locale, or to the locale at index `DEFAULT` if `locale` isn't
+    /// part of the table.
+    #[inline]
+    #[must_use]
+    pub const fn get_locale(&'a self, locale: &str) -> LocaleHandle<'a, KEYS> {
+        self.table.get_locale_with_default(locale, DEFAULT)
+    }
+}
+
+impl<'a, const LOCALES: usize, const KEYS: usize, const DEFAULT: usize>
+    From<DefaultingTable<'a, LOCALES, KEYS, DEFAULT>> for LocalizationTable<'a, LOCALES, KEYS>
+{
+    fn from(table: DefaultingTable<'a, LOCALES, KEYS, DEFAULT>) -> Self {
+        table.table
+    }
+}
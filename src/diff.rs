@@ -0,0 +1,46 @@
+use core::fmt::{self, Display};
+
+/// One difference between two tables, as found by
+/// [`LocalizationTable::diff`](crate::LocalizationTable::diff).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TableChange<'a> {
+    /// The translation key is only part of the new table
+    AddedKey(&'a str),
+    /// The translation key is only part of the old table
+    RemovedKey(&'a str),
+    /// The locale is only part of the new table
+    AddedLocale(&'a str),
+    /// The locale is only part of the old table
+    RemovedLocale(&'a str),
+    /// The translation of a key that both tables have, in a locale that both tables have, is different
+    ChangedCell {
+        locale: &'a str,
+        translation_key: &'a str,
+        old: &'a str,
+        new: &'a str,
+    },
+}
+
+impl Display for TableChange<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddedKey(translation_key) => {
+                write!(f, "added translation key `{translation_key}`")
+            }
+            Self::RemovedKey(translation_key) => {
+                write!(f, "removed translation key `{translation_key}`")
+            }
+            Self::AddedLocale(locale) => write!(f, "added locale `{locale}`"),
+            Self::RemovedLocale(locale) => write!(f, "removed locale `{locale}`"),
+            Self::ChangedCell {
+                locale,
+                translation_key,
+                old,
+                new,
+            } => write!(
+                f,
+                "changed `{translation_key}` in `{locale}` from {old:?} to {new:?}"
+            ),
+        }
+    }
+}
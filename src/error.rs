@@ -0,0 +1,74 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+/// The error returned when a locale isn't part of a [`LocalizationTable`](crate::LocalizationTable).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnknownLocale;
+
+impl Display for UnknownLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown locale")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownLocale {}
+
+/// The reason [`LocalizationTable::new_checked`](crate::LocalizationTable::new_checked) rejected a table,
+/// with the first translation key or locale that isn't greater than the one before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TableError<'a> {
+    /// The translation key comes before the key before it
+    UnsortedKey(&'a str),
+    /// The translation key is the same as the key before it
+    DuplicateKey(&'a str),
+    /// The locale comes before the locale before it
+    UnsortedLocale(&'a str),
+    /// The locale is the same as the locale before it
+    DuplicateLocale(&'a str),
+}
+
+impl Display for TableError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsortedKey(translation_key) => {
+                write!(f, "translation key `{translation_key}` is out of order")
+            }
+            Self::DuplicateKey(translation_key) => {
+                write!(f, "duplicate translation key `{translation_key}`")
+            }
+            Self::UnsortedLocale(locale) => write!(f, "locale `{locale}` is out of order"),
+            Self::DuplicateLocale(locale) => write!(f, "duplicate locale `{locale}`"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TableError<'_> {}
+
+/// The reason a lookup with [`LocalizationTable::localize_result`](crate::LocalizationTable::localize_result)
+/// failed.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LocalizeError {
+    /// The locale isn't part of the table
+    UnknownLocale(String),
+    /// The translation key isn't part of the table
+    UnknownKey(String),
+}
+
+#[cfg(feature = "alloc")]
+impl Display for LocalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownLocale(locale) => write!(f, "unknown locale `{locale}`"),
+            Self::UnknownKey(translation_key) => {
+                write!(f, "unknown translation key `{translation_key}`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LocalizeError {}
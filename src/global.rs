@@ -0,0 +1,75 @@
+use crate::{Localizable, UnknownLocale};
+use std::sync::{PoisonError, RwLock};
+
+/// The locale set with [`set_global_locale`], or empty before it's set
+static GLOBAL_LOCALE: RwLock<String> = RwLock::new(String::new());
+
+/// Set the locale that [`global_localize`] and the [`tg!`](crate::tg) macro translate into, for
+/// applications with a single current locale that every thread shares.
+///
+/// The locale is checked against the locales of the table `T`; aliases have to be resolved first with
+/// the table's generated `resolve_locale` function. Before the first successful call, there is no global
+/// locale, so translations use the default locale of each table.
+///
+/// # Errors
+///
+/// Returns [`UnknownLocale`] and keeps the current global locale if `locale` isn't one of the locales of
+/// `T`.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, set_global_locale, tg, UnknownLocale};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// assert_eq!(tg!(Spanglish, "greeting"), "Hello");
+///
+/// set_global_locale::<Spanglish>("es").unwrap();
+/// assert_eq!(tg!(Spanglish, "greeting"), "Hola");
+///
+/// assert_eq!(set_global_locale::<Spanglish>("de"), Err(UnknownLocale));
+/// assert_eq!(tg!(Spanglish, "greeting"), "Hola");
+/// ```
+pub fn set_global_locale<T: Localizable>(locale: &str) -> Result<(), UnknownLocale> {
+    if !T::locales().contains(&locale) {
+        return Err(UnknownLocale);
+    }
+    locale.clone_into(
+        &mut GLOBAL_LOCALE
+            .write()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+    Ok(())
+}
+
+/// Get the locale set with [`set_global_locale`], or `None` if it hasn't been set
+#[must_use]
+pub fn global_locale() -> Option<String> {
+    let locale = GLOBAL_LOCALE.read().unwrap_or_else(PoisonError::into_inner);
+    (!locale.is_empty()).then(|| locale.as_str().to_owned())
+}
+
+/// Translate a key into the global locale from [`set_global_locale`].
+///
+/// This uses the generated `localize` function of the table `T`, so a locale that `T` doesn't have (or no
+/// global locale) falls back to its default locale.
+#[must_use]
+pub fn global_localize<T: Localizable>(translation_key: &str) -> &'static str {
+    let locale = GLOBAL_LOCALE.read().unwrap_or_else(PoisonError::into_inner);
+    T::localize(translation_key, &locale)
+}
+
+/// Translate a key into the global locale, like [`global_localize`]. `tg!(Table, "key")` is short for
+/// `global_localize::<Table>("key")`.
+#[macro_export]
+macro_rules! tg {
+    ($table:path, $key:expr) => {
+        $crate::global_localize::<$table>($key)
+    };
+}
@@ -0,0 +1,272 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::{
+    fmt::{self, Write},
+    marker::PhantomData,
+};
+
+/// Substitute `{name}` placeholders in `template` with the matching values from `args`.
+///
+/// - `{name}` is replaced with the value paired with `name` in `args`.
+/// - Placeholders that don't have a value in `args` are left as-is, braces included.
+/// - `{{` and `}}` are replaced with literal `{` and `}`.
+///
+/// The template is only copied when something is actually replaced, so this returns [`Cow::Borrowed`]
+/// for templates without placeholders.
+///
+/// # Example
+///
+/// ```
+/// use localize::interpolate;
+/// use std::borrow::Cow;
+///
+/// let greeting = interpolate("Hello, {name}!", &[("name", "Ferris")]);
+/// assert_eq!(greeting, "Hello, Ferris!");
+/// assert!(matches!(greeting, Cow::Owned(_)));
+///
+/// let plain = interpolate("Hello!", &[("name", "Ferris")]);
+/// assert!(matches!(plain, Cow::Borrowed("Hello!")));
+///
+/// assert_eq!(interpolate("{{name}} is {name}", &[("name", "Ferris")]), "{name} is Ferris");
+/// assert_eq!(interpolate("Hello, {nmae}!", &[("name", "Ferris")]), "Hello, {nmae}!");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn interpolate<'a>(template: &'a str, args: &[(&str, &str)]) -> Cow<'a, str> {
+    collect(template, || Pieces::new(template, args))
+}
+
+/// Substitute positional `{0}`, `{1}`, ... placeholders in `template` with the matching elements of
+/// `args`, like `format!`.
+///
+/// - `{0}` is replaced with `args[0]`, `{1}` with `args[1]`, and so on.
+/// - Placeholders without an element in `args`, and placeholders that aren't an index, are left as-is,
+///   braces included.
+/// - `{{` and `}}` are replaced with literal `{` and `}`.
+///
+/// Like [`interpolate`], the template is only copied when something is actually replaced.
+///
+/// # Example
+///
+/// ```
+/// use localize::interpolate_positional;
+///
+/// assert_eq!(interpolate_positional("{0} sent {1} files", &["Ferris", "3"]), "Ferris sent 3 files");
+/// assert_eq!(interpolate_positional("{1} of {2}", &["a", "b"]), "b of {2}");
+/// assert_eq!(interpolate_positional("{{0}} is {0}", &["zero"]), "{0} is zero");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn interpolate_positional<'a>(template: &'a str, args: &[&str]) -> Cow<'a, str> {
+    collect(template, || Pieces::positional(template, args))
+}
+
+/// Build the output of the pieces from `pieces`, borrowing `template` if none of them were replaced
+#[cfg(feature = "alloc")]
+fn collect<'t, 'v, L: Lookup<'v>>(
+    template: &'t str,
+    pieces: impl Fn() -> Pieces<'t, 'v, L>,
+) -> Cow<'t, str> {
+    if !pieces().any(|piece| matches!(piece, Piece::Replaced(_))) {
+        return Cow::Borrowed(template);
+    }
+    let mut output = String::with_capacity(template.len());
+    for piece in pieces() {
+        match piece {
+            Piece::Unchanged(text) | Piece::Replaced(text) => output.push_str(text),
+        }
+    }
+    Cow::Owned(output)
+}
+
+/// Substitute `{name}` placeholders in `template` like [`interpolate`], writing the output to `writer`
+/// piece by piece instead of building a `String`.
+///
+/// This doesn't allocate, so it also works without the `alloc` feature, and it can write directly into
+/// the `Formatter` of a `Display` implementation.
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+///
+/// # Example
+///
+/// ```
+/// use localize::interpolate_into;
+/// use std::fmt::{self, Display};
+///
+/// struct Greeting<'a>(&'a str);
+///
+/// impl Display for Greeting<'_> {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         interpolate_into(f, "Hello, {name}!", &[("name", self.0)])
+///     }
+/// }
+///
+/// assert_eq!(Greeting("Ferris").to_string(), "Hello, Ferris!");
+/// ```
+pub fn interpolate_into<W: Write + ?Sized>(
+    writer: &mut W,
+    template: &str,
+    args: &[(&str, &str)],
+) -> fmt::Result {
+    for piece in Pieces::new(template, args) {
+        match piece {
+            Piece::Unchanged(text) | Piece::Replaced(text) => writer.write_str(text)?,
+        }
+    }
+    Ok(())
+}
+
+/// A template and the values of its placeholders, which is interpolated like [`interpolate_into`] when it's
+/// displayed.
+///
+/// Formatting writes the pieces of the output straight to the `Formatter`, so a message can be passed to
+/// `println!` or `write!` without building a `String` first, and without the `alloc` feature. See
+/// [`LocalizationTable::message`](crate::LocalizationTable::message) to create one from a translation.
+///
+/// # Example
+///
+/// ```
+/// use localize::LocalizedMessage;
+///
+/// let message = LocalizedMessage::new("{count} new {{messages}}", &[("count", "3")]);
+/// assert_eq!(message.to_string(), "3 new {messages}");
+/// assert_eq!(format!("[{message}]"), "[3 new {messages}]");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocalizedMessage<'a> {
+    template: &'a str,
+    args: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> LocalizedMessage<'a> {
+    /// Create a message that substitutes the `{name}` placeholders of `template` with the values from
+    /// `args` when it's displayed
+    #[must_use]
+    pub const fn new(template: &'a str, args: &'a [(&'a str, &'a str)]) -> Self {
+        Self { template, args }
+    }
+
+    /// Get the template, before any placeholders are substituted
+    #[must_use]
+    pub const fn template(&self) -> &'a str {
+        self.template
+    }
+}
+
+impl fmt::Display for LocalizedMessage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        interpolate_into(f, self.template, self.args)
+    }
+}
+
+/// A part of an interpolated template
+enum Piece<'t, 'v> {
+    /// Text from the template that is copied as-is
+    Unchanged(&'t str),
+    /// Text that replaces a placeholder or an escaped brace
+    Replaced(&'v str),
+}
+
+/// Splits a template into the pieces of its interpolated output, looking up the value of each
+/// placeholder with `lookup`
+struct Pieces<'t, 'v, L> {
+    rest: &'t str,
+    lookup: L,
+    value: PhantomData<&'v str>,
+}
+
+impl<'t, 'v, 'args> Pieces<'t, 'v, NamedArgs<'args, 'v>> {
+    const fn new(template: &'t str, args: &'args [(&'args str, &'v str)]) -> Self {
+        Self {
+            rest: template,
+            lookup: NamedArgs(args),
+            value: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'t, 'v, 'args> Pieces<'t, 'v, PositionalArgs<'args, 'v>> {
+    const fn positional(template: &'t str, args: &'args [&'v str]) -> Self {
+        Self {
+            rest: template,
+            lookup: PositionalArgs(args),
+            value: PhantomData,
+        }
+    }
+}
+
+/// The values of the placeholders of a template
+trait Lookup<'v> {
+    /// Get the value of the placeholder `{name}`, if there is one
+    fn lookup(&self, name: &str) -> Option<&'v str>;
+}
+
+/// Values for `{name}` placeholders, paired with their names
+struct NamedArgs<'args, 'v>(&'args [(&'args str, &'v str)]);
+
+impl<'v> Lookup<'v> for NamedArgs<'_, 'v> {
+    fn lookup(&self, name: &str) -> Option<&'v str> {
+        self.0
+            .iter()
+            .find_map(|(arg, value)| (*arg == name).then_some(*value))
+    }
+}
+
+/// Values for `{0}`, `{1}`, ... placeholders, in order
+#[cfg(feature = "alloc")]
+struct PositionalArgs<'args, 'v>(&'args [&'v str]);
+
+#[cfg(feature = "alloc")]
+impl<'v> Lookup<'v> for PositionalArgs<'_, 'v> {
+    fn lookup(&self, name: &str) -> Option<&'v str> {
+        if name.is_empty() || !name.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        self.0.get(name.parse::<usize>().ok()?).copied()
+    }
+}
+
+impl<'t, 'v, L: Lookup<'v>> Iterator for Pieces<'t, 'v, L> {
+    type Item = Piece<'t, 'v>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest;
+        if rest.is_empty() {
+            return None;
+        }
+        let Some(brace) = rest.find(['{', '}']) else {
+            self.rest = "";
+            return Some(Piece::Unchanged(rest));
+        };
+        if brace > 0 {
+            self.rest = &rest[brace..];
+            return Some(Piece::Unchanged(&rest[..brace]));
+        }
+        if let Some(after) = rest.strip_prefix("{{") {
+            self.rest = after;
+            return Some(Piece::Replaced("{"));
+        }
+        if let Some(after) = rest.strip_prefix("}}") {
+            self.rest = after;
+            return Some(Piece::Replaced("}"));
+        }
+        if let Some(after) = rest.strip_prefix('}') {
+            self.rest = after;
+            return Some(Piece::Unchanged("}"));
+        }
+        // `rest` starts with a single `{`
+        let Some(close) = rest.find('}') else {
+            self.rest = "";
+            return Some(Piece::Unchanged(rest));
+        };
+        self.rest = &rest[close + 1..];
+        Some(
+            self.lookup
+                .lookup(&rest[1..close])
+                .map_or(Piece::Unchanged(&rest[..=close]), Piece::Replaced),
+        )
+    }
+}
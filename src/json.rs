@@ -0,0 +1,24 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Append `value` to `json` as a quoted JSON string, escaping it as needed
+pub fn push_string(json: &mut String, value: &str) {
+    json.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '\u{08}' => json.push_str("\\b"),
+            '\u{0c}' => json.push_str("\\f"),
+            ch if (ch as u32) < 0x20 => {
+                // writing to a `String` can't fail
+                let _ = write!(json, "\\u{:04x}", ch as u32);
+            }
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+}
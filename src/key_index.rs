@@ -0,0 +1,80 @@
+use crate::strcmp;
+
+/// A precomputed hash table mapping translation keys to their index in a [`LocalizationTable`](crate::LocalizationTable).
+///
+/// The `localization_table` macro generates one of these as the `KEY_INDEX` constant and uses it to find
+/// translation keys in constant time instead of scanning every key.
+///
+/// The table uses open addressing with linear probing: each key is placed in the first empty slot at or
+/// after `hash_key(key) % slots.len()`. The number of slots must be a power of two, and at least one slot
+/// must be [`EMPTY`](Self::EMPTY) so that lookups of unknown keys terminate.
+///
+/// # Example
+///
+/// ```
+/// # use localize::localization_table;
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    },
+///    "farewell" = {
+///        en => "Goodbye",
+///        es => "Adiós"
+///    }
+/// }}
+///
+/// let keys = &Spanglish::TABLE.translation_keys;
+/// assert_eq!(Spanglish::KEY_INDEX.get(keys, "greeting"), Some(1));
+/// assert_eq!(Spanglish::KEY_INDEX.get(keys, "welcome"), None);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct KeyIndex<'a> {
+    /// The index of the translation key stored in each slot, or [`EMPTY`](Self::EMPTY)
+    pub slots: &'a [usize],
+}
+
+impl KeyIndex<'_> {
+    /// The value of a slot that doesn't hold a translation key
+    pub const EMPTY: usize = usize::MAX;
+
+    /// Find the index of `translation_key` in `translation_keys`, which must be the keys this index was
+    /// built from.
+    #[must_use]
+    pub const fn get(&self, translation_keys: &[&str], translation_key: &str) -> Option<usize> {
+        let mask = self.slots.len() - 1;
+        #[allow(clippy::cast_possible_truncation)]
+        let mut slot = hash_key(translation_key) as usize & mask;
+        loop {
+            let idx = self.slots[slot];
+            if idx == Self::EMPTY {
+                // the hash is of the bytes, so a key that's only canonically equivalent to one of the
+                // translation keys ends up in the wrong slot
+                #[cfg(feature = "nfc")]
+                if !translation_key.is_ascii() {
+                    return crate::find(translation_keys, translation_key);
+                }
+                return None;
+            }
+            if strcmp(translation_keys[idx], translation_key) {
+                return Some(idx);
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash of a translation key, as used by [`KeyIndex`].
+#[must_use]
+pub const fn hash_key(translation_key: &str) -> u64 {
+    let bytes = translation_key.as_bytes();
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
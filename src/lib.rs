@@ -37,15 +37,150 @@
 //! let farewell_es = Spanglish::localize("farewell", "es");
 //! assert_eq!(farewell_es, "Adiós");
 //! ```
+//!
+//! # Features
+//! * `std` (default): Implies `alloc` and enables the global miss handler, [`set_miss_handler`], the
+//!   global locale, [`set_global_locale`], [`LocalizationTable::to_map`], and [`Overlay`]. Without it, the
+//!   crate is `#![no_std]`.
+//! * `alloc`: Enables everything that allocates, like `OwnedLocalizationTable`, `PseudoLocalizer`,
+//!   `interpolate`, `format_message`, `format_number`, `format_decimal`, `to_locale_upper`,
+//!   `LocalizationTable::diff`, and the owned lookups `localize_string` and `localize_cstring`.
+//!   [`LocalizationTable`], [`LocaleHandle`], and the `localization_table` macro work without any
+//!   features.
+//! * `nfc`: Compares translation keys (and locales and contexts) by canonical equivalence with
+//!   `strcmp_nfc`, so that a key written with a precomposed `é` matches one written as `e` followed by a
+//!   combining accent. This is useful for tables keyed by source-language text. Strings that are ASCII
+//!   or byte-for-byte equal are compared as before, but other comparisons decompose both strings
+//!   character by character with table lookups, which is several times slower. A non-ASCII key that
+//!   isn't byte-for-byte part of the table is also looked for by scanning every key instead of through
+//!   the generated `KEY_INDEX`. Off by default.
+//!
+//! # Showing keys
+//!
+//! Building with `RUSTFLAGS="--cfg localize_show_keys"` makes the `localize` function generated by the
+//! `localization_table` macro return the translation key itself instead of its translation, so a UI
+//! shows `greeting` instead of `Hello`. This is a QA mode to spot text that isn't localized, since
+//! anything that doesn't look like a key didn't go through a table. Only keys that are identifiers, like
+//! `greeting` or `sign_in`, are shown; keys like `"_"` or `"apples.one"`, and keys that aren't part of
+//! the table, are still looked up as usual. It's a `cfg` flag rather than a feature so that it's never
+//! turned on by `--all-features`, and it's only meant for development builds.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::pedantic, clippy::nursery)]
-pub use localize_macros::localization_table;
-use std::fmt::Display;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    ffi::{CString, NulError},
+    string::String,
+    vec::Vec,
+};
+pub use as_locale::AsLocale;
+#[cfg(feature = "alloc")]
+pub use case::{to_locale_lower, to_locale_upper};
+pub use context::ContextVariant;
+use core::{
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    ops::Index,
+};
+pub use defaulting::DefaultingTable;
+#[cfg(feature = "alloc")]
+pub use diff::TableChange;
+#[cfg(feature = "alloc")]
+pub use error::LocalizeError;
+pub use error::{TableError, UnknownLocale};
+#[cfg(feature = "std")]
+pub use global::{global_locale, global_localize, set_global_locale};
+#[cfg(feature = "alloc")]
+pub use interpolate::{interpolate, interpolate_positional};
+pub use interpolate::{interpolate_into, LocalizedMessage};
+pub use key_index::{hash_key, KeyIndex};
+pub use localizable::Localizable;
+pub use localize_macros::{localization_table, merge_tables};
+pub use localizer::Localizer;
+#[cfg(feature = "alloc")]
+pub use message::{format_message, plural_category, MessageArg, PluralCategory};
+pub use meta::{Direction, LocaleMeta};
+#[cfg(feature = "std")]
+pub use miss::{set_miss_handler, MissHandler};
+pub use namespace::{NamespacedHandle, NAMESPACE_SEPARATOR};
+#[cfg(feature = "nfc")]
+pub use nfc::strcmp_nfc;
+pub use number::NumberFormat;
+#[cfg(feature = "alloc")]
+pub use number::{format_decimal, format_number};
+#[cfg(feature = "std")]
+pub use overlay::Overlay;
+#[cfg(feature = "alloc")]
+pub use owned::{OwnedLocaleHandle, OwnedLocalizationTable};
+#[cfg(feature = "alloc")]
+pub use pseudo::PseudoLocalizer;
+pub use resolution::Resolution;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+mod as_locale;
+#[cfg(feature = "alloc")]
+mod case;
+mod context;
+mod count;
+mod defaulting;
+#[cfg(feature = "alloc")]
+mod diff;
+mod error;
+#[cfg(feature = "std")]
+mod global;
+mod interpolate;
+#[cfg(feature = "alloc")]
+mod json;
+mod key_index;
+mod localizable;
+mod localizer;
+#[cfg(feature = "alloc")]
+mod message;
+mod meta;
+#[cfg(feature = "std")]
+mod miss;
+mod namespace;
+#[cfg(feature = "nfc")]
+mod nfc;
+mod number;
+#[cfg(feature = "std")]
+mod overlay;
+#[cfg(feature = "alloc")]
+mod owned;
+mod properties;
+#[cfg(feature = "alloc")]
+mod pseudo;
+mod resolution;
+#[cfg(feature = "alloc")]
+mod suggest;
+
+/// The translation used for cells that have neither a translation nor a `"_"` default.
+///
+/// Lookups that don't find a translation in any of their fallbacks return this, rather than an empty
+/// string, so that a missing translation is visible. A table generated by the `localization_table`
+/// macro can use a different string with its `#[placeholder(...)]` attribute.
+pub const PLACEHOLDER: &str = "<NO TRANSLATION>";
+
+/// Whether the crate is built with `--cfg localize_show_keys`, which makes the `localize` function
+/// generated by the `localization_table` macro return translation keys instead of their translations.
+pub const SHOW_KEYS: bool = cfg!(localize_show_keys);
 
 /// A table of translations based on locale.
 ///
 /// The best way to generate this struct is through the `localization_table` macro,
 /// which provides a simple syntax and guarantees that the translation keys and locales are formatted properly.
+///
+/// The macro sorts `translation_keys` and `locales` in strictly ascending (byte-wise) order, and
+/// this is part of the contract of the type: lookups may rely on it. Tables built by hand should
+/// keep the same order, which [`new_checked`](Self::new_checked) and
+/// [`debug_assert_sorted`](Self::debug_assert_sorted) can check. The only exception is a table
+/// generated with `#[preserve_order]`, whose locales are in the order they were written; the
+/// lookups of this crate scan the locales in order, so they work on either kind of table.
 /// # Example
 ///
 /// ```
@@ -77,7 +212,7 @@ use std::fmt::Display;
 /// let farewell_es = spanglish.localize("farewell", "es");
 /// assert_eq!(farewell_es, "Adiós");
 /// ```
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct LocalizationTable<'a, const LOCALES: usize, const KEYS: usize> {
     pub translation_keys: [&'a str; KEYS],
     pub locales: [&'a str; LOCALES],
@@ -97,8 +232,13 @@ impl<'a, const LOCALES: usize, const KEYS: usize> LocalizationTable<'a, LOCALES,
     /// # Returns
     ///
     /// - A reference to the localized string corresponding to the key and locale.
-    /// - If the translation is not available for the specified locale and the default locale,
-    ///   an empty string is returned.
+    /// - If the locale isn't part of the table, the first locale is used instead, and if the key isn't
+    ///   part of the table, the first key is used instead.
+    /// - If the locale doesn't have a translation for the key, the translation is looked up in this order:
+    ///   the `"_"` default of the locale, the translation of the default locale, the `"_"` default of the
+    ///   default locale, and finally [`PLACEHOLDER`]. The default locale is the first locale here, or the
+    ///   `#[default_locale]` of a table generated by the `localization_table` macro.
+    /// - If the table [is empty](Self::is_empty), [`PLACEHOLDER`] is returned for every key and locale.
     ///
     /// # Example
     ///
@@ -130,19 +270,166 @@ impl<'a, const LOCALES: usize, const KEYS: usize> LocalizationTable<'a, LOCALES,
     /// assert_eq!(farewell_es, "Adiós");
     /// ```
     pub const fn localize(&self, translation_key: &str, locale: &str) -> &'a str {
-        self.translations[find_idx(&self.locales, locale)]
-            [find_idx(&self.translation_keys, translation_key)]
+        self.localize_with_default(translation_key, locale, 0)
     }
 
-    /// Create a reference to the specified locale
+    /// Translates a given key like [`localize`](Self::localize), but falls back to the locale at index
+    /// `default_locale` instead of the first locale when `locale` isn't part of the table.
+    ///
+    /// This is what the `localization_table` macro uses to implement the `#[default_locale]` attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `locale` isn't part of the table and `default_locale` is out of bounds.
+    ///
     /// # Example
+    ///
     /// ```
-    /// # use localize::{localization_table, LocaleHandle};
+    /// # use localize::localization_table;
+    ///
+    /// # localization_table!{Spanglish = LDSL {
+    /// #    "greeting" = {
+    /// #        en => "Hello",
+    /// #        es => "Hola"
+    /// #    }
+    /// # }}
+    /// # let spanglish = Spanglish::TABLE;
+    ///
+    /// let greeting_de = spanglish.localize_with_default("greeting", "de", 1);
+    /// assert_eq!(greeting_de, "Hola");
+    ///
+    /// let greeting_en = spanglish.localize_with_default("greeting", "en", 1);
+    /// assert_eq!(greeting_en, "Hello");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn localize_with_default(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        default_locale: usize,
+    ) -> &'a str {
+        self.localize_indices_with_default(
+            find_or(&self.locales, locale, default_locale),
+            find_or(&self.translation_keys, translation_key, 0),
+            default_locale,
+        )
+    }
+
+    /// Translates a given key like [`localize_with_default`](Self::localize_with_default), but uses
+    /// `index` to find the translation key instead of scanning every key.
+    ///
+    /// `index` must have been built from this table's translation keys, like the `KEY_INDEX` constant
+    /// generated by the `localization_table` macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `locale` isn't part of the table and `default_locale` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// # localization_table!{Spanglish = LDSL {
+    /// #    "greeting" = {
+    /// #        en => "Hello",
+    /// #        es => "Hola"
+    /// #    }
+    /// # }}
+    /// let greeting_es = Spanglish::TABLE.localize_with_index(&Spanglish::KEY_INDEX, "greeting", "es", 0);
+    /// assert_eq!(greeting_es, "Hola");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn localize_with_index(
+        &self,
+        index: &KeyIndex<'_>,
+        translation_key: &str,
+        locale: &str,
+        default_locale: usize,
+    ) -> &'a str {
+        let key_idx = match index.get(&self.translation_keys, translation_key) {
+            Some(idx) => idx,
+            None => 0,
+        };
+        self.localize_indices_with_default(
+            find_or(&self.locales, locale, default_locale),
+            key_idx,
+            default_locale,
+        )
+    }
+
+    /// Translates the translation key made of `namespace`, [`NAMESPACE_SEPARATOR`], and `translation_key`,
+    /// like [`localize`](Self::localize), without allocating the joined key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Menu = LDSL {
+    ///    "menu.file.open" = {
+    ///        en => "Open",
+    ///        es => "Abrir"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Menu::TABLE.localize_ns("menu.file", "open", "es"), "Abrir");
+    /// assert_eq!(Menu::TABLE.localize_ns("menu", "file.open", "en"), "Open");
+    /// ```
+    #[must_use]
+    pub fn localize_ns(&self, namespace: &str, translation_key: &str, locale: &str) -> &'a str {
+        let key_idx = self
+            .translation_keys
+            .iter()
+            .position(|key| namespace::strip_segment(key, namespace) == Some(translation_key))
+            .unwrap_or(0);
+        self.localize_indices_with_default(find_or(&self.locales, locale, 0), key_idx, 0)
+    }
+
+    /// Translates a given key like [`localize`](Self::localize), with the locale given as anything that
+    /// implements [`AsLocale`], like a string or a [`LocaleHandle`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
     ///
     /// localization_table!{Spanglish = LDSL {
     ///    "greeting" = {
     ///        en => "Hello",
     ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// let spanish = Spanglish::get_locale("es");
+    /// assert_eq!(Spanglish::TABLE.localize_in("greeting", spanish), "Hola");
+    /// assert_eq!(Spanglish::TABLE.localize_in("greeting", String::from("en")), "Hello");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn localize_in(&self, translation_key: &str, locale: impl AsLocale) -> &'a str {
+        self.localize(translation_key, locale.as_locale())
+    }
+
+    /// Translates a given key like [`localize`](Self::localize), then substitutes `{name}` placeholders
+    /// with the values from `args` as described in [`interpolate`].
+    ///
+    /// This only allocates when a placeholder is actually substituted; otherwise the translation is
+    /// returned as [`Cow::Borrowed`]. To respect the aliases and default locale of a table generated by the
+    /// `localization_table` macro, use [`interpolate`] on the result of its `localize` function instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    /// use std::borrow::Cow;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello, {name}",
+    ///        es => "Hola, {name}"
     ///    },
     ///    "farewell" = {
     ///        en => "Goodbye",
@@ -150,92 +437,2024 @@ impl<'a, const LOCALES: usize, const KEYS: usize> LocalizationTable<'a, LOCALES,
     ///    }
     /// }}
     ///
-    /// let spanish = Spanglish::get_locale("es");
-    /// assert_eq!(spanish.localize("greeting"), "Hola");
+    /// let greeting = Spanglish::TABLE.localize_cow("greeting", "es", &[("name", "Ferris")]);
+    /// assert_eq!(greeting, "Hola, Ferris");
     ///
-    /// let english = Spanglish::get_locale("en");
-    /// assert_eq!(english.localize("greeting"), "Hello");
+    /// let farewell = Spanglish::TABLE.localize_cow("farewell", "es", &[("name", "Ferris")]);
+    /// assert!(matches!(farewell, Cow::Borrowed("Adiós")));
     /// ```
-    #[inline]
+    #[cfg(feature = "alloc")]
     #[must_use]
-    pub const fn get_locale(&'a self, locale: &str) -> LocaleHandle<'a, KEYS> {
-        let idx = find_idx(&self.locales, locale);
-        LocaleHandle {
-            locale: self.locales[idx],
-            translation_keys: &self.translation_keys,
-            translations: &self.translations[idx],
-        }
+    pub fn localize_cow(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        args: &[(&str, &str)],
+    ) -> Cow<'a, str> {
+        interpolate(self.localize(translation_key, locale), args)
     }
-}
 
-/// A reference to a specific row of a translation table.
-///
-/// # Example
-/// ```
-/// # use localize::{localization_table, LocaleHandle};
-///
-/// localization_table!{Spanglish = LDSL {
-///    "greeting" = {
-///        en => "Hello",
-///        es => "Hola"
-///    },
-///    "farewell" = {
-///        en => "Goodbye",
-///        es => "Adiós"
-///    }
-/// }}
-///
-/// let spanish: LocaleHandle<'static, 2> = Spanglish::get_locale("es");
-/// assert_eq!(spanish.localize("greeting"), "Hola");
-/// assert_eq!(format!("{spanish}"), "es");
-///
-/// let english: LocaleHandle<'static, 2> = Spanglish::get_locale("en");
-/// assert_eq!(english.localize("greeting"), "Hello");
-/// assert_eq!(format!("{english}"), "en");
-/// ```
-#[derive(Clone, Copy)]
-pub struct LocaleHandle<'a, const KEYS: usize> {
-    locale: &'a str,
-    translation_keys: &'a [&'a str; KEYS],
-    translations: &'a [&'a str; KEYS],
-}
+    /// Translates a given key like [`localize`](Self::localize), then substitutes positional `{0}`, `{1}`,
+    /// ... placeholders with the elements of `args` as described in [`interpolate_positional`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "sent" = {
+    ///        en => "{0} sent {1} files",
+    ///        es => "{0} envió {1} archivos"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(
+    ///     Spanglish::TABLE.localize_positional("sent", "es", &["Ferris", "3"]),
+    ///     "Ferris envió 3 archivos"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn localize_positional(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        args: &[&str],
+    ) -> String {
+        interpolate_positional(self.localize(translation_key, locale), args).into_owned()
+    }
 
-impl<'a, const KEYS: usize> Display for LocaleHandle<'a, KEYS> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.locale)
+    /// Translates a given key like [`localize`](Self::localize), but returns an owned copy of the
+    /// translation that doesn't borrow from the table.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn localize_string(&self, translation_key: &str, locale: &str) -> String {
+        String::from(self.localize(translation_key, locale))
     }
-}
 
-impl<'a, const KEYS: usize> LocaleHandle<'a, KEYS> {
-    /// Get the translated string for the given translation key in this locale
-    #[inline]
+    /// Translates a given key like [`localize`](Self::localize), but returns a null-terminated copy
+    /// of the translation to pass over a C FFI boundary.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NulError`] if the translation contains a null byte.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// let greeting = Spanglish::TABLE.localize_cstring("greeting", "es").unwrap();
+    /// assert_eq!(greeting.as_bytes_with_nul(), b"Hola\0");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn localize_cstring(
+        &self,
+        translation_key: &str,
+        locale: &str,
+    ) -> Result<CString, NulError> {
+        CString::new(self.localize(translation_key, locale))
+    }
+
+    /// Translates a given key like [`localize`](Self::localize), but picks a sibling key depending on
+    /// `count`: `"<key>.zero"` when it's `0`, `"<key>.one"` when it's `1`, and `"<key>.many"` otherwise.
+    /// If the table doesn't have that sibling, `translation_key` itself is used.
+    ///
+    /// These three buckets are enough for languages like English and Spanish without any plural rules. For
+    /// the CLDR plural rules of other languages, use [`format_message`](Self::format_message) instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Inbox = LDSL {
+    ///    "messages" = {
+    ///        en => "You have messages",
+    ///        es => "Tienes mensajes"
+    ///    },
+    ///    "messages.zero" = {
+    ///        en => "You have no messages",
+    ///        es => "No tienes mensajes"
+    ///    },
+    ///    "messages.one" = {
+    ///        en => "You have a message",
+    ///        es => "Tienes un mensaje"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Inbox::TABLE.localize_count("messages", "es", 0), "No tienes mensajes");
+    /// assert_eq!(Inbox::TABLE.localize_count("messages", "en", 1), "You have a message");
+    /// // there's no `messages.many`, so this falls back to `messages`
+    /// assert_eq!(Inbox::TABLE.localize_count("messages", "en", 5), "You have messages");
+    /// ```
     #[must_use]
-    pub const fn localize(&self, translation_key: &str) -> &'a str {
-        self.translations[find_idx(self.translation_keys, translation_key)]
+    pub fn localize_count(&self, translation_key: &str, locale: &str, count: u64) -> &'a str {
+        let key_idx = count::find_bucket(&self.translation_keys, translation_key, count)
+            .unwrap_or_else(|| find_or(&self.translation_keys, translation_key, 0));
+        self.localize_indices_with_default(find_or(&self.locales, locale, 0), key_idx, 0)
     }
-}
 
-#[inline]
-const fn strcmp(a: &str, b: &str) -> bool {
-    a.len() == b.len() && {
-        let mut i = 0;
-        while i < a.len() {
-            if a.as_bytes()[i] != b.as_bytes()[i] {
-                return false;
-            }
-            i += 1;
-        }
-        true
+    /// Translates a given key like [`localize`](Self::localize) and writes the translation to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    /// use std::fmt::Write;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// let mut output = String::from("¡");
+    /// Spanglish::TABLE.localize_into(&mut output, "greeting", "es").unwrap();
+    /// output.push('!');
+    /// assert_eq!(output, "¡Hola!");
+    /// ```
+    #[inline]
+    pub fn localize_into<W: fmt::Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        translation_key: &str,
+        locale: &str,
+    ) -> fmt::Result {
+        writer.write_str(self.localize(translation_key, locale))
     }
-}
 
-const fn find_idx(arr: &[&str], s: &str) -> usize {
-    let mut i = 0;
-    while i < arr.len() {
-        if strcmp(arr[i], s) {
-            return i;
-        }
-        i += 1;
+    /// Translates a given key like [`localize`](Self::localize) and writes the translation to `writer`,
+    /// substituting `{name}` placeholders with the values from `args` as described in [`interpolate_into`].
+    ///
+    /// Unlike [`localize_cow`](Self::localize_cow), this never builds an intermediate `String`, so it
+    /// doesn't need the `alloc` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    /// use std::fmt::{self, Display};
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello, {name}",
+    ///        es => "Hola, {name}"
+    ///    }
+    /// }}
+    ///
+    /// struct Greeting<'a> {
+    ///     name: &'a str,
+    ///     locale: &'a str,
+    /// }
+    ///
+    /// impl Display for Greeting<'_> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         Spanglish::TABLE.localize_args_into(f, "greeting", self.locale, &[("name", self.name)])
+    ///     }
+    /// }
+    ///
+    /// let greeting = Greeting { name: "Ferris", locale: "es" };
+    /// assert_eq!(greeting.to_string(), "Hola, Ferris");
+    /// ```
+    pub fn localize_args_into<W: fmt::Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        translation_key: &str,
+        locale: &str,
+        args: &[(&str, &str)],
+    ) -> fmt::Result {
+        interpolate_into(writer, self.localize(translation_key, locale), args)
+    }
+
+    /// Translates a given key like [`localize`](Self::localize) into a [`LocalizedMessage`], which
+    /// substitutes `{name}` placeholders with the values from `args` as described in [`interpolate_into`]
+    /// when it's displayed.
+    ///
+    /// Like [`localize_args_into`](Self::localize_args_into), this never builds an intermediate
+    /// `String`, but the message can be passed around and formatted with `{}` anywhere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Inbox = LDSL {
+    ///    "unread" = {
+    ///        en => "You have {n} unread messages",
+    ///        es => "Tienes {n} mensajes sin leer"
+    ///    }
+    /// }}
+    ///
+    /// let message = Inbox::TABLE.message("unread", "es", &[("n", "3")]);
+    /// assert_eq!(message.to_string(), "Tienes 3 mensajes sin leer");
+    /// println!("{message}");
+    /// ```
+    #[must_use]
+    pub const fn message<'m>(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        args: &'m [(&'m str, &'m str)],
+    ) -> LocalizedMessage<'m>
+    where
+        'a: 'm,
+    {
+        LocalizedMessage::new(self.localize(translation_key, locale), args)
+    }
+
+    /// Translates a given key and formats the translation as an ICU `MessageFormat` pattern.
+    ///
+    /// The plural rules of `locale` are used to pick `plural` cases. See [`format_message`] for the
+    /// supported syntax; malformed translations are returned unformatted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{localization_table, MessageArg};
+    ///
+    /// localization_table!{Inbox = LDSL {
+    ///    "messages" = {
+    ///        en => "{count, plural, =0 {No messages} one {# message} other {# messages}}",
+    ///        fr => "{count, plural, one {# message} other {# messages}}"
+    ///    }
+    /// }}
+    ///
+    /// let messages = |locale, count| Inbox::TABLE.format_message("messages", locale, &[("count", MessageArg::Number(count))]);
+    /// assert_eq!(messages("en", 0), "No messages");
+    /// assert_eq!(messages("en", 1), "1 message");
+    /// assert_eq!(messages("fr", 0), "0 message");
+    /// assert_eq!(messages("fr", 2), "2 messages");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn format_message(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        args: &[(&str, MessageArg<'_>)],
+    ) -> String {
+        format_message(self.localize(translation_key, locale), locale, args)
     }
-    0
+
+    /// Translates a given key like [`localize`](Self::localize), but returns an error instead of falling
+    /// back when the locale or the translation key isn't part of the table.
+    ///
+    /// Cells that are filled in by the `"_"` key or [`PLACEHOLDER`] are still returned successfully.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LocalizeError::UnknownLocale`] if the locale isn't part of the table, or else
+    /// [`LocalizeError::UnknownKey`] if the translation key isn't.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{localization_table, LocalizeError};
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Spanglish::TABLE.localize_result("greeting", "es"), Ok("Hola"));
+    /// assert_eq!(
+    ///     Spanglish::TABLE.localize_result("greeting", "de"),
+    ///     Err(LocalizeError::UnknownLocale("de".to_owned()))
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn localize_result(
+        &self,
+        translation_key: &str,
+        locale: &str,
+    ) -> Result<&'a str, LocalizeError> {
+        let locale_idx = self
+            .locale_index(locale)
+            .ok_or_else(|| LocalizeError::UnknownLocale(locale.into()))?;
+        let key_idx = self
+            .key_index(translation_key)
+            .ok_or_else(|| LocalizeError::UnknownKey(translation_key.into()))?;
+        Ok(self.localize_indices(locale_idx, key_idx))
+    }
+
+    /// Export the table as a JSON object that maps every translation key to an object of its
+    /// translations by locale, like `{"greeting":{"en":"Hello","es":"Hola"}}`.
+    ///
+    /// Keys and locales are written in the same (sorted) order as the table's arrays, so the output is
+    /// deterministic. Every cell is included, so missing translations appear as their `"_"` default or
+    /// [`PLACEHOLDER`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///        es => "Adiós"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(
+    ///     Spanglish::TABLE.to_json(),
+    ///     r#"{"farewell":{"en":"Goodbye","es":"Adiós"},"greeting":{"en":"Hello","es":"Hola"}}"#
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut output = String::from("{");
+        for (key_idx, translation_key) in self.translation_keys.iter().enumerate() {
+            if key_idx > 0 {
+                output.push(',');
+            }
+            json::push_string(&mut output, translation_key);
+            output.push_str(":{");
+            for (locale_idx, locale) in self.locales.iter().enumerate() {
+                if locale_idx > 0 {
+                    output.push(',');
+                }
+                json::push_string(&mut output, locale);
+                output.push(':');
+                json::push_string(&mut output, self.translations[locale_idx][key_idx]);
+            }
+            output.push('}');
+        }
+        output.push('}');
+        output
+    }
+
+    /// Collect every cell of the table into a map from `(locale, translation_key)` to its translation, for
+    /// code that's moving over from a hand-rolled map of translations.
+    ///
+    /// Cells filled in by the `"_"` key are included like any other cell, but cells that are
+    /// [`PLACEHOLDER`] are left out, so looking up a missing translation in the map returns `None`
+    /// instead of falling back to the default locale like [`localize`](Self::localize).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///    }
+    /// }}
+    ///
+    /// let map = Spanglish::TABLE.to_map();
+    /// assert_eq!(map[&("es", "greeting")], "Hola");
+    /// assert_eq!(map.get(&("es", "farewell")), None);
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_map(&self) -> HashMap<(&'a str, &'a str), &'a str> {
+        self.locales
+            .iter()
+            .zip(&self.translations)
+            .flat_map(|(locale, row)| {
+                self.translation_keys
+                    .iter()
+                    .zip(row)
+                    .filter(|(_, cell)| **cell != PLACEHOLDER)
+                    .map(|(translation_key, cell)| ((*locale, *translation_key), *cell))
+            })
+            .collect()
+    }
+
+    /// Translates a given key like [`localize`](Self::localize), but calls the handler registered with
+    /// [`set_miss_handler`] with the translation key and locale when the lookup misses.
+    ///
+    /// A lookup misses when the locale or the translation key isn't part of the table, or when the cell
+    /// is [`PLACEHOLDER`] because it has neither a translation nor a `"_"` default, even if the default
+    /// locale has a translation to fall back to. Cells filled in by the `"_"` key aren't reported.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn localize_logged(&self, translation_key: &str, locale: &str) -> &'a str {
+        if self
+            .try_localize(translation_key, locale)
+            .is_none_or(|cell| cell == PLACEHOLDER)
+        {
+            miss::report_miss(translation_key, locale);
+        }
+        self.localize(translation_key, locale)
+    }
+
+    /// Translates a given key without any fallback, returning `None` if the locale or the translation key
+    /// isn't part of the table.
+    ///
+    /// Cells that are filled in by the `"_"` key or [`PLACEHOLDER`] are still returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Spanglish::TABLE.try_localize("greeting", "es"), Some("Hola"));
+    /// assert_eq!(Spanglish::TABLE.try_localize("farewell", "es"), None);
+    /// assert_eq!(Spanglish::TABLE.try_localize("greeting", "de"), None);
+    /// ```
+    #[must_use]
+    pub const fn try_localize(&self, translation_key: &str, locale: &str) -> Option<&'a str> {
+        match (self.locale_index(locale), self.key_index(translation_key)) {
+            (Some(locale_idx), Some(key_idx)) => Some(self.translations[locale_idx][key_idx]),
+            _ => None,
+        }
+    }
+
+    /// Translates a given key like [`try_localize`](Self::try_localize), but returns `fallback` if the
+    /// locale or the translation key isn't part of the table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Spanglish::TABLE.localize_or("greeting", "es", "Hi"), "Hola");
+    /// assert_eq!(Spanglish::TABLE.localize_or("farewell", "es", "Bye"), "Bye");
+    /// ```
+    #[must_use]
+    pub const fn localize_or(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        fallback: &'a str,
+    ) -> &'a str {
+        match self.try_localize(translation_key, locale) {
+            Some(translation) => translation,
+            None => fallback,
+        }
+    }
+
+    /// Whether the table doesn't have any locales or any translation keys, like a table generated from an
+    /// empty `localization_table!{Empty = LDSL {}}`.
+    ///
+    /// Lookups in an empty table don't have a cell to return, so they return [`PLACEHOLDER`] instead of
+    /// panicking, and [`get_locale`](Self::get_locale) returns a handle for the locale `""` that does the
+    /// same.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        LOCALES == 0 || KEYS == 0
+    }
+
+    /// Get the translation keys of the table, which are always in strictly ascending (byte-wise) order.
+    #[inline]
+    #[must_use]
+    pub const fn keys(&self) -> &[&'a str] {
+        &self.translation_keys
+    }
+
+    /// Get the locales of the table in the order of its `locales`. This is strictly ascending (byte-wise)
+    /// order, except for tables generated with `#[preserve_order]`, whose locales are in the order they
+    /// were written; use [`sorted_locales`](Self::sorted_locales) for a sorted copy of either.
+    #[inline]
+    #[must_use]
+    pub const fn locales(&self) -> &[&'a str] {
+        &self.locales
+    }
+
+    /// Get a copy of the translation keys in strictly ascending (byte-wise) order. This is the same as
+    /// [`keys`](Self::keys), and is provided to compare with [`sorted_locales`](Self::sorted_locales).
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn sorted_keys(&self) -> Vec<&'a str> {
+        let mut keys = self.translation_keys.to_vec();
+        keys.sort_unstable();
+        keys
+    }
+
+    /// Get a copy of the locales in strictly ascending (byte-wise) order, even for a table generated with
+    /// `#[preserve_order]`, for tools that need a stable order, like diffing two tables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Curated = LDSL #[preserve_order] {
+    ///    "greeting" = {
+    ///        es => "Hola",
+    ///        en => "Hello"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Curated::TABLE.locales(), ["es", "en"]);
+    /// assert_eq!(Curated::TABLE.sorted_locales(), ["en", "es"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn sorted_locales(&self) -> Vec<&'a str> {
+        let mut locales = self.locales.to_vec();
+        locales.sort_unstable();
+        locales
+    }
+
+    /// List what changed from this table to `other`, like for reviewing an update to the translations.
+    ///
+    /// The changes are listed in this order: removed translation keys, added translation keys, removed
+    /// locales, and added locales, each in the order of the table they're part of, then the changed
+    /// translations of every key and locale that both tables have, in the order of this table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{localization_table, TableChange};
+    ///
+    /// localization_table!{Old = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// localization_table!{New = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hi",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///        es => "Adiós"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(
+    ///     Old::TABLE.diff(&New::TABLE),
+    ///     [
+    ///         TableChange::AddedKey("farewell"),
+    ///         TableChange::ChangedCell {
+    ///             locale: "en",
+    ///             translation_key: "greeting",
+    ///             old: "Hello",
+    ///             new: "Hi"
+    ///         }
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn diff<const OTHER_LOCALES: usize, const OTHER_KEYS: usize>(
+        &self,
+        other: &LocalizationTable<'a, OTHER_LOCALES, OTHER_KEYS>,
+    ) -> Vec<TableChange<'a>> {
+        let mut changes: Vec<TableChange<'a>> = self
+            .translation_keys
+            .iter()
+            .filter(|key| other.key_index(key).is_none())
+            .map(|key| TableChange::RemovedKey(key))
+            .collect();
+        changes.extend(
+            other
+                .translation_keys
+                .iter()
+                .filter(|key| self.key_index(key).is_none())
+                .map(|key| TableChange::AddedKey(key)),
+        );
+        changes.extend(
+            self.locales
+                .iter()
+                .filter(|locale| other.locale_index(locale).is_none())
+                .map(|locale| TableChange::RemovedLocale(locale)),
+        );
+        changes.extend(
+            other
+                .locales
+                .iter()
+                .filter(|locale| self.locale_index(locale).is_none())
+                .map(|locale| TableChange::AddedLocale(locale)),
+        );
+        for (locale, row) in self.locales.iter().zip(&self.translations) {
+            let Some(other_locale) = other.locale_index(locale) else {
+                continue;
+            };
+            for (translation_key, old) in self.translation_keys.iter().zip(row) {
+                let Some(other_key) = other.key_index(translation_key) else {
+                    continue;
+                };
+                let new = other.translations[other_locale][other_key];
+                if *old != new {
+                    changes.push(TableChange::ChangedCell {
+                        locale,
+                        translation_key,
+                        old,
+                        new,
+                    });
+                }
+            }
+        }
+        changes
+    }
+
+    /// Find the index of a translation key, for use with [`localize_indices`](Self::localize_indices).
+    ///
+    /// Returns `None` if the key isn't part of the table. Otherwise, the index is in `0..KEYS`.
+    #[inline]
+    #[must_use]
+    pub const fn key_index(&self, translation_key: &str) -> Option<usize> {
+        find(&self.translation_keys, translation_key)
+    }
+
+    /// Find the index of a locale, for use with [`localize_indices`](Self::localize_indices).
+    ///
+    /// Returns `None` if the locale isn't part of the table. Otherwise, the index is in `0..LOCALES`.
+    #[inline]
+    #[must_use]
+    pub const fn locale_index(&self, locale: &str) -> Option<usize> {
+        find(&self.locales, locale)
+    }
+
+    /// Whether `locale` is one of the table's locales. Since this is a `const fn`, it can check a locale
+    /// at compile time, which the [`locale!`] macro does for locale literals:
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// const _: () = assert!(Spanglish::TABLE.contains_locale("en"));
+    /// assert!(!Spanglish::TABLE.contains_locale("de"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn contains_locale(&self, locale: &str) -> bool {
+        self.locale_index(locale).is_some()
+    }
+
+    /// Get the translation at the given locale and key indices without searching the table.
+    ///
+    /// Resolving the indices once with [`locale_index`](Self::locale_index) and [`key_index`](Self::key_index)
+    /// avoids repeating the string comparisons when the same locale or key is used for many lookups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `locale_idx` isn't in `0..LOCALES` or `key_idx` isn't in `0..KEYS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// # localization_table!{Spanglish = LDSL {
+    /// #    "greeting" = {
+    /// #        en => "Hello",
+    /// #        es => "Hola"
+    /// #    },
+    /// #    "farewell" = {
+    /// #        en => "Goodbye",
+    /// #        es => "Adiós"
+    /// #    }
+    /// # }}
+    /// # let spanglish = Spanglish::TABLE;
+    ///
+    /// let greeting = spanglish.key_index("greeting").unwrap();
+    /// let translations: Vec<&str> = (0..spanglish.locales.len())
+    ///     .map(|locale| spanglish.localize_indices(locale, greeting))
+    ///     .collect();
+    /// assert_eq!(translations, ["Hello", "Hola"]);
+    ///
+    /// assert_eq!(spanglish.key_index("welcome"), None);
+    /// assert_eq!(spanglish.locale_index("es"), Some(1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn localize_indices(&self, locale_idx: usize, key_idx: usize) -> &'a str {
+        self.translations[locale_idx][key_idx]
+    }
+
+    /// Get the translation at the given locale and key indices like
+    /// [`localize_indices`](Self::localize_indices), but use the translation of the locale at index
+    /// `default_locale` when the cell is [`PLACEHOLDER`].
+    ///
+    /// Since cells without a translation are already filled in by the `"_"` key of their locale, this
+    /// tries the translation, the `"_"` default of the locale, the translation of the default locale, and
+    /// the `"_"` default of the default locale, in that order. This is the fallback that
+    /// [`localize`](Self::localize) uses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `locale_idx` isn't in `0..LOCALES` or `key_idx` isn't in `0..KEYS`, unless the table
+    /// [is empty](Self::is_empty), which returns [`PLACEHOLDER`]. An out of bounds `default_locale` is
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{localization_table, PLACEHOLDER};
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///    }
+    /// }}
+    ///
+    /// let farewell = Spanglish::TABLE.key_index("farewell").unwrap();
+    /// assert_eq!(Spanglish::TABLE.localize_indices(1, farewell), PLACEHOLDER);
+    /// assert_eq!(Spanglish::TABLE.localize_indices_with_default(1, farewell, 0), "Goodbye");
+    /// ```
+    #[must_use]
+    pub const fn localize_indices_with_default(
+        &self,
+        locale_idx: usize,
+        key_idx: usize,
+        default_locale: usize,
+    ) -> &'a str {
+        if self.is_empty() {
+            return PLACEHOLDER;
+        }
+        let translation = self.translations[locale_idx][key_idx];
+        if bytes_eq(translation, PLACEHOLDER) && default_locale < LOCALES {
+            self.translations[default_locale][key_idx]
+        } else {
+            translation
+        }
+    }
+
+    /// Get the translations of every key in a locale, in the same order as
+    /// [`translation_keys`](Self::translation_keys), or `None` if the locale isn't part of the table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///        es => "Adiós"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Spanglish::TABLE.row("es"), Some(&["Adiós", "Hola"]));
+    /// assert_eq!(Spanglish::TABLE.row("de"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn row(&self, locale: &str) -> Option<&[&'a str; KEYS]> {
+        match self.locale_index(locale) {
+            Some(locale_idx) => Some(&self.translations[locale_idx]),
+            None => None,
+        }
+    }
+
+    /// Get the translations of a key in every locale, in the same order as [`locales`](Self::locales), or
+    /// `None` if the key isn't part of the table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Spanglish::TABLE.column("greeting"), Some(["Hello", "Hola"]));
+    /// assert_eq!(Spanglish::TABLE.column("farewell"), None);
+    /// ```
+    #[must_use]
+    pub const fn column(&self, translation_key: &str) -> Option<[&'a str; LOCALES]> {
+        let Some(key_idx) = self.key_index(translation_key) else {
+            return None;
+        };
+        let mut column = [""; LOCALES];
+        let mut locale_idx = 0;
+        while locale_idx < LOCALES {
+            column[locale_idx] = self.translations[locale_idx][key_idx];
+            locale_idx += 1;
+        }
+        Some(column)
+    }
+
+    /// Check that no cell of the table is [`PLACEHOLDER`], meaning that every key has a translation (or a
+    /// `"_"` default) in every locale.
+    ///
+    /// The `#[complete]` attribute of the `localization_table` macro uses this to fail the build for
+    /// incomplete tables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye"
+    ///    }
+    /// }}
+    ///
+    /// assert!(!Spanglish::TABLE.is_complete());
+    /// ```
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        let mut locale_idx = 0;
+        while locale_idx < LOCALES {
+            let mut key_idx = 0;
+            while key_idx < KEYS {
+                if strcmp(self.translations[locale_idx][key_idx], PLACEHOLDER) {
+                    return false;
+                }
+                key_idx += 1;
+            }
+            locale_idx += 1;
+        }
+        true
+    }
+
+    /// Get the total length in bytes of every cell of the table, counting cells with the same translation
+    /// separately, for statistics or constant assertions on the size of the translations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// const _: () = assert!(Spanglish::TABLE.total_chars() < 1024);
+    /// assert_eq!(Spanglish::TABLE.total_chars(), 9);
+    /// ```
+    #[must_use]
+    pub const fn total_chars(&self) -> usize {
+        let mut total = 0;
+        let mut locale_idx = 0;
+        while locale_idx < LOCALES {
+            let mut key_idx = 0;
+            while key_idx < KEYS {
+                total += self.translations[locale_idx][key_idx].len();
+                key_idx += 1;
+            }
+            locale_idx += 1;
+        }
+        total
+    }
+
+    /// Get the number of different locales of the table. This is `LOCALES` for any table generated by the
+    /// macro, but a table built by hand can repeat a locale.
+    #[must_use]
+    pub const fn distinct_locales(&self) -> usize {
+        let mut count = 0;
+        let mut idx = 0;
+        while idx < LOCALES {
+            let mut earlier = 0;
+            while earlier < idx && !bytes_eq(self.locales[earlier], self.locales[idx]) {
+                earlier += 1;
+            }
+            if earlier == idx {
+                count += 1;
+            }
+            idx += 1;
+        }
+        count
+    }
+
+    /// Check that `translation_keys` and `locales` are in strictly ascending order, like the macro
+    /// generates them. This does nothing unless debug assertions are enabled.
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, panics if a translation key or locale isn't greater than the one
+    /// before it, meaning that the array is out of order or has duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::LocalizationTable;
+    ///
+    /// let table = LocalizationTable {
+    ///     translation_keys: ["farewell", "greeting"],
+    ///     locales: ["en", "es"],
+    ///     translations: [["Goodbye", "Hello"], ["Adiós", "Hola"]],
+    /// };
+    /// table.debug_assert_sorted();
+    /// ```
+    pub const fn debug_assert_sorted(&self) {
+        if cfg!(debug_assertions) {
+            assert!(
+                is_strictly_ascending(&self.translation_keys),
+                "the translation keys of a `LocalizationTable` must be sorted without duplicates"
+            );
+            assert!(
+                is_strictly_ascending(&self.locales),
+                "the locales of a `LocalizationTable` must be sorted without duplicates"
+            );
+        }
+    }
+
+    /// Check the whole table: that `translation_keys` and `locales` are in strictly ascending order like
+    /// the macro generates them, and that the table [is complete](Self::is_complete).
+    ///
+    /// Since this is a `const fn`, a constant assertion can check any table at compile time, including
+    /// one built by hand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{LocalizationTable, PLACEHOLDER};
+    ///
+    /// const TABLE: LocalizationTable<2, 2> = LocalizationTable {
+    ///     translation_keys: ["farewell", "greeting"],
+    ///     locales: ["en", "es"],
+    ///     translations: [["Goodbye", "Hello"], ["Adiós", "Hola"]],
+    /// };
+    /// const _: () = assert!(TABLE.validate());
+    ///
+    /// let incomplete = LocalizationTable {
+    ///     translation_keys: ["greeting"],
+    ///     locales: ["en", "es"],
+    ///     translations: [["Hello"], [PLACEHOLDER]],
+    /// };
+    /// assert!(!incomplete.validate());
+    /// ```
+    #[must_use]
+    pub const fn validate(&self) -> bool {
+        is_strictly_ascending(&self.translation_keys)
+            && is_strictly_ascending(&self.locales)
+            && self.is_complete()
+    }
+
+    /// Create a table from its parts, checking that `translation_keys` and `locales` are in strictly
+    /// ascending order like the macro generates them, for tables built by hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first translation key or locale that isn't greater than the one before it, as a
+    /// [`TableError`] that says whether it's a duplicate or out of order. The locales of a table
+    /// generated with `#[preserve_order]` aren't sorted, so it can't be rebuilt with this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{LocalizationTable, TableError};
+    ///
+    /// let table = LocalizationTable::new_checked(
+    ///     ["farewell", "greeting"],
+    ///     ["en", "es"],
+    ///     [["Goodbye", "Hello"], ["Adiós", "Hola"]],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(table.localize("greeting", "es"), "Hola");
+    ///
+    /// let unsorted = LocalizationTable::new_checked(["greeting"], ["es", "en"], [["Hola"], ["Hello"]]);
+    /// assert_eq!(unsorted, Err(TableError::UnsortedLocale("en")));
+    /// ```
+    pub const fn new_checked(
+        translation_keys: [&'a str; KEYS],
+        locales: [&'a str; LOCALES],
+        translations: [[&'a str; KEYS]; LOCALES],
+    ) -> Result<Self, TableError<'a>> {
+        if let Some(idx) = first_out_of_order(&translation_keys) {
+            let key = translation_keys[idx];
+            return Err(if bytes_eq(translation_keys[idx - 1], key) {
+                TableError::DuplicateKey(key)
+            } else {
+                TableError::UnsortedKey(key)
+            });
+        }
+        if let Some(idx) = first_out_of_order(&locales) {
+            let locale = locales[idx];
+            return Err(if bytes_eq(locales[idx - 1], locale) {
+                TableError::DuplicateLocale(locale)
+            } else {
+                TableError::UnsortedLocale(locale)
+            });
+        }
+        Ok(Self {
+            translation_keys,
+            locales,
+            translations,
+        })
+    }
+
+    /// Iterate over every `(locale, translation_key, translation)` in the table.
+    ///
+    /// Locales are the outer loop and translation keys the inner one, matching the layout of
+    /// `translations`, so there are `LOCALES * KEYS` items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// let all: Vec<_> = Spanglish::TABLE.iter_all().collect();
+    /// assert_eq!(all, [("en", "greeting", "Hello"), ("es", "greeting", "Hola")]);
+    /// ```
+    pub fn iter_all(&self) -> impl Iterator<Item = (&'a str, &'a str, &'a str)> + '_ {
+        self.locales
+            .iter()
+            .zip(&self.translations)
+            .flat_map(|(&locale, row)| {
+                self.translation_keys
+                    .iter()
+                    .zip(row)
+                    .map(move |(&key, &translation)| (locale, key, translation))
+            })
+    }
+
+    /// Create a reference to the specified locale
+    /// # Example
+    /// ```
+    /// # use localize::{localization_table, LocaleHandle};
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///        es => "Adiós"
+    ///    }
+    /// }}
+    ///
+    /// let spanish = Spanglish::get_locale("es");
+    /// assert_eq!(spanish.localize("greeting"), "Hola");
+    ///
+    /// let english = Spanglish::get_locale("en");
+    /// assert_eq!(english.localize("greeting"), "Hello");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn get_locale(&'a self, locale: &str) -> LocaleHandle<'a, KEYS> {
+        self.get_locale_with_default(locale, 0)
+    }
+
+    /// Find the translation key that is closest to `translation_key` by edit distance, for error messages
+    /// like "unknown key `greting`, did you mean `greeting`?".
+    ///
+    /// Keys are compared by Levenshtein distance, counting single-character insertions, deletions, and
+    /// substitutions. Only keys within a third of the length of `translation_key` (and at least one
+    /// edit) are suggested, so `None` is returned for input that isn't close to any key. On a tie, the
+    /// first key in the table's order wins. The `"_"` key is never suggested. A key that is part of the
+    /// table is its own suggestion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///        es => "Adiós"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Spanglish::TABLE.suggest_key("greting"), Some("greeting"));
+    /// assert_eq!(Spanglish::TABLE.suggest_key("farwel"), Some("farewell"));
+    /// assert_eq!(Spanglish::TABLE.suggest_key("submit"), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn suggest_key(&self, translation_key: &str) -> Option<&'a str> {
+        suggest::closest_key(&self.translation_keys, translation_key)
+    }
+
+    /// Find the locale of the table that best matches a list of preferred locales, most preferred first,
+    /// or `None` if none of them match.
+    ///
+    /// Each preference is tried in turn: first exactly, then with its last subtag removed until only the
+    /// language is left, so `zh-Hant-TW` also tries `zh-Hant` and `zh`. Subtags can be separated by `-` or
+    /// `_`. A later preference is only tried if the earlier ones don't match at all, so `["fr-CA", "en"]`
+    /// picks `fr` over `en`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola",
+    ///        es-MX => "Qué onda"
+    ///    }
+    /// }}
+    ///
+    /// assert_eq!(Spanglish::TABLE.negotiate(&["es-MX", "en"]), Some("es-MX"));
+    /// assert_eq!(Spanglish::TABLE.negotiate(&["es-AR", "en"]), Some("es"));
+    /// assert_eq!(Spanglish::TABLE.negotiate(&["de-DE", "en-GB"]), Some("en"));
+    /// assert_eq!(Spanglish::TABLE.negotiate(&["de", "fr"]), None);
+    /// ```
+    #[must_use]
+    pub fn negotiate(&self, preferred: &[&str]) -> Option<&'a str> {
+        preferred.iter().find_map(|&locale| {
+            let mut candidate = locale;
+            loop {
+                if let Some(idx) = self.locale_index(candidate) {
+                    return Some(self.locales[idx]);
+                }
+                candidate = &candidate[..candidate.rfind(['-', '_'])?];
+            }
+        })
+    }
+
+    /// Translates a given key like [`localize`](Self::localize), and also reports where the translation
+    /// came from, for debugging unexpected fallbacks or setting the `lang` of the text.
+    ///
+    /// Unlike [`localize`](Self::localize), a locale that isn't part of the table first falls back to its
+    /// base language, like [`negotiate`](Self::negotiate), so `es-MX` is translated like `es` in a table
+    /// with `es` and reported as [`Resolution::BaseLanguage`]. Otherwise the lookup falls back like
+    /// [`localize`](Self::localize). A key that isn't part of the table is reported as
+    /// [`Resolution::DefaultKey`], since it's translated like the first key. A hand-built table doesn't
+    /// record which cells were filled in from a default key, so those are reported like any other
+    /// translation; tables generated by the `localization_table` macro have a `localize_resolved`
+    /// function that does, and that also resolves aliases and falls back to the `#[default_locale]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{localization_table, Resolution, PLACEHOLDER};
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye"
+    ///    }
+    /// }}
+    ///
+    /// let table = Spanglish::TABLE;
+    /// assert_eq!(table.localize_resolved("greeting", "es"), ("Hola", Resolution::Exact("es")));
+    /// assert_eq!(table.localize_resolved("greeting", "es-MX"), ("Hola", Resolution::BaseLanguage("es")));
+    /// assert_eq!(table.localize_resolved("greeting", "de"), ("Hello", Resolution::Default("en")));
+    /// assert_eq!(table.localize_resolved("farewell", "es"), ("Goodbye", Resolution::Default("en")));
+    /// assert_eq!(table.localize_resolved("welcome", "es"), ("Goodbye", Resolution::DefaultKey));
+    /// ```
+    #[must_use]
+    pub fn localize_resolved(
+        &self,
+        translation_key: &str,
+        locale: &str,
+    ) -> (&'a str, Resolution<'a>) {
+        self.localize_resolved_with_default(translation_key, locale, 0)
+    }
+
+    /// Translates a given key like [`localize_resolved`](Self::localize_resolved), but falls back to the
+    /// locale at index `default_locale` instead of the first locale, like
+    /// [`localize_with_default`](Self::localize_with_default). An out of bounds `default_locale` is
+    /// ignored.
+    #[must_use]
+    pub fn localize_resolved_with_default(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        default_locale: usize,
+    ) -> (&'a str, Resolution<'a>) {
+        self.resolve(translation_key, locale, default_locale, None)
+    }
+
+    /// Translates a given key like
+    /// [`localize_resolved_with_default`](Self::localize_resolved_with_default), and reports the cells
+    /// that `explicit[locale][key]` marks as filled in from a default key as
+    /// [`Resolution::DefaultKey`]. This is what the `localize_resolved` function generated by the
+    /// `localization_table` macro uses, with its `EXPLICIT` constant.
+    #[must_use]
+    pub fn localize_resolved_with_explicit(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        default_locale: usize,
+        explicit: &[[bool; KEYS]; LOCALES],
+    ) -> (&'a str, Resolution<'a>) {
+        self.resolve(translation_key, locale, default_locale, Some(explicit))
+    }
+
+    fn resolve(
+        &self,
+        translation_key: &str,
+        locale: &str,
+        default_locale: usize,
+        explicit: Option<&[[bool; KEYS]; LOCALES]>,
+    ) -> (&'a str, Resolution<'a>) {
+        if self.is_empty() {
+            return (PLACEHOLDER, Resolution::Missing);
+        }
+        let default_locale = if default_locale < LOCALES {
+            default_locale
+        } else {
+            0
+        };
+        let default = (
+            default_locale,
+            Resolution::Default(self.locales[default_locale]),
+        );
+        let requested = self.locale_index(locale).map_or_else(
+            || {
+                self.base_language_index(locale).map_or(default, |idx| {
+                    (idx, Resolution::BaseLanguage(self.locales[idx]))
+                })
+            },
+            |idx| (idx, Resolution::Exact(self.locales[idx])),
+        );
+        let key_idx = self.key_index(translation_key);
+        for (locale_idx, resolution) in [requested, default] {
+            let translation = self.translations[locale_idx][key_idx.unwrap_or(0)];
+            if translation == PLACEHOLDER {
+                continue;
+            }
+            let from_default_key = key_idx.is_none_or(|key_idx| {
+                explicit.is_some_and(|explicit| !explicit[locale_idx][key_idx])
+            });
+            return if from_default_key {
+                (translation, Resolution::DefaultKey)
+            } else {
+                (translation, resolution)
+            };
+        }
+        (PLACEHOLDER, Resolution::Missing)
+    }
+
+    /// Get the index of the longest prefix of `locale` that is part of the table, dropping one subtag at
+    /// a time, like `es` for `es-MX`
+    fn base_language_index(&self, locale: &str) -> Option<usize> {
+        let mut candidate = locale;
+        loop {
+            candidate = &candidate[..candidate.rfind(['-', '_'])?];
+            if let Some(idx) = self.locale_index(candidate) {
+                return Some(idx);
+            }
+        }
+    }
+
+    /// Create a reference to the specified locale, or return `None` if it isn't part of the table.
+    ///
+    /// # Example
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// let spanish = Spanglish::TABLE.try_get_locale("es").unwrap();
+    /// assert_eq!(spanish.localize("greeting"), "Hola");
+    ///
+    /// assert!(Spanglish::TABLE.try_get_locale("de").is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn try_get_locale(&'a self, locale: &str) -> Option<LocaleHandle<'a, KEYS>> {
+        self.try_get_locale_with_default(locale, 0)
+    }
+
+    /// Create a reference to the specified locale like [`try_get_locale`](Self::try_get_locale), with
+    /// translations that the locale doesn't have falling back to the locale at index `default_locale`.
+    #[inline]
+    #[must_use]
+    pub const fn try_get_locale_with_default(
+        &'a self,
+        locale: &str,
+        default_locale: usize,
+    ) -> Option<LocaleHandle<'a, KEYS>> {
+        match self.locale_index(locale) {
+            Some(_) => Some(self.get_locale_with_default(locale, default_locale)),
+            None => None,
+        }
+    }
+
+    /// Create a reference to the specified locale, or to the locale at index `default_locale` if
+    /// `locale` isn't part of the table. Translations that the locale doesn't have fall back to the locale
+    /// at index `default_locale`, like [`localize_with_default`](Self::localize_with_default).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `locale` isn't part of the table and `default_locale` is out of bounds.
+    #[inline]
+    #[must_use]
+    pub const fn get_locale_with_default(
+        &'a self,
+        locale: &str,
+        default_locale: usize,
+    ) -> LocaleHandle<'a, KEYS> {
+        if LOCALES == 0 {
+            return LocaleHandle {
+                locale: "",
+                translation_keys: &self.translation_keys,
+                translations: &LocaleHandle::<KEYS>::PLACEHOLDERS,
+                defaults: &LocaleHandle::<KEYS>::PLACEHOLDERS,
+                placeholder: PLACEHOLDER,
+            };
+        }
+        let idx = find_or(&self.locales, locale, default_locale);
+        let default_idx = if default_locale < LOCALES {
+            default_locale
+        } else {
+            idx
+        };
+        LocaleHandle {
+            locale: self.locales[idx],
+            translation_keys: &self.translation_keys,
+            translations: &self.translations[idx],
+            defaults: &self.translations[default_idx],
+            placeholder: PLACEHOLDER,
+        }
+    }
+}
+
+/// Tables are equal when their translation keys, locales, and translations are all equal, regardless of
+/// the lifetime of the borrowed strings.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, LocalizationTable};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// let greeting = String::from("greeting");
+/// let spanglish = LocalizationTable {
+///     translation_keys: [greeting.as_str()],
+///     locales: ["en", "es"],
+///     translations: [["Hello"], ["Hola"]],
+/// };
+/// assert_eq!(Spanglish::TABLE, spanglish);
+/// ```
+impl<'b, const LOCALES: usize, const KEYS: usize> PartialEq<LocalizationTable<'b, LOCALES, KEYS>>
+    for LocalizationTable<'_, LOCALES, KEYS>
+{
+    fn eq(&self, other: &LocalizationTable<'b, LOCALES, KEYS>) -> bool {
+        self.translation_keys == other.translation_keys
+            && self.locales == other.locales
+            && self.translations == other.translations
+    }
+}
+
+impl<const LOCALES: usize, const KEYS: usize> Eq for LocalizationTable<'_, LOCALES, KEYS> {}
+
+/// Tables are hashed by their translation keys, locales, and translations, consistently with `Eq`, so
+/// they can be used as the keys of a `HashMap` or `HashSet`.
+impl<const LOCALES: usize, const KEYS: usize> Hash for LocalizationTable<'_, LOCALES, KEYS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.translation_keys.hash(state);
+        self.locales.hash(state);
+        self.translations.hash(state);
+    }
+}
+
+/// Get the translation for a `(locale, translation_key)` pair.
+///
+/// Unlike [`LocalizationTable::localize`], this doesn't fall back to another locale or to the `"_"`
+/// key. Use [`LocalizationTable::localize_result`] to handle a missing locale or key without panicking.
+///
+/// # Panics
+///
+/// Panics if the locale or the translation key isn't part of the table.
+///
+/// # Example
+///
+/// ```should_panic
+/// # use localize::localization_table;
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// assert_eq!(&Spanglish::TABLE[("es", "greeting")], "Hola");
+///
+/// // panics with "unknown translation key `farewell`"
+/// let _ = Spanglish::TABLE[("es", "farewell")];
+/// ```
+impl<const LOCALES: usize, const KEYS: usize> Index<(&str, &str)>
+    for LocalizationTable<'_, LOCALES, KEYS>
+{
+    type Output = str;
+
+    fn index(&self, (locale, translation_key): (&str, &str)) -> &str {
+        let Some(locale_idx) = self.locale_index(locale) else {
+            panic!("unknown locale `{locale}`");
+        };
+        let Some(key_idx) = self.key_index(translation_key) else {
+            panic!("unknown translation key `{translation_key}`");
+        };
+        self.translations[locale_idx][key_idx]
+    }
+}
+
+/// A reference to a specific row of a translation table.
+///
+/// # Example
+/// ```
+/// # use localize::{localization_table, LocaleHandle};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    },
+///    "farewell" = {
+///        en => "Goodbye",
+///        es => "Adiós"
+///    }
+/// }}
+///
+/// let spanish: LocaleHandle<'static, 2> = Spanglish::get_locale("es");
+/// assert_eq!(spanish.localize("greeting"), "Hola");
+/// assert_eq!(format!("{spanish}"), "es");
+///
+/// let english: LocaleHandle<'static, 2> = Spanglish::get_locale("en");
+/// assert_eq!(english.localize("greeting"), "Hello");
+/// assert_eq!(format!("{english}"), "en");
+/// ```
+///
+/// Handles compare and hash by their locale alone, so they can be used as keys of a cache: two handles
+/// with the same number of keys are equal if they're for the same locale, even if they come from
+/// different tables.
+///
+/// ```
+/// # use localize::localization_table;
+/// # localization_table!{Spanglish = LDSL {
+/// #    "greeting" = {
+/// #        en => "Hello",
+/// #        es => "Hola"
+/// #    }
+/// # }}
+/// assert_eq!(Spanglish::get_locale("es"), Spanglish::ES);
+/// assert_ne!(Spanglish::get_locale("es"), Spanglish::EN);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LocaleHandle<'a, const KEYS: usize> {
+    locale: &'a str,
+    translation_keys: &'a [&'a str; KEYS],
+    translations: &'a [&'a str; KEYS],
+    /// The translations of the default locale, for cells of this locale that are [`PLACEHOLDER`]
+    defaults: &'a [&'a str; KEYS],
+    /// The string returned for translations that neither this locale nor the default locale has
+    placeholder: &'a str,
+}
+
+impl<'b, const KEYS: usize> PartialEq<LocaleHandle<'b, KEYS>> for LocaleHandle<'_, KEYS> {
+    fn eq(&self, other: &LocaleHandle<'b, KEYS>) -> bool {
+        self.locale == other.locale
+    }
+}
+
+impl<const KEYS: usize> Eq for LocaleHandle<'_, KEYS> {}
+
+impl<const KEYS: usize> Hash for LocaleHandle<'_, KEYS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.locale.hash(state);
+    }
+}
+
+impl<const KEYS: usize> Display for LocaleHandle<'_, KEYS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.locale)
+    }
+}
+
+/// Get the translation for a translation key in this locale.
+///
+/// Unlike [`LocaleHandle::localize`], this doesn't fall back to the `"_"` key or the default locale.
+///
+/// # Panics
+///
+/// Panics if the translation key isn't part of the table.
+///
+/// # Example
+///
+/// ```should_panic
+/// # use localize::localization_table;
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// let spanish = Spanglish::get_locale("es");
+/// assert_eq!(&spanish["greeting"], "Hola");
+///
+/// // panics with "unknown translation key `farewell` in locale `es`"
+/// let _ = &spanish["farewell"];
+/// ```
+impl<const KEYS: usize> Index<&str> for LocaleHandle<'_, KEYS> {
+    type Output = str;
+
+    fn index(&self, translation_key: &str) -> &str {
+        let Some(key_idx) = find(self.translation_keys, translation_key) else {
+            panic!(
+                "unknown translation key `{translation_key}` in locale `{}`",
+                self.locale
+            );
+        };
+        self.translations[key_idx]
+    }
+}
+
+impl<const KEYS: usize> AsLocale for LocaleHandle<'_, KEYS> {
+    fn as_locale(&self) -> &str {
+        self.locale
+    }
+}
+
+impl<'a, const KEYS: usize> LocaleHandle<'a, KEYS> {
+    /// The translations of the handle returned for a table without any locales
+    const PLACEHOLDERS: [&'static str; KEYS] = [PLACEHOLDER; KEYS];
+
+    /// Get the translation at `key_idx`, or the translation of the default locale if this locale
+    /// doesn't have one, like [`LocalizationTable::localize_indices_with_default`]
+    const fn cell(&self, key_idx: usize) -> &'a str {
+        let translation = self.translations[key_idx];
+        if bytes_eq(translation, PLACEHOLDER) {
+            replace_placeholder(self.defaults[key_idx], self.placeholder)
+        } else {
+            translation
+        }
+    }
+
+    /// Use `placeholder` instead of [`PLACEHOLDER`] for translations that neither this locale nor the
+    /// default locale has, like the handles of a table with `#[placeholder(...)]`
+    #[inline]
+    #[must_use]
+    pub const fn with_placeholder(self, placeholder: &'a str) -> Self {
+        Self {
+            placeholder,
+            ..self
+        }
+    }
+
+    /// Get the translated string for the given translation key in this locale, or [`PLACEHOLDER`] if the
+    /// table doesn't have any translation keys.
+    ///
+    /// Translations that the locale doesn't have fall back like [`LocalizationTable::localize`].
+    #[inline]
+    #[must_use]
+    pub const fn localize(&self, translation_key: &str) -> &'a str {
+        if KEYS == 0 {
+            return self.placeholder;
+        }
+        self.cell(find_or(self.translation_keys, translation_key, 0))
+    }
+
+    /// Get the translated string for the given translation key in this locale, like
+    /// [`localize`](Self::localize)
+    #[inline]
+    #[must_use]
+    pub const fn t(&self, translation_key: &str) -> &'a str {
+        self.localize(translation_key)
+    }
+
+    /// Find the index of a translation key, for use with [`localize_at`](Self::localize_at).
+    ///
+    /// Returns `None` if the key isn't part of the table. Otherwise, the index is in `0..KEYS`, and it's the
+    /// same in every locale of the table, like [`LocalizationTable::key_index`].
+    #[inline]
+    #[must_use]
+    pub const fn key_index(&self, translation_key: &str) -> Option<usize> {
+        find(self.translation_keys, translation_key)
+    }
+
+    /// Get the translated string for the translation key at `key_idx` without searching for the key.
+    ///
+    /// Resolving a key once with [`key_index`](Self::key_index) (or [`LocalizationTable::key_index`]) and
+    /// then using the index avoids comparing strings when the same keys are looked up many times, like in
+    /// a loop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_idx` isn't in `0..KEYS`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// let greeting = Spanglish::TABLE.key_index("greeting").unwrap();
+    /// for locale in ["en", "es"] {
+    ///     let handle = Spanglish::get_locale(locale);
+    ///     assert_eq!(handle.localize_at(greeting), handle.localize("greeting"));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn localize_at(&self, key_idx: usize) -> &'a str {
+        self.cell(key_idx)
+    }
+
+    /// Get the translated string for the given translation key in this locale, or `None` if the key isn't
+    /// part of the table
+    #[inline]
+    #[must_use]
+    pub const fn try_localize(&self, translation_key: &str) -> Option<&'a str> {
+        match find(self.translation_keys, translation_key) {
+            Some(key_idx) => Some(self.cell(key_idx)),
+            None => None,
+        }
+    }
+
+    /// Get the translated string for the given translation key in this locale, or `fallback` if the key
+    /// isn't part of the table.
+    ///
+    /// # Example
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    }
+    /// }}
+    ///
+    /// let spanish = Spanglish::get_locale("es");
+    /// assert_eq!(spanish.localize_or("greeting", "Hi"), "Hola");
+    /// assert_eq!(spanish.localize_or("farewell", "Adiós"), "Adiós");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn localize_or(&self, translation_key: &str, fallback: &'a str) -> &'a str {
+        match self.try_localize(translation_key) {
+            Some(translation) => translation,
+            None => fallback,
+        }
+    }
+
+    /// Get the translated strings for several translation keys in this locale, in the same order.
+    ///
+    /// # Example
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///        es => "Adiós"
+    ///    }
+    /// }}
+    ///
+    /// let spanish = Spanglish::with_locale("es");
+    /// assert_eq!(spanish.localize_all(&["greeting", "farewell"]), ["Hola", "Adiós"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn localize_all(&self, translation_keys: &[&str]) -> Vec<&'a str> {
+        translation_keys
+            .iter()
+            .map(|translation_key| self.localize(translation_key))
+            .collect()
+    }
+
+    /// Create a handle that prepends `prefix` and [`NAMESPACE_SEPARATOR`] to every translation key it
+    /// looks up. See [`NamespacedHandle`] for an example.
+    #[inline]
+    #[must_use]
+    pub const fn namespace<'n>(&self, prefix: &'n str) -> NamespacedHandle<'n, 'a, KEYS> {
+        NamespacedHandle::new(*self, prefix)
+    }
+
+    /// Write every translation of this locale as a `key=value` line of a Java `.properties` file, in the
+    /// order of the table's translation keys, without allocating.
+    ///
+    /// Keys and values are escaped like `Properties::store` escapes them: backslashes, line breaks, tabs,
+    /// and the separators `=`, `:`, `#`, and `!` are escaped with a backslash, as are spaces in keys and
+    /// at the start of values. Other characters, including non-ASCII ones, are written as they are, so
+    /// the output should be read as UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    ///
+    /// # Example
+    /// ```
+    /// # use localize::localization_table;
+    ///
+    /// localization_table!{Spanglish = LDSL {
+    ///    "greeting" = {
+    ///        en => "Hello",
+    ///        es => "Hola"
+    ///    },
+    ///    "farewell" = {
+    ///        en => "Goodbye",
+    ///        es => "Adiós"
+    ///    }
+    /// }}
+    ///
+    /// let mut properties = String::new();
+    /// Spanglish::with_locale("es").write_properties(&mut properties).unwrap();
+    /// assert_eq!(properties, "farewell=Adiós\ngreeting=Hola\n");
+    /// ```
+    pub fn write_properties<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        for (translation_key, translation) in self.translation_keys.iter().zip(self.translations) {
+            properties::write_escaped(w, translation_key, true)?;
+            w.write_char('=')?;
+            properties::write_escaped(w, translation, false)?;
+            w.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Get the translated string for the sibling of the given translation key that matches `count`, as
+    /// described in [`LocalizationTable::localize_count`]
+    #[must_use]
+    pub fn localize_count(&self, translation_key: &str, count: u64) -> &'a str {
+        let key_idx = count::find_bucket(self.translation_keys, translation_key, count)
+            .unwrap_or_else(|| find_or(self.translation_keys, translation_key, 0));
+        if KEYS == 0 {
+            return self.placeholder;
+        }
+        self.cell(key_idx)
+    }
+}
+
+/// Map `locale` to its canonical locale if it's one of the `(alias, locale)` pairs in `aliases`, or return
+/// it unchanged otherwise.
+///
+/// This is used by the `resolve_locale` function generated by the `localization_table` macro for the
+/// `#[alias]` attribute.
+///
+/// # Example
+///
+/// ```
+/// use localize::resolve_alias;
+///
+/// const ALIASES: [(&str, &str); 2] = [("english", "en"), ("spanish", "es")];
+///
+/// assert_eq!(resolve_alias(&ALIASES, "spanish"), "es");
+/// assert_eq!(resolve_alias(&ALIASES, "es"), "es");
+/// assert_eq!(resolve_alias(&ALIASES, "french"), "french");
+/// ```
+#[must_use]
+pub const fn resolve_alias<'l>(aliases: &[(&'l str, &'l str)], locale: &'l str) -> &'l str {
+    let mut i = 0;
+    while i < aliases.len() {
+        if strcmp(aliases[i].0, locale) {
+            return aliases[i].1;
+        }
+        i += 1;
+    }
+    locale
+}
+
+/// Return `placeholder` instead of `translation` if it's [`PLACEHOLDER`], or `translation` otherwise.
+///
+/// The cells of a table always use [`PLACEHOLDER`] for missing translations, so that fallbacks and
+/// [`LocalizationTable::is_complete`] can find them. This is used by the lookups generated by the
+/// `localization_table` macro to return the string from its `#[placeholder(...)]` attribute instead.
+///
+/// # Example
+///
+/// ```
+/// use localize::{replace_placeholder, PLACEHOLDER};
+///
+/// assert_eq!(replace_placeholder(PLACEHOLDER, "???"), "???");
+/// assert_eq!(replace_placeholder("Hello", "???"), "Hello");
+/// ```
+#[must_use]
+pub const fn replace_placeholder<'a>(translation: &'a str, placeholder: &'a str) -> &'a str {
+    if bytes_eq(translation, PLACEHOLDER) {
+        placeholder
+    } else {
+        translation
+    }
+}
+
+/// Whether two strings are equal. With the `nfc` feature, strings that aren't byte-for-byte equal are
+/// compared by canonical equivalence, unless they're both ASCII.
+#[inline]
+const fn strcmp(a: &str, b: &str) -> bool {
+    if bytes_eq(a, b) {
+        return true;
+    }
+    #[cfg(feature = "nfc")]
+    if !(a.is_ascii() && b.is_ascii()) {
+        return nfc::strcmp_nfc(a, b);
+    }
+    false
+}
+
+/// Whether two strings have the same bytes, like `a.as_bytes() == b.as_bytes()`, which can't be used in a
+/// `const fn`
+#[inline]
+const fn bytes_eq(a: &str, b: &str) -> bool {
+    let (mut a, mut b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    while let ([x, a_rest @ ..], [y, b_rest @ ..]) = (a, b) {
+        if *x != *y {
+            return false;
+        }
+        (a, b) = (a_rest, b_rest);
+    }
+    true
+}
+
+/// Whether `a` comes before `b` when comparing their bytes, like `a < b`
+const fn str_lt(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+const fn is_strictly_ascending(arr: &[&str]) -> bool {
+    first_out_of_order(arr).is_none()
+}
+
+/// Find the index of the first string in `arr` that isn't greater than the string before it
+const fn first_out_of_order(arr: &[&str]) -> Option<usize> {
+    let mut i = 1;
+    while i < arr.len() {
+        if !str_lt(arr[i - 1], arr[i]) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Find the index of the first string in `arr` that is equal to `s`, or `None` if there isn't one.
+///
+/// This is the lookup that every translation key and locale lookup of this crate is built on; functions
+/// like [`LocalizationTable::localize`] then decide what to fall back to when it returns `None`. With
+/// the `nfc` feature, strings are compared by canonical equivalence like translation keys are.
+///
+/// # Example
+///
+/// ```
+/// use localize::find;
+///
+/// const LOCALES: [&str; 3] = ["de", "en", "es"];
+/// const SPANISH: Option<usize> = find(&LOCALES, "es");
+///
+/// assert_eq!(SPANISH, Some(2));
+/// assert_eq!(find(&LOCALES, "fr"), None);
+/// ```
+#[must_use]
+pub const fn find(arr: &[&str], s: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < arr.len() {
+        if strcmp(arr[i], s) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[inline]
+const fn find_or(arr: &[&str], s: &str, default: usize) -> usize {
+    match find(arr, s) {
+        Some(idx) => idx,
+        None => default,
+    }
+}
+
+/// Check at compile time that a locale literal is part of a table, and evaluate to the literal.
+///
+/// The table must be generated by the `localization_table` macro. Aliases from its `#[alias]` attribute
+/// are resolved first, so they're accepted too.
+///
+/// This catches typos in the locales of call sites when the crate is built, instead of silently falling
+/// back to the default locale at runtime.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{locale, localization_table};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// assert_eq!(Spanglish::localize("greeting", locale!(Spanglish, "es")), "Hola");
+/// ```
+///
+/// A locale that isn't part of the table is a compile error:
+///
+/// ```compile_fail
+/// # use localize::{locale, localization_table};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// Spanglish::localize("greeting", locale!(Spanglish, "sp"));
+/// ```
+#[macro_export]
+macro_rules! locale {
+    ($table:path, $locale:literal) => {{
+        const _: () = ::core::assert!(
+            <$table>::TABLE.contains_locale(<$table>::resolve_locale($locale)),
+            ::core::concat!("Unknown locale `", $locale, "`"),
+        );
+        $locale
+    }};
 }
@@ -0,0 +1,52 @@
+use core::{fmt::Display, ops::Index};
+
+/// A table generated by the `localization_table` macro, for code that works with any table.
+///
+/// Every generated table implements this trait with its own inherent functions. The number of
+/// translation keys is part of the type of a [`LocaleHandle`](crate::LocaleHandle), so handles are
+/// returned as the associated [`Handle`](Self::Handle) type, which can be displayed as its locale and
+/// indexed by translation key.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, Localizable};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// fn greet<T: Localizable>(locale: &str) -> String {
+///     let handle = T::get_locale(locale);
+///     format!("{} ({handle})", &handle["greeting"])
+/// }
+///
+/// assert_eq!(greet::<Spanglish>("es"), "Hola (es)");
+/// assert_eq!(<Spanglish as Localizable>::locales(), ["en", "es"]);
+/// ```
+pub trait Localizable {
+    /// The type of the handles returned by [`get_locale`](Self::get_locale), which is
+    /// `LocaleHandle<'static, KEYS>` for tables with `KEYS` translation keys
+    type Handle: Copy + Display + for<'k> Index<&'k str, Output = str>;
+
+    /// Translates a given key like the generated `localize` function, resolving aliases and falling back
+    /// to the default locale
+    fn localize(translation_key: &str, locale: &str) -> &'static str;
+
+    /// Translates a given key without any fallback, returning `None` if the locale (after resolving
+    /// aliases) or the translation key isn't part of the table
+    fn try_localize(translation_key: &str, locale: &str) -> Option<&'static str>;
+
+    /// Create a reference to the specified locale like the generated `get_locale` function, falling back
+    /// to the default locale
+    fn get_locale(locale: &str) -> Self::Handle;
+
+    /// The locales of the table, in the same order as its `locales`
+    fn locales() -> &'static [&'static str];
+
+    /// The translation keys of the table, in the same order as its `translation_keys`
+    fn translation_keys() -> &'static [&'static str];
+}
@@ -0,0 +1,91 @@
+use crate::{LocalizationTable, UnknownLocale};
+
+/// A [`LocalizationTable`] together with the locale that is currently selected, for applications that
+/// choose a locale once and then use it for every lookup.
+///
+/// The locale must be one of the table's locales exactly; aliases from the `#[alias]` attribute of the
+/// `localization_table` macro can be resolved with the generated `resolve_locale` function first.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, Localizer, UnknownLocale};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// let mut localizer = Localizer::new(&Spanglish::TABLE, "en").unwrap();
+/// assert_eq!(localizer.t("greeting"), "Hello");
+///
+/// localizer.set_locale("es").unwrap();
+/// assert_eq!(localizer.t("greeting"), "Hola");
+///
+/// assert_eq!(localizer.set_locale("de"), Err(UnknownLocale));
+/// assert_eq!(localizer.locale(), "es");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Localizer<'a, const LOCALES: usize, const KEYS: usize> {
+    table: &'a LocalizationTable<'a, LOCALES, KEYS>,
+    locale_idx: usize,
+}
+
+impl<'a, const LOCALES: usize, const KEYS: usize> Localizer<'a, LOCALES, KEYS> {
+    /// Create a localizer for `table` with `locale` selected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownLocale`] if `locale` isn't one of the table's locales.
+    pub const fn new(
+        table: &'a LocalizationTable<'a, LOCALES, KEYS>,
+        locale: &str,
+    ) -> Result<Self, UnknownLocale> {
+        match table.locale_index(locale) {
+            Some(locale_idx) => Ok(Self { table, locale_idx }),
+            None => Err(UnknownLocale),
+        }
+    }
+
+    /// Select a different locale. If it isn't one of the table's locales, the current locale is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnknownLocale`] if `locale` isn't one of the table's locales.
+    pub const fn set_locale(&mut self, locale: &str) -> Result<(), UnknownLocale> {
+        match self.table.locale_index(locale) {
+            Some(locale_idx) => {
+                self.locale_idx = locale_idx;
+                Ok(())
+            }
+            None => Err(UnknownLocale),
+        }
+    }
+
+    /// Get the currently selected locale
+    #[must_use]
+    pub const fn locale(&self) -> &'a str {
+        self.table.locales[self.locale_idx]
+    }
+
+    /// Get the table this localizer looks up translations in
+    #[must_use]
+    pub const fn table(&self) -> &'a LocalizationTable<'a, LOCALES, KEYS> {
+        self.table
+    }
+
+    /// Translate a key in the current locale. Like [`LocalizationTable::localize`], an unknown key falls
+    /// back to the first key of the table, and a translation that the locale doesn't have falls back to
+    /// the first locale.
+    #[must_use]
+    pub const fn t(&self, translation_key: &str) -> &'a str {
+        let key_idx = match self.table.key_index(translation_key) {
+            Some(idx) => idx,
+            None => 0,
+        };
+        self.table
+            .localize_indices_with_default(self.locale_idx, key_idx, 0)
+    }
+}
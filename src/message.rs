@@ -0,0 +1,364 @@
+//! A small interpreter for ICU `MessageFormat` patterns.
+
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// A value that can be substituted into a `MessageFormat` pattern by [`format_message`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageArg<'a> {
+    /// A number, which can be used by `plural` arguments
+    Number(i64),
+    /// A string, which can be used by `select` arguments
+    Str(&'a str),
+}
+
+impl From<i64> for MessageArg<'_> {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl<'a> From<&'a str> for MessageArg<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Str(value)
+    }
+}
+
+/// The CLDR plural categories used to select a case of a `plural` argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    const fn keyword(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Get the cardinal plural category of the integer `n` in the given locale.
+///
+/// Only the language part of the locale is used, so `pt-BR` follows the rules for `pt`. The built-in rules
+/// cover the integer cases of Arabic, Czech, French, Polish, Portuguese, Russian, Slovak, Ukrainian,
+/// Belarusian, Hindi, Persian, and languages without plural forms like Japanese, Chinese, and Korean.
+/// Every other language uses the English rule, where only `1` is [`PluralCategory::One`].
+///
+/// # Example
+///
+/// ```
+/// use localize::{plural_category, PluralCategory};
+///
+/// assert_eq!(plural_category("en", 1), PluralCategory::One);
+/// assert_eq!(plural_category("en", 0), PluralCategory::Other);
+/// assert_eq!(plural_category("fr", 0), PluralCategory::One);
+/// assert_eq!(plural_category("ru", 22), PluralCategory::Few);
+/// assert_eq!(plural_category("ja", 1), PluralCategory::Other);
+/// ```
+#[must_use]
+pub fn plural_category(locale: &str, n: i64) -> PluralCategory {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    let n = n.unsigned_abs();
+    let (mod10, mod100) = (n % 10, n % 100);
+    let is = |lang: &str| language.eq_ignore_ascii_case(lang);
+    if ["ja", "zh", "ko", "th", "vi", "id", "ms"]
+        .into_iter()
+        .any(is)
+    {
+        PluralCategory::Other
+    } else if is("fr") {
+        match n {
+            0 | 1 => PluralCategory::One,
+            _ if n.is_multiple_of(1_000_000) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        }
+    } else if ["pt", "hi", "fa"].into_iter().any(is) {
+        if n <= 1 {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    } else if ["ru", "uk", "be"].into_iter().any(is) {
+        if mod10 == 1 && mod100 != 11 {
+            PluralCategory::One
+        } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+            PluralCategory::Few
+        } else {
+            PluralCategory::Many
+        }
+    } else if is("pl") {
+        if n == 1 {
+            PluralCategory::One
+        } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+            PluralCategory::Few
+        } else {
+            PluralCategory::Many
+        }
+    } else if is("cs") || is("sk") {
+        match n {
+            1 => PluralCategory::One,
+            2..=4 => PluralCategory::Few,
+            _ => PluralCategory::Other,
+        }
+    } else if is("ar") {
+        match (n, mod100) {
+            (0, _) => PluralCategory::Zero,
+            (1, _) => PluralCategory::One,
+            (2, _) => PluralCategory::Two,
+            (_, 3..=10) => PluralCategory::Few,
+            (_, 11..=99) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        }
+    } else if n == 1 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
+/// Format an ICU `MessageFormat` pattern with the given arguments, using the plural rules of `locale`.
+///
+/// # Supported syntax
+///
+/// - `{name}` is replaced with the argument `name`. Placeholders without an argument are left as-is.
+/// - `{name, plural, ...}` selects a case based on the number `name`. A case is either an exact value like
+///   `=0` or a plural category (`zero`, `one`, `two`, `few`, `many`, `other`) as decided by
+///   [`plural_category`]. Inside a case, `#` is replaced with the number.
+/// - `{name, select, ...}` selects the case whose keyword is equal to the string `name`.
+///
+/// Every `plural` and `select` argument must have an `other` case, which is used when no other case
+/// matches. Cases can contain nested arguments. Other argument types (like `number` or `date`), offsets,
+/// and apostrophe quoting aren't supported.
+///
+/// # Errors
+///
+/// If the pattern is malformed, or a `plural` or `select` argument is missing or has the wrong kind of
+/// value, the pattern is returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// use localize::{format_message, MessageArg};
+///
+/// let pattern = "{name} has {count, plural, =0 {no items} one {# item} other {# items}}";
+/// let format = |count| format_message(pattern, "en", &[("name", "Ferris".into()), ("count", MessageArg::Number(count))]);
+/// assert_eq!(format(0), "Ferris has no items");
+/// assert_eq!(format(1), "Ferris has 1 item");
+/// assert_eq!(format(5), "Ferris has 5 items");
+///
+/// let pattern = "{gender, select, female {Elle} male {Il} other {Iel}} est là";
+/// assert_eq!(format_message(pattern, "fr", &[("gender", "female".into())]), "Elle est là");
+///
+/// // malformed patterns are returned as-is
+/// assert_eq!(format_message("{count, plural, one {#}", "en", &[]), "{count, plural, one {#}");
+/// ```
+#[must_use]
+pub fn format_message(pattern: &str, locale: &str, args: &[(&str, MessageArg<'_>)]) -> String {
+    let mut parser = Parser { pattern, pos: 0 };
+    let nodes = match parser.parse_message(false) {
+        Ok(nodes) if parser.pos == pattern.len() => nodes,
+        _ => return pattern.to_owned(),
+    };
+    let mut output = String::with_capacity(pattern.len());
+    match write_nodes(&mut output, &nodes, locale, args, None) {
+        Ok(()) => output,
+        Err(MalformedPattern) => pattern.to_owned(),
+    }
+}
+
+#[derive(Debug)]
+struct MalformedPattern;
+
+/// A parsed piece of a pattern
+enum Node<'p> {
+    Text(&'p str),
+    /// `#` inside a plural case
+    Number,
+    Argument(&'p str),
+    Plural(&'p str, Vec<(&'p str, Vec<Self>)>),
+    Select(&'p str, Vec<(&'p str, Vec<Self>)>),
+}
+
+struct Parser<'p> {
+    pattern: &'p str,
+    pos: usize,
+}
+
+impl<'p> Parser<'p> {
+    fn peek(&self) -> Option<u8> {
+        self.pattern.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), MalformedPattern> {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(MalformedPattern)
+        }
+    }
+
+    /// Read a name, selector, or argument type
+    fn word(&mut self) -> Result<&'p str, MalformedPattern> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|b| !b.is_ascii_whitespace() && !b"{},#".contains(&b))
+        {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(MalformedPattern);
+        }
+        Ok(&self.pattern[start..self.pos])
+    }
+
+    /// Parse text and arguments until the end of the pattern or an unmatched `}`
+    fn parse_message(&mut self, in_plural: bool) -> Result<Vec<Node<'p>>, MalformedPattern> {
+        let mut nodes = Vec::new();
+        let mut text_start = self.pos;
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'}' => break,
+                b'{' | b'#' if byte == b'{' || in_plural => {
+                    if text_start < self.pos {
+                        nodes.push(Node::Text(&self.pattern[text_start..self.pos]));
+                    }
+                    self.pos += 1;
+                    nodes.push(if byte == b'{' {
+                        self.parse_argument()?
+                    } else {
+                        Node::Number
+                    });
+                    text_start = self.pos;
+                }
+                _ => self.pos += 1,
+            }
+        }
+        if text_start < self.pos {
+            nodes.push(Node::Text(&self.pattern[text_start..self.pos]));
+        }
+        Ok(nodes)
+    }
+
+    /// Parse an argument after its opening `{`, up to and including its closing `}`
+    fn parse_argument(&mut self) -> Result<Node<'p>, MalformedPattern> {
+        let name = self.word()?;
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Node::Argument(name));
+        }
+        self.expect(b',')?;
+        let kind = self.word()?;
+        let in_plural = match kind {
+            "plural" => true,
+            "select" => false,
+            _ => return Err(MalformedPattern),
+        };
+        self.expect(b',')?;
+        let mut cases = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(b'}') {
+                self.pos += 1;
+                break;
+            }
+            let selector = self.word()?;
+            self.expect(b'{')?;
+            let message = self.parse_message(in_plural)?;
+            self.expect(b'}')?;
+            cases.push((selector, message));
+        }
+        if !cases.iter().any(|(selector, _)| *selector == "other") {
+            return Err(MalformedPattern);
+        }
+        Ok(if in_plural {
+            Node::Plural(name, cases)
+        } else {
+            Node::Select(name, cases)
+        })
+    }
+}
+
+fn write_nodes(
+    output: &mut String,
+    nodes: &[Node<'_>],
+    locale: &str,
+    args: &[(&str, MessageArg<'_>)],
+    number: Option<i64>,
+) -> Result<(), MalformedPattern> {
+    let lookup = |name: &str| {
+        args.iter()
+            .find_map(|(arg, value)| (*arg == name).then_some(*value))
+    };
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Number => output.push_str(&number.ok_or(MalformedPattern)?.to_string()),
+            Node::Argument(name) => match lookup(name) {
+                Some(MessageArg::Number(n)) => output.push_str(&n.to_string()),
+                Some(MessageArg::Str(s)) => output.push_str(s),
+                None => {
+                    output.push('{');
+                    output.push_str(name);
+                    output.push('}');
+                }
+            },
+            Node::Plural(name, cases) => {
+                let Some(MessageArg::Number(n)) = lookup(name) else {
+                    return Err(MalformedPattern);
+                };
+                let exact = format!("={n}");
+                let category = plural_category(locale, n).keyword();
+                let case = find_case(cases, &exact)
+                    .or_else(|| find_case(cases, category))
+                    .or_else(|| find_case(cases, "other"))
+                    .ok_or(MalformedPattern)?;
+                write_nodes(output, case, locale, args, Some(n))?;
+            }
+            Node::Select(name, cases) => {
+                let Some(MessageArg::Str(value)) = lookup(name) else {
+                    return Err(MalformedPattern);
+                };
+                let case = find_case(cases, value)
+                    .or_else(|| find_case(cases, "other"))
+                    .ok_or(MalformedPattern)?;
+                write_nodes(output, case, locale, args, number)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn find_case<'n, 'p>(
+    cases: &'n [(&'p str, Vec<Node<'p>>)],
+    selector: &str,
+) -> Option<&'n [Node<'p>]> {
+    cases
+        .iter()
+        .find_map(|(case, nodes)| (*case == selector).then_some(nodes.as_slice()))
+}
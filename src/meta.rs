@@ -0,0 +1,87 @@
+use core::fmt::{self, Display};
+
+/// The direction that text in a locale is written in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Left-to-right, like English
+    #[default]
+    Ltr,
+    /// Right-to-left, like Arabic or Hebrew
+    Rtl,
+}
+
+impl Direction {
+    /// The value of the HTML `dir` attribute for this direction, `"ltr"` or `"rtl"`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Metadata about a locale of a table, set with the `#[locale_meta]` attribute of the
+/// `localization_table` macro.
+///
+/// Tables generated by the macro have a `LOCALE_META` constant with the metadata of every locale, in
+/// the same order as their `locales`. Locales without a `#[locale_meta]` entry use
+/// [`LocaleMeta::DEFAULT`].
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, Direction, LocaleMeta};
+///
+/// localization_table!{Greetings = LDSL #[locale_meta(ar = { rtl }, en = { date_format = "%m/%d/%Y" })] {
+///    "greeting" = {
+///        ar => "مرحبا",
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// assert_eq!(Greetings::LOCALE_META[0], LocaleMeta { rtl: true, ..LocaleMeta::DEFAULT });
+/// assert!(Greetings::is_rtl("ar"));
+/// assert_eq!(Greetings::direction("en"), Direction::Ltr);
+/// assert_eq!(Greetings::date_format("en"), "%m/%d/%Y");
+/// assert_eq!(Greetings::date_format("es"), "%Y-%m-%d");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocaleMeta {
+    /// Whether the locale is written right-to-left
+    pub rtl: bool,
+    /// The pattern that dates are usually written with in the locale, in `strftime` syntax like
+    /// `"%d/%m/%Y"`. This is only stored for use with a date library; this crate doesn't format dates.
+    pub date_format: &'static str,
+}
+
+impl LocaleMeta {
+    /// The metadata of locales without a `#[locale_meta]` entry: left-to-right, with ISO 8601 dates
+    pub const DEFAULT: Self = Self {
+        rtl: false,
+        date_format: "%Y-%m-%d",
+    };
+
+    /// The direction that text in the locale is written in
+    #[must_use]
+    pub const fn direction(&self) -> Direction {
+        if self.rtl {
+            Direction::Rtl
+        } else {
+            Direction::Ltr
+        }
+    }
+}
+
+impl Default for LocaleMeta {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
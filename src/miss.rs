@@ -0,0 +1,48 @@
+use std::sync::OnceLock;
+
+/// A callback for translation lookups that miss, which is passed the requested translation key and
+/// locale.
+pub type MissHandler = fn(translation_key: &str, locale: &str);
+
+static MISS_HANDLER: OnceLock<MissHandler> = OnceLock::new();
+
+/// Register the callback for lookups that miss.
+///
+/// [`LocalizationTable::localize_logged`](crate::LocalizationTable::localize_logged) calls it when
+/// a lookup misses, for example to log translations that are missing in production. The handler is
+/// global and can only be set once, so it should be set at startup.
+///
+/// # Errors
+///
+/// If a handler was already set, it is kept and `handler` is returned.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, set_miss_handler};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// set_miss_handler(|translation_key, locale| {
+///     eprintln!("missing translation for `{translation_key}` in `{locale}`");
+/// })
+/// .unwrap();
+///
+/// // prints "missing translation for `farewell` in `es`"
+/// Spanglish::TABLE.localize_logged("farewell", "es");
+/// ```
+pub fn set_miss_handler(handler: MissHandler) -> Result<(), MissHandler> {
+    MISS_HANDLER.set(handler)
+}
+
+/// Call the miss handler, if one was set
+pub fn report_miss(translation_key: &str, locale: &str) {
+    if let Some(handler) = MISS_HANDLER.get() {
+        handler(translation_key, locale);
+    }
+}
@@ -0,0 +1,109 @@
+use core::fmt::Display;
+
+use crate::LocaleHandle;
+
+/// The separator between the segments of a namespaced translation key, like `menu.file.open`.
+pub const NAMESPACE_SEPARATOR: char = '.';
+
+/// A [`LocaleHandle`] that prepends a namespace to every translation key it looks up.
+///
+/// The table itself stays flat: looking up `open` in the namespace `menu.file` finds the translation key
+/// `menu.file.open`. Namespaces can be nested with [`namespace`](Self::namespace), which borrows the outer
+/// handle instead of allocating the joined prefix.
+///
+/// # Example
+/// ```
+/// # use localize::localization_table;
+///
+/// localization_table!{Menu = LDSL {
+///    "menu.file.open" = {
+///        en => "Open",
+///        es => "Abrir"
+///    },
+///    "menu.file.close" = {
+///        en => "Close",
+///        es => "Cerrar"
+///    }
+/// }}
+///
+/// let menu = Menu::get_locale("es").namespace("menu");
+/// let file = menu.namespace("file");
+/// assert_eq!(file.localize("open"), "Abrir");
+/// assert_eq!(menu.localize("file.close"), "Cerrar");
+/// assert_eq!(format!("{file}"), "menu.file");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NamespacedHandle<'n, 'a, const KEYS: usize> {
+    handle: LocaleHandle<'a, KEYS>,
+    prefix: &'n str,
+    parent: Option<&'n Self>,
+}
+
+/// Displays the full namespace, with its segments joined by [`NAMESPACE_SEPARATOR`].
+impl<const KEYS: usize> Display for NamespacedHandle<'_, '_, KEYS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{parent}{NAMESPACE_SEPARATOR}")?;
+        }
+        write!(f, "{}", self.prefix)
+    }
+}
+
+impl<'n, 'a, const KEYS: usize> NamespacedHandle<'n, 'a, KEYS> {
+    pub(crate) const fn new(handle: LocaleHandle<'a, KEYS>, prefix: &'n str) -> Self {
+        Self {
+            handle,
+            prefix,
+            parent: None,
+        }
+    }
+
+    /// Create a handle for a namespace nested inside this one
+    #[must_use]
+    pub const fn namespace(&'n self, prefix: &'n str) -> Self {
+        Self {
+            handle: self.handle,
+            prefix,
+            parent: Some(self),
+        }
+    }
+
+    /// Get the handle of the locale this namespace belongs to
+    #[must_use]
+    pub const fn locale_handle(&self) -> LocaleHandle<'a, KEYS> {
+        self.handle
+    }
+
+    /// Get the translated string for the given translation key inside this namespace.
+    ///
+    /// Like [`LocaleHandle::localize`], a key that isn't part of the table falls back to the first
+    /// translation key, which is `"_"` if the table has one, and a table without any translation keys
+    /// returns the placeholder.
+    #[must_use]
+    pub fn localize(&self, translation_key: &str) -> &'a str {
+        if KEYS == 0 {
+            return self.handle.placeholder;
+        }
+        let key_idx = self
+            .handle
+            .translation_keys
+            .iter()
+            .position(|full_key| self.strip_namespace(full_key) == Some(translation_key))
+            .unwrap_or(0);
+        self.handle.localize_at(key_idx)
+    }
+
+    /// Remove this namespace and the separator after it from the start of `full_key`
+    fn strip_namespace<'k>(&self, full_key: &'k str) -> Option<&'k str> {
+        let rest = match self.parent {
+            Some(parent) => parent.strip_namespace(full_key)?,
+            None => full_key,
+        };
+        strip_segment(rest, self.prefix)
+    }
+}
+
+/// Remove `prefix` and the separator after it from the start of `key`
+pub fn strip_segment<'k>(key: &'k str, prefix: &str) -> Option<&'k str> {
+    key.strip_prefix(prefix)?.strip_prefix(NAMESPACE_SEPARATOR)
+}
@@ -0,0 +1,238 @@
+//! Canonical equivalence of strings, for the `nfc` feature.
+
+mod tables;
+
+use tables::{COMBINING_CLASSES, DECOMPOSITIONS};
+
+/// The most decomposed characters that a [`Decomposer`] holds at once, which limits the length of a
+/// starter and the combining marks after it
+const BUFFER: usize = 32;
+
+/// Whether `a` and `b` are canonically equivalent, meaning that they're the same after NFC normalization.
+///
+/// For example, `"café"` can be written with the precomposed `é` or with `e` followed by a combining acute
+/// accent. Strings are compared by decomposing both of them one character at a time and putting the combining
+/// marks after each starter in canonical order, without allocating. There is no case folding, so `"É"`
+/// and `"é"` are still different. A character followed by more than 30 combining marks or so only
+/// matches a byte-for-byte identical string.
+///
+/// # Example
+///
+/// ```
+/// # use localize::strcmp_nfc;
+///
+/// assert!(strcmp_nfc("caf\u{e9}", "cafe\u{301}"));
+/// // the cedilla goes before the acute accent in canonical order
+/// assert!(strcmp_nfc("\u{1e09}", "c\u{301}\u{327}"));
+/// // Hangul syllables are decomposed into their jamo
+/// assert!(strcmp_nfc("\u{ac01}", "\u{1100}\u{1161}\u{11a8}"));
+/// assert!(!strcmp_nfc("caf\u{e9}", "cafe"));
+/// assert!(!strcmp_nfc("caf\u{e9}", "CAF\u{c9}"));
+/// ```
+#[must_use]
+pub const fn strcmp_nfc(a: &str, b: &str) -> bool {
+    if crate::bytes_eq(a, b) {
+        return true;
+    }
+    let mut a = Decomposer::new(a);
+    let mut b = Decomposer::new(b);
+    loop {
+        let len = a.next_segment();
+        if len != b.next_segment() || a.overflow || b.overflow {
+            return false;
+        }
+        if len == 0 {
+            return true;
+        }
+        let mut i = 0;
+        while i < len {
+            if a.buffer[i] != b.buffer[i] {
+                return false;
+            }
+            i += 1;
+        }
+        a.consume(len);
+        b.consume(len);
+    }
+}
+
+/// Decomposes a string into segments of a starter and the combining marks after it, in canonical order
+struct Decomposer<'s> {
+    bytes: &'s [u8],
+    /// The byte offset of the next character to decompose
+    pos: usize,
+    /// Decomposed characters that haven't been consumed yet
+    buffer: [u32; BUFFER],
+    len: usize,
+    /// Whether a segment didn't fit in the buffer
+    overflow: bool,
+}
+
+impl<'s> Decomposer<'s> {
+    const fn new(s: &'s str) -> Self {
+        Self {
+            bytes: s.as_bytes(),
+            pos: 0,
+            buffer: [0; BUFFER],
+            len: 0,
+            overflow: false,
+        }
+    }
+
+    /// Decompose characters until the start of the buffer holds a complete segment, put its combining
+    /// marks in canonical order, and return its length. Returns `0` at the end of the string.
+    const fn next_segment(&mut self) -> usize {
+        let mut end = 1;
+        loop {
+            while end < self.len && combining_class(self.buffer[end]) != 0 {
+                end += 1;
+            }
+            if end < self.len || self.pos >= self.bytes.len() || self.overflow {
+                break;
+            }
+            let (c, width) = decode(self.bytes, self.pos);
+            self.pos += width;
+            self.push_decomposed(c);
+        }
+        let end = if end < self.len { end } else { self.len };
+        self.sort_marks(end);
+        end
+    }
+
+    /// Remove the first `len` characters from the buffer
+    const fn consume(&mut self, len: usize) {
+        let mut i = len;
+        while i < self.len {
+            self.buffer[i - len] = self.buffer[i];
+            i += 1;
+        }
+        self.len -= len;
+    }
+
+    /// Add the full canonical decomposition of `c` to the buffer
+    const fn push_decomposed(&mut self, c: u32) {
+        if let Some((l, v, t)) = decompose_hangul(c) {
+            self.push(l);
+            self.push(v);
+            if t != 0 {
+                self.push(t);
+            }
+        } else if let Some((first, second)) = decomposition(c) {
+            self.push_decomposed(first);
+            if second != 0 {
+                self.push_decomposed(second);
+            }
+        } else {
+            self.push(c);
+        }
+    }
+
+    const fn push(&mut self, c: u32) {
+        if self.len == BUFFER {
+            self.overflow = true;
+        } else {
+            self.buffer[self.len] = c;
+            self.len += 1;
+        }
+    }
+
+    /// Stably sort the combining marks in the first `len` characters of the buffer by combining class,
+    /// which only moves marks past marks of a different class
+    const fn sort_marks(&mut self, len: usize) {
+        let mut i = 1;
+        while i < len {
+            let c = self.buffer[i];
+            let class = combining_class(c);
+            let mut j = i;
+            while j > 0 && combining_class(self.buffer[j - 1]) > class {
+                self.buffer[j] = self.buffer[j - 1];
+                j -= 1;
+            }
+            self.buffer[j] = c;
+            i += 1;
+        }
+    }
+}
+
+/// Decode the UTF-8 character starting at `bytes[i]`, returning it and its length in bytes
+const fn decode(bytes: &[u8], i: usize) -> (u32, usize) {
+    let first = bytes[i] as u32;
+    if first < 0x80 {
+        (first, 1)
+    } else if first < 0xE0 {
+        (((first & 0x1F) << 6) | continuation(bytes[i + 1]), 2)
+    } else if first < 0xF0 {
+        (
+            ((first & 0x0F) << 12) | (continuation(bytes[i + 1]) << 6) | continuation(bytes[i + 2]),
+            3,
+        )
+    } else {
+        (
+            ((first & 0x07) << 18)
+                | (continuation(bytes[i + 1]) << 12)
+                | (continuation(bytes[i + 2]) << 6)
+                | continuation(bytes[i + 3]),
+            4,
+        )
+    }
+}
+
+const fn continuation(byte: u8) -> u32 {
+    (byte & 0x3F) as u32
+}
+
+/// The canonical combining class of `c`, which is `0` for starters
+const fn combining_class(c: u32) -> u8 {
+    let (mut low, mut high) = (0, COMBINING_CLASSES.len());
+    while low < high {
+        let mid = usize::midpoint(low, high);
+        let (first, last, class) = COMBINING_CLASSES[mid];
+        if c < first {
+            high = mid;
+        } else if c > last {
+            low = mid + 1;
+        } else {
+            return class;
+        }
+    }
+    0
+}
+
+/// The one or two characters that `c` decomposes to, with `0` as the second for a single character
+const fn decomposition(c: u32) -> Option<(u32, u32)> {
+    let (mut low, mut high) = (0, DECOMPOSITIONS.len());
+    while low < high {
+        let mid = usize::midpoint(low, high);
+        let (composed, first, second) = DECOMPOSITIONS[mid];
+        if c < composed {
+            high = mid;
+        } else if c > composed {
+            low = mid + 1;
+        } else {
+            return Some((first, second));
+        }
+    }
+    None
+}
+
+/// Split a Hangul syllable into its leading consonant, vowel, and trailing consonant (or `0`), as
+/// described in chapter 3.12 of the Unicode standard
+const fn decompose_hangul(c: u32) -> Option<(u32, u32, u32)> {
+    const S_BASE: u32 = 0xAC00;
+    const L_BASE: u32 = 0x1100;
+    const V_BASE: u32 = 0x1161;
+    const T_BASE: u32 = 0x11A7;
+    const V_COUNT: u32 = 21;
+    const T_COUNT: u32 = 28;
+    const S_COUNT: u32 = 19 * V_COUNT * T_COUNT;
+    if c < S_BASE || c >= S_BASE + S_COUNT {
+        return None;
+    }
+    let index = c - S_BASE;
+    let t = index % T_COUNT;
+    Some((
+        L_BASE + index / (V_COUNT * T_COUNT),
+        V_BASE + (index % (V_COUNT * T_COUNT)) / T_COUNT,
+        if t == 0 { 0 } else { T_BASE + t },
+    ))
+}
@@ -0,0 +1,139 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+/// The symbols used to write numbers in a locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    /// The separator between groups of three digits, like `,` in `1,000`
+    pub grouping_separator: &'static str,
+    /// The separator between the integer and fractional part, like `.` in `1.5`
+    pub decimal_separator: &'static str,
+    /// The fewest digits a number needs in its integer part to be grouped; Spanish writes `1000` but
+    /// `10.000`, so this is `5` for `es`
+    pub min_grouping_digits: usize,
+}
+
+/// Number formats by language, checked in order; the first one is the default
+const NUMBER_FORMATS: [(&str, NumberFormat); 4] = [
+    (
+        "en",
+        NumberFormat {
+            grouping_separator: ",",
+            decimal_separator: ".",
+            min_grouping_digits: 4,
+        },
+    ),
+    (
+        "es",
+        NumberFormat {
+            grouping_separator: ".",
+            decimal_separator: ",",
+            min_grouping_digits: 5,
+        },
+    ),
+    (
+        "fr",
+        NumberFormat {
+            // narrow no-break space
+            grouping_separator: "\u{202f}",
+            decimal_separator: ",",
+            min_grouping_digits: 4,
+        },
+    ),
+    (
+        "de",
+        NumberFormat {
+            grouping_separator: ".",
+            decimal_separator: ",",
+            min_grouping_digits: 4,
+        },
+    ),
+];
+
+impl NumberFormat {
+    /// Get the number format for a locale. Only the language part of the locale is used, so `de-AT` is
+    /// formatted like `de`. Locales other than `en`, `es`, `fr`, and `de` use the `en` format.
+    #[must_use]
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+        NUMBER_FORMATS
+            .iter()
+            .find(|(lang, _)| language.eq_ignore_ascii_case(lang))
+            .unwrap_or(&NUMBER_FORMATS[0])
+            .1
+    }
+}
+
+/// Write an integer with the digit grouping of the given locale, as described by
+/// [`NumberFormat::for_locale`].
+///
+/// # Example
+///
+/// ```
+/// use localize::format_number;
+///
+/// assert_eq!(format_number("en", 1_234_567), "1,234,567");
+/// assert_eq!(format_number("de", 1_234_567), "1.234.567");
+/// assert_eq!(format_number("es", 1000), "1000");
+/// assert_eq!(format_number("es", -10000), "-10.000");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn format_number(locale: &str, n: i64) -> String {
+    let format = NumberFormat::for_locale(locale);
+    let digits = n.unsigned_abs().to_string();
+    let mut output = String::with_capacity(digits.len() * 2);
+    if n < 0 {
+        output.push('-');
+    }
+    push_grouped(&mut output, &digits, format);
+    output
+}
+
+/// Write a number rounded to `decimals` fractional digits with the digit grouping and decimal
+/// separator of the given locale, as described by [`NumberFormat::for_locale`].
+///
+/// # Example
+///
+/// ```
+/// use localize::format_decimal;
+///
+/// assert_eq!(format_decimal("en", 1234.5, 2), "1,234.50");
+/// assert_eq!(format_decimal("de", 1234.5, 2), "1.234,50");
+/// assert_eq!(format_decimal("es", -0.125, 1), "-0,1");
+/// assert_eq!(format_decimal("fr", 2.0, 0), "2");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn format_decimal(locale: &str, n: f64, decimals: usize) -> String {
+    let format = NumberFormat::for_locale(locale);
+    let rounded = alloc::format!("{n:.decimals$}");
+    let (sign, unsigned) = rounded
+        .strip_prefix('-')
+        .map_or(("", rounded.as_str()), |unsigned| ("-", unsigned));
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let mut output = String::with_capacity(rounded.len() * 2);
+    output.push_str(sign);
+    push_grouped(&mut output, integer, format);
+    if !fraction.is_empty() {
+        output.push_str(format.decimal_separator);
+        output.push_str(fraction);
+    }
+    output
+}
+
+/// Append the digits of an integer to `output`, separated into groups of three if there are enough
+/// of them
+#[cfg(feature = "alloc")]
+fn push_grouped(output: &mut String, digits: &str, format: NumberFormat) {
+    if digits.len() < format.min_grouping_digits {
+        output.push_str(digits);
+        return;
+    }
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            output.push_str(format.grouping_separator);
+        }
+        output.push(digit);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::LocalizationTable;
+use std::collections::HashMap;
+
+/// A [`LocalizationTable`] with translations that can be replaced at runtime, like copy from a config
+/// server, without rebuilding the table.
+///
+/// Overrides are keyed by the exact locale and translation key, so they don't resolve aliases or fall
+/// back to other locales. Every cell without an override is looked up in the table as usual.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, Overlay};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello",
+///        es => "Hola"
+///    }
+/// }}
+///
+/// let mut overlay = Overlay::new(&Spanglish::TABLE);
+/// overlay.set_override("en", "greeting", "Howdy");
+/// assert_eq!(overlay.localize("greeting", "en"), "Howdy");
+/// assert_eq!(overlay.localize("greeting", "es"), "Hola");
+///
+/// assert_eq!(overlay.clear_override("en", "greeting").as_deref(), Some("Howdy"));
+/// assert_eq!(overlay.localize("greeting", "en"), "Hello");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Overlay<'a, const LOCALES: usize, const KEYS: usize> {
+    table: &'a LocalizationTable<'a, LOCALES, KEYS>,
+    overrides: HashMap<String, HashMap<String, String>>,
+}
+
+impl<'a, const LOCALES: usize, const KEYS: usize> Overlay<'a, LOCALES, KEYS> {
+    /// Create an overlay for `table` without any overrides
+    #[must_use]
+    pub fn new(table: &'a LocalizationTable<'a, LOCALES, KEYS>) -> Self {
+        Self {
+            table,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Get the table this overlay falls back to
+    #[must_use]
+    pub const fn table(&self) -> &'a LocalizationTable<'a, LOCALES, KEYS> {
+        self.table
+    }
+
+    /// Get the overrides, keyed by locale and then by translation key
+    #[must_use]
+    pub const fn overrides(&self) -> &HashMap<String, HashMap<String, String>> {
+        &self.overrides
+    }
+
+    /// Replace the translation of `translation_key` in `locale`, returning the previous override if there
+    /// was one. The locale and key don't have to be part of the table.
+    pub fn set_override(
+        &mut self,
+        locale: &str,
+        translation_key: &str,
+        translation: impl Into<String>,
+    ) -> Option<String> {
+        self.overrides
+            .entry(locale.to_owned())
+            .or_default()
+            .insert(translation_key.to_owned(), translation.into())
+    }
+
+    /// Remove the override of `translation_key` in `locale`, so it's looked up in the table again, and
+    /// return it if there was one
+    pub fn clear_override(&mut self, locale: &str, translation_key: &str) -> Option<String> {
+        let keys = self.overrides.get_mut(locale)?;
+        let translation = keys.remove(translation_key);
+        if keys.is_empty() {
+            self.overrides.remove(locale);
+        }
+        translation
+    }
+
+    /// Remove every override
+    pub fn clear_overrides(&mut self) {
+        self.overrides.clear();
+    }
+
+    /// Translate a key in a locale, using its override if it has one and
+    /// [`LocalizationTable::localize`] otherwise.
+    #[must_use]
+    pub fn localize<'s>(&'s self, translation_key: &str, locale: &str) -> &'s str {
+        self.overrides
+            .get(locale)
+            .and_then(|keys| keys.get(translation_key))
+            .map_or_else(
+                || self.table.localize(translation_key, locale),
+                String::as_str,
+            )
+    }
+}
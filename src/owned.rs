@@ -0,0 +1,281 @@
+use crate::{AsLocale, LocalizationTable, PLACEHOLDER};
+use alloc::{borrow::ToOwned, collections::BTreeSet, string::String, vec::Vec};
+use core::{
+    fmt::{self, Display},
+    ops::Index,
+};
+
+/// A table of translations that owns its strings, for tables that are assembled at runtime.
+///
+/// It has the same layout as [`LocalizationTable`]: `translations[locale][key]`, with the translation
+/// keys and locales sorted. Static tables can be converted with [`From`], and several tables can be
+/// combined with [`merge`](Self::merge).
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, OwnedLocalizationTable};
+///
+/// localization_table!{Greetings = LDSL {
+///     "greeting" = {
+///         en => "Hello",
+///         es => "Hola"
+///     }
+/// }}
+///
+/// let greetings = OwnedLocalizationTable::from(Greetings::TABLE);
+/// assert_eq!(greetings.localize("greeting", "es"), "Hola");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OwnedLocalizationTable {
+    pub translation_keys: Vec<String>,
+    pub locales: Vec<String>,
+    pub translations: Vec<Vec<String>>,
+}
+
+impl OwnedLocalizationTable {
+    /// Translates a given key to the corresponding localized string for the specified locale.
+    ///
+    /// This follows the same rules as [`LocalizationTable::localize`]: an unknown locale or key falls back
+    /// to the first locale or key of the table, and a translation that the locale doesn't have (a
+    /// [`PLACEHOLDER`] cell) falls back to the first locale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has no locales or no translation keys.
+    #[must_use]
+    pub fn localize(&self, translation_key: &str, locale: &str) -> &str {
+        let locale_idx = position(&self.locales, locale).unwrap_or(0);
+        let key_idx = position(&self.translation_keys, translation_key).unwrap_or(0);
+        self.handle(locale_idx).cell(key_idx)
+    }
+
+    /// Create a reference to the specified locale, like [`LocalizationTable::get_locale`]. An unknown
+    /// locale falls back to the first locale of the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has no locales.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{localization_table, OwnedLocalizationTable};
+    ///
+    /// localization_table!{Greetings = LDSL {
+    ///     "greeting" = {
+    ///         en => "Hello",
+    ///         es => "Hola"
+    ///     }
+    /// }}
+    ///
+    /// let greetings = OwnedLocalizationTable::from(Greetings::TABLE);
+    /// let spanish = greetings.get_locale("es");
+    /// assert_eq!(spanish.localize("greeting"), "Hola");
+    /// assert_eq!(spanish.to_string(), "es");
+    /// ```
+    #[must_use]
+    pub fn get_locale(&self, locale: &str) -> OwnedLocaleHandle<'_> {
+        let locale_idx = position(&self.locales, locale).unwrap_or(0);
+        self.handle(locale_idx)
+    }
+
+    /// Create a reference to the specified locale, or `None` if the locale isn't part of the table
+    #[must_use]
+    pub fn try_get_locale(&self, locale: &str) -> Option<OwnedLocaleHandle<'_>> {
+        position(&self.locales, locale).map(|locale_idx| self.handle(locale_idx))
+    }
+
+    fn handle(&self, locale_idx: usize) -> OwnedLocaleHandle<'_> {
+        OwnedLocaleHandle {
+            locale: &self.locales[locale_idx],
+            translation_keys: &self.translation_keys,
+            translations: &self.translations[locale_idx],
+            defaults: &self.translations[0],
+        }
+    }
+
+    /// Combines two tables into one containing every locale and translation key from both.
+    ///
+    /// When both tables have a translation for the same key and locale, the one from `other` is used.
+    /// Cells that neither table has a translation for are filled from the `"_"` default of `other`
+    /// if present, then the `"_"` default of `self`, and finally [`PLACEHOLDER`]. Cells that already
+    /// hold [`PLACEHOLDER`] count as missing.
+    ///
+    /// Tables that are all known at compile time can be combined into a static table with the
+    /// `merge_tables` macro instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use localize::{localization_table, OwnedLocalizationTable};
+    ///
+    /// localization_table!{Core = LDSL {
+    ///     "greeting" = {
+    ///         en => "Hello",
+    ///         es => "Hola"
+    ///     }
+    /// }}
+    ///
+    /// localization_table!{Plugin = LDSL {
+    ///     "plugin_name" = {
+    ///         en => "Spell Checker",
+    ///         fr => "Correcteur"
+    ///     }
+    /// }}
+    ///
+    /// let merged = OwnedLocalizationTable::from(Core::TABLE).merge(Plugin::TABLE);
+    /// assert_eq!(merged.locales, ["en", "es", "fr"]);
+    /// assert_eq!(merged.localize("greeting", "es"), "Hola");
+    /// assert_eq!(merged.localize("plugin_name", "fr"), "Correcteur");
+    /// ```
+    #[must_use]
+    pub fn merge(self, other: impl Into<Self>) -> Self {
+        let other = other.into();
+        let translation_keys: Vec<String> = self
+            .translation_keys
+            .iter()
+            .chain(&other.translation_keys)
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let locales: Vec<String> = self
+            .locales
+            .iter()
+            .chain(&other.locales)
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let translations = locales
+            .iter()
+            .map(|locale| {
+                translation_keys
+                    .iter()
+                    .map(|key| {
+                        other
+                            .cell(key, locale)
+                            .or_else(|| self.cell(key, locale))
+                            .or_else(|| other.cell("_", locale))
+                            .or_else(|| self.cell("_", locale))
+                            .unwrap_or(PLACEHOLDER)
+                            .to_owned()
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            translation_keys,
+            locales,
+            translations,
+        }
+    }
+
+    /// Get the translation stored for this exact key and locale, if there is one
+    fn cell(&self, translation_key: &str, locale: &str) -> Option<&str> {
+        let locale_idx = position(&self.locales, locale)?;
+        let key_idx = position(&self.translation_keys, translation_key)?;
+        Some(self.translations[locale_idx][key_idx].as_str()).filter(|cell| *cell != PLACEHOLDER)
+    }
+}
+
+impl<const LOCALES: usize, const KEYS: usize> From<LocalizationTable<'_, LOCALES, KEYS>>
+    for OwnedLocalizationTable
+{
+    fn from(table: LocalizationTable<'_, LOCALES, KEYS>) -> Self {
+        Self {
+            translation_keys: table.translation_keys.map(str::to_owned).to_vec(),
+            locales: table.locales.map(str::to_owned).to_vec(),
+            translations: table
+                .translations
+                .iter()
+                .map(|row| row.map(str::to_owned).to_vec())
+                .collect(),
+        }
+    }
+}
+
+/// A reference to a single locale of an [`OwnedLocalizationTable`], like
+/// [`LocaleHandle`](crate::LocaleHandle) is for a [`LocalizationTable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OwnedLocaleHandle<'a> {
+    locale: &'a str,
+    translation_keys: &'a [String],
+    translations: &'a [String],
+    /// The translations of the first locale, used for the translations this locale doesn't have
+    defaults: &'a [String],
+}
+
+impl Display for OwnedLocaleHandle<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.locale)
+    }
+}
+
+impl AsLocale for OwnedLocaleHandle<'_> {
+    fn as_locale(&self) -> &str {
+        self.locale
+    }
+}
+
+/// Get the translation for a translation key in this locale.
+///
+/// # Panics
+///
+/// Panics if the translation key isn't part of the table.
+impl Index<&str> for OwnedLocaleHandle<'_> {
+    type Output = str;
+
+    fn index(&self, translation_key: &str) -> &str {
+        self.try_localize(translation_key).unwrap_or_else(|| {
+            panic!(
+                "unknown translation key `{translation_key}` in locale `{}`",
+                self.locale
+            )
+        })
+    }
+}
+
+impl<'a> OwnedLocaleHandle<'a> {
+    /// Get the translated string for the given translation key in this locale. An unknown key falls back to
+    /// the first key of the table and a missing translation to the first locale, like
+    /// [`OwnedLocalizationTable::localize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has no translation keys.
+    #[must_use]
+    pub fn localize(&self, translation_key: &str) -> &'a str {
+        self.cell(position(self.translation_keys, translation_key).unwrap_or(0))
+    }
+
+    /// Get the translated string for the given translation key in this locale, or `None` if the key isn't
+    /// part of the table
+    #[must_use]
+    pub fn try_localize(&self, translation_key: &str) -> Option<&'a str> {
+        position(self.translation_keys, translation_key).map(|key_idx| self.cell(key_idx))
+    }
+
+    /// Get the translated string for the given translation key in this locale, or `fallback` if the key
+    /// isn't part of the table
+    #[must_use]
+    pub fn localize_or(&self, translation_key: &str, fallback: &'a str) -> &'a str {
+        self.try_localize(translation_key).unwrap_or(fallback)
+    }
+
+    /// Get the translation at `key_idx`, or the translation of the first locale if this locale doesn't
+    /// have one
+    fn cell(&self, key_idx: usize) -> &'a str {
+        let translation = self.translations[key_idx].as_str();
+        if translation == PLACEHOLDER {
+            &self.defaults[key_idx]
+        } else {
+            translation
+        }
+    }
+}
+
+fn position(arr: &[String], s: &str) -> Option<usize> {
+    arr.iter().position(|item| item == s)
+}
@@ -0,0 +1,25 @@
+use core::fmt::{self, Write};
+
+/// Write `text` as a key or value of a Java `.properties` file, escaping it like `Properties::store`.
+///
+/// Backslashes, line breaks, tabs, form feeds, and the separators `=`, `:`, `#`, and `!` are escaped
+/// everywhere. Spaces are escaped everywhere in a key, but only at the start of a value, where they would
+/// otherwise be skipped.
+pub fn write_escaped<W: Write + ?Sized>(w: &mut W, text: &str, is_key: bool) -> fmt::Result {
+    for (idx, ch) in text.char_indices() {
+        match ch {
+            '\\' => w.write_str("\\\\")?,
+            '\n' => w.write_str("\\n")?,
+            '\r' => w.write_str("\\r")?,
+            '\t' => w.write_str("\\t")?,
+            '\u{0c}' => w.write_str("\\f")?,
+            '=' | ':' | '#' | '!' => {
+                w.write_char('\\')?;
+                w.write_char(ch)?;
+            }
+            ' ' if is_key || idx == 0 => w.write_str("\\ ")?,
+            ch => w.write_char(ch)?,
+        }
+    }
+    Ok(())
+}
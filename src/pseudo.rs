@@ -0,0 +1,129 @@
+use crate::LocalizationTable;
+use alloc::string::String;
+
+/// Accented replacements for ASCII letters, which keep the text readable while making it obvious that
+/// it went through pseudo-localization
+const ACCENTS: [(char, char); 16] = [
+    ('a', 'á'),
+    ('c', 'ç'),
+    ('e', 'é'),
+    ('i', 'î'),
+    ('n', 'ñ'),
+    ('o', 'ö'),
+    ('u', 'ü'),
+    ('y', 'ý'),
+    ('A', 'Å'),
+    ('C', 'Ç'),
+    ('E', 'É'),
+    ('I', 'Î'),
+    ('N', 'Ñ'),
+    ('O', 'Ö'),
+    ('U', 'Ü'),
+    ('Y', 'Ý'),
+];
+
+/// A [`LocalizationTable`] that pseudo-localizes every translation it returns, for testing a UI before
+/// its real translations exist.
+///
+/// Pseudo-localized text is still readable, but:
+///
+/// - it's wrapped in `[!! ` and ` !!]`, so text that doesn't come from the table, or that is cut off by
+///   the layout, stands out;
+/// - letters are replaced with accented ones, like `Hello` with `Héllö`, to check that non-ASCII text
+///   is displayed correctly;
+/// - it's padded with `~` to be longer, since translations are often longer than the source text.
+///
+/// Each of these can be configured with the public fields. `{name}` placeholders are copied without
+/// accents, so the result can still be interpolated.
+///
+/// # Example
+///
+/// ```
+/// # use localize::{localization_table, PseudoLocalizer};
+///
+/// localization_table!{Spanglish = LDSL {
+///    "greeting" = {
+///        en => "Hello, {name}",
+///        es => "Hola, {name}"
+///    }
+/// }}
+///
+/// let mut pseudo = PseudoLocalizer::new(&Spanglish::TABLE);
+/// assert_eq!(pseudo.localize("greeting", "en"), "[!! Héllö, {name}~~~~ !!]");
+///
+/// pseudo.accents = false;
+/// pseudo.expansion_percent = 0;
+/// assert_eq!(pseudo.localize("greeting", "es"), "[!! Hola, {name} !!]");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PseudoLocalizer<'a, const LOCALES: usize, const KEYS: usize> {
+    table: &'a LocalizationTable<'a, LOCALES, KEYS>,
+    /// Whether to wrap the text in `[!! ` and ` !!]`
+    pub markers: bool,
+    /// Whether to replace letters with accented ones
+    pub accents: bool,
+    /// How much longer to make the text, as a percentage of its length in characters, rounded up
+    pub expansion_percent: usize,
+}
+
+impl<'a, const LOCALES: usize, const KEYS: usize> PseudoLocalizer<'a, LOCALES, KEYS> {
+    /// Create a pseudo-localizer for `table` with markers, accents, and 30% expansion
+    #[must_use]
+    pub const fn new(table: &'a LocalizationTable<'a, LOCALES, KEYS>) -> Self {
+        Self {
+            table,
+            markers: true,
+            accents: true,
+            expansion_percent: 30,
+        }
+    }
+
+    /// Get the table this pseudo-localizer looks up translations in
+    #[must_use]
+    pub const fn table(&self) -> &'a LocalizationTable<'a, LOCALES, KEYS> {
+        self.table
+    }
+
+    /// Translate a key like [`LocalizationTable::localize`], then pseudo-localize the translation.
+    #[must_use]
+    pub fn localize(&self, translation_key: &str, locale: &str) -> String {
+        self.transform(self.table.localize(translation_key, locale))
+    }
+
+    /// Pseudo-localize any text with the settings of this pseudo-localizer
+    #[must_use]
+    pub fn transform(&self, text: &str) -> String {
+        let len = text.chars().count();
+        let padding = (len * self.expansion_percent).div_ceil(100);
+        let mut output = String::with_capacity(text.len() * 2 + padding + 8);
+        if self.markers {
+            output.push_str("[!! ");
+        }
+        let mut in_placeholder = false;
+        for c in text.chars() {
+            match c {
+                '{' => in_placeholder = true,
+                '}' => in_placeholder = false,
+                _ => {}
+            }
+            output.push(if self.accents && !in_placeholder {
+                accented(c)
+            } else {
+                c
+            });
+        }
+        output.extend(core::iter::repeat_n('~', padding));
+        if self.markers {
+            output.push_str(" !!]");
+        }
+        output
+    }
+}
+
+/// Get the accented replacement of `c` from [`ACCENTS`], or `c` itself if it doesn't have one
+fn accented(c: char) -> char {
+    ACCENTS
+        .iter()
+        .find_map(|&(plain, accented)| (plain == c).then_some(accented))
+        .unwrap_or(c)
+}
@@ -0,0 +1,46 @@
+use core::fmt::{self, Display};
+
+/// Where the translation returned by
+/// [`LocalizationTable::localize_resolved`](crate::LocalizationTable::localize_resolved) came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Resolution<'a> {
+    /// The translation is from the requested locale
+    Exact(&'a str),
+    /// The translation is from the base language of the requested locale, like `es` for `es-MX`,
+    /// because the requested locale isn't part of the table
+    BaseLanguage(&'a str),
+    /// The translation is from the default locale, because neither the requested locale nor its base
+    /// language is part of the table, or it doesn't translate the key
+    Default(&'a str),
+    /// The translation is from the default key of the locale (`"_"` or its `#[default_key]`), because
+    /// the key doesn't have its own, or the translation of the first key, because the key isn't part of
+    /// the table
+    DefaultKey,
+    /// No translation was found, so the lookup returned the placeholder
+    Missing,
+}
+
+impl<'a> Resolution<'a> {
+    /// Get the locale that the translation came from, if it's known
+    #[must_use]
+    pub const fn locale(&self) -> Option<&'a str> {
+        match self {
+            Self::Exact(locale) | Self::BaseLanguage(locale) | Self::Default(locale) => {
+                Some(*locale)
+            }
+            Self::DefaultKey | Self::Missing => None,
+        }
+    }
+}
+
+impl Display for Resolution<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact(locale) => write!(f, "locale `{locale}`"),
+            Self::BaseLanguage(locale) => write!(f, "base language `{locale}`"),
+            Self::Default(locale) => write!(f, "default locale `{locale}`"),
+            Self::DefaultKey => f.write_str("default key"),
+            Self::Missing => f.write_str("missing translation"),
+        }
+    }
+}
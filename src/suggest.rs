@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+/// The number of single-character insertions, deletions, and substitutions that turn `a` into `b`
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // the distances from the characters of `a` seen so far to every prefix of `b`
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Find the key closest to `translation_key` within a third of its length (and at least one edit),
+/// preferring the earliest key on a tie. The `"_"` key is never suggested.
+pub fn closest_key<'a>(translation_keys: &[&'a str], translation_key: &str) -> Option<&'a str> {
+    let threshold = (translation_key.chars().count() / 3).max(1);
+    let mut best: Option<(usize, &'a str)> = None;
+    for key in translation_keys {
+        if *key == "_" {
+            continue;
+        }
+        let distance = edit_distance(translation_key, key);
+        if distance <= threshold && best.is_none_or(|(best_distance, _)| distance < best_distance) {
+            best = Some((distance, key));
+        }
+    }
+    best.map(|(_, key)| key)
+}
@@ -1,4 +1,14 @@
-use localize_macros::localization_table;
+use localize::{
+    find, format_decimal, format_number, interpolate, interpolate_into, interpolate_positional,
+    locale, to_locale_lower, to_locale_upper, AsLocale, DefaultingTable, Direction, LocaleMeta,
+    Localizable, LocalizationTable, LocalizeError, LocalizedMessage, Localizer, MessageArg,
+    OwnedLocaleHandle, OwnedLocalizationTable, PseudoLocalizer, Resolution, TableChange,
+    TableError, UnknownLocale, PLACEHOLDER,
+};
+#[cfg(feature = "std")]
+use localize::{global_locale, global_localize, set_global_locale, set_miss_handler, tg, Overlay};
+use localize_macros::{localization_table, merge_tables};
+use std::borrow::Cow;
 
 localization_table! {TestLocTable = LDSL {
     "_" = {
@@ -59,3 +69,3014 @@ fn test_get_locale() {
     assert_eq!(format!("{es}"), "es");
     assert_eq!(es.localize("greeting"), "Hola");
 }
+
+localization_table! {EscapeTable = LDSL {
+    multiline = {
+        en => "line1
+line2",
+        es => "línea1\nlínea2",
+    },
+    quoted = {
+        en => "Say \"hi\"\tplease",
+    },
+    path = {
+        en => r#"C:\Program Files\"Localize"\help.txt"#,
+        es => r"C:\Archivos de programa\help.txt",
+    }
+}}
+
+/// Make sure escape sequences and raw string literals are handled like normal Rust strings
+#[test]
+fn test_escapes_and_raw_strings() {
+    assert_eq!(EscapeTable::localize("multiline", "en"), "line1\nline2");
+    assert_eq!(EscapeTable::localize("multiline", "es"), "línea1\nlínea2");
+    assert_eq!(EscapeTable::localize("quoted", "en"), "Say \"hi\"\tplease");
+    assert_eq!(
+        EscapeTable::localize("path", "en"),
+        "C:\\Program Files\\\"Localize\"\\help.txt"
+    );
+    assert_eq!(
+        EscapeTable::localize("path", "es"),
+        "C:\\Archivos de programa\\help.txt"
+    );
+}
+
+localization_table! {DefaultLocaleTable = LDSL #[default_locale(en)] {
+    greeting = {
+        es => "Hola",
+        en => "Hello",
+        ar => "مرحبا",
+    }
+}}
+
+/// Make sure unknown locales fall back to the `#[default_locale]` row
+#[test]
+fn test_default_locale() {
+    assert_eq!(DefaultLocaleTable::DEFAULT_LOCALE, 1);
+    assert_eq!(DefaultLocaleTable::localize("greeting", "de"), "Hello");
+    assert_eq!(DefaultLocaleTable::localize("greeting", "es"), "Hola");
+    assert_eq!(
+        DefaultLocaleTable::get_locale("de").localize("greeting"),
+        "Hello"
+    );
+    assert_eq!(format!("{}", DefaultLocaleTable::get_locale("de")), "en");
+}
+
+/// Make sure a `DefaultingTable` falls back to the locale at its `DEFAULT` index
+#[test]
+fn test_defaulting_table() {
+    const TABLE: DefaultingTable<'static, 3, 1, 1> = DefaultLocaleTable::DEFAULTING_TABLE;
+    assert_eq!(TABLE.default_locale(), "en");
+    assert_eq!(TABLE.localize("greeting", "de"), "Hello");
+    assert_eq!(TABLE.localize("greeting", "ar"), "مرحبا");
+    assert_eq!(
+        TABLE.localize_with_index(&DefaultLocaleTable::KEY_INDEX, "greeting", "de"),
+        "Hello"
+    );
+    assert_eq!(TABLE.get_locale("de").to_string(), "en");
+    assert_eq!(*TABLE.table(), DefaultLocaleTable::TABLE);
+
+    let arabic = DefaultingTable::<3, 1, 0>::new(DefaultLocaleTable::TABLE);
+    assert_eq!(arabic.localize("greeting", "de"), "مرحبا");
+    assert_eq!(LocalizationTable::from(arabic), DefaultLocaleTable::TABLE);
+}
+
+/// Make sure a `DefaultingTable` can't have a default locale outside of its locales
+#[test]
+#[should_panic = "the default locale of a `DefaultingTable` must be one of its locales"]
+fn test_defaulting_table_out_of_bounds() {
+    let _ = DefaultingTable::<3, 1, 3>::new(DefaultLocaleTable::TABLE);
+}
+
+localization_table! {MergeBase = LDSL {
+    "_" = {
+        en => "<Missing>",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    },
+    farewell = {
+        en => "Goodbye",
+        es => "Adiós",
+    }
+}}
+
+localization_table! {MergePlugin = LDSL {
+    "_" = {
+        fr => "<Manquant>",
+    },
+    greeting = {
+        en => "Hi",
+        fr => "Salut",
+    },
+    plugin = {
+        fr => "Greffon",
+    }
+}}
+
+/// Make sure merging owned tables unions the keys and locales, preferring the second table
+#[test]
+fn test_owned_merge() {
+    let merged = OwnedLocalizationTable::from(MergeBase::TABLE).merge(MergePlugin::TABLE);
+    assert_eq!(merged.locales, ["en", "es", "fr"]);
+    assert_eq!(
+        merged.translation_keys,
+        ["_", "farewell", "greeting", "plugin"]
+    );
+
+    // overlapping key with a conflicting value
+    assert_eq!(merged.localize("greeting", "en"), "Hi");
+    // overlapping key without a conflict
+    assert_eq!(merged.localize("greeting", "es"), "Hola");
+    // disjoint locale
+    assert_eq!(merged.localize("greeting", "fr"), "Salut");
+    assert_eq!(merged.localize("farewell", "es"), "Adiós");
+
+    // cells that neither table has
+    assert_eq!(merged.localize("farewell", "fr"), "<Manquant>");
+    assert_eq!(merged.localize("plugin", "en"), "<Missing>");
+    // falls back to the first locale when looked up, but stays missing in the table
+    assert_eq!(merged.localize("plugin", "es"), "<Missing>");
+    assert_eq!(merged.translations[1][3], PLACEHOLDER);
+}
+
+localization_table! {Spanglish = LDSL {
+    "greeting" = {
+        en => "Hello",
+        es => "Hola"
+    },
+    "farewell" = {
+        en => "Goodbye",
+        es => "Adiós"
+    }
+}}
+
+/// Make sure tables compare equal to an identical hand-built table
+#[test]
+fn test_table_eq() {
+    let spanglish = LocalizationTable {
+        translation_keys: ["farewell", "greeting"],
+        locales: ["en", "es"],
+        translations: [["Goodbye", "Hello"], ["Adiós", "Hola"]],
+    };
+    assert_eq!(Spanglish::TABLE, spanglish);
+
+    let mut changed = spanglish;
+    changed.translations[1][0] = "Chao";
+    assert_ne!(Spanglish::TABLE, changed);
+}
+
+/// Make sure the hashed lookup finds the same translations as the linear one
+#[test]
+fn test_key_index() {
+    for key in TestLocTable::TABLE.translation_keys {
+        assert_eq!(
+            TestLocTable::KEY_INDEX.get(&TestLocTable::TABLE.translation_keys, key),
+            TestLocTable::TABLE
+                .translation_keys
+                .iter()
+                .position(|k| k == &key)
+        );
+        for locale in TestLocTable::TABLE.locales {
+            assert_eq!(
+                TestLocTable::localize(key, locale),
+                TestLocTable::TABLE.localize(key, locale)
+            );
+        }
+    }
+    assert_eq!(
+        TestLocTable::KEY_INDEX.get(&TestLocTable::TABLE.translation_keys, "farewell"),
+        None
+    );
+}
+
+localization_table! {DuplicateTable = LDSL {
+    cancel = {
+        en => "Cancel",
+        es => "Cancelar",
+    },
+    cancel_dialog = {
+        en => "Cancel",
+        es => "Cancelar",
+    },
+    cancel_upload = {
+        en => "Cancel",
+        es => "Cancelar",
+    },
+    ok = {
+        en => "OK",
+        es => "OK",
+    }
+}}
+
+/// Make sure identical translations share a single string
+#[test]
+fn test_duplicate_translations() {
+    assert_eq!(DuplicateTable::localize("cancel_dialog", "en"), "Cancel");
+    assert_eq!(DuplicateTable::localize("cancel_upload", "es"), "Cancelar");
+    assert_eq!(DuplicateTable::localize("ok", "es"), "OK");
+    assert!(std::ptr::eq(
+        DuplicateTable::localize("cancel", "en"),
+        DuplicateTable::localize("cancel_upload", "en")
+    ));
+    assert!(std::ptr::eq(
+        DuplicateTable::localize("ok", "en"),
+        DuplicateTable::localize("ok", "es")
+    ));
+}
+
+localization_table! {AliasTable = LDSL #[alias(english = en, spanish = es)] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    }
+}}
+
+/// Make sure aliases resolve to their locale
+#[test]
+fn test_aliases() {
+    assert_eq!(AliasTable::localize("greeting", "english"), "Hello");
+    assert_eq!(AliasTable::localize("greeting", "spanish"), "Hola");
+    assert_eq!(AliasTable::localize("greeting", "es"), "Hola");
+    assert_eq!(format!("{}", AliasTable::get_locale("spanish")), "es");
+    assert_eq!(AliasTable::resolve_locale("english"), "en");
+    assert_eq!(AliasTable::resolve_locale("french"), "french");
+}
+
+/// Make sure the index-based lookups agree with the string-based ones
+#[test]
+fn test_indices() {
+    let table = TestLocTable::TABLE;
+    let en = table.locale_index("en").unwrap();
+    let fr = table.locale_index("fr").unwrap();
+    let apple = table.key_index("apple").unwrap();
+    assert_eq!(table.localize_indices(en, apple), "Apple");
+    assert_eq!(table.localize_indices(fr, apple), "Pomme");
+    assert_eq!(table.locale_index("de"), None);
+    assert_eq!(table.key_index("farewell"), None);
+}
+
+/// Make sure out-of-range indices panic
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_indices_out_of_range() {
+    let _ = TestLocTable::TABLE.localize_indices(0, 3);
+}
+
+localization_table! {ContextTable = LDSL {
+    welcome = {
+        en => "Welcome",
+        es => "Bienvenido",
+        es(feminine) => "Bienvenida",
+        fr(masculine) => "Bienvenu",
+        fr(feminine) => "Bienvenue",
+    }
+}}
+
+/// Make sure context variants are used when requested and fall back to the plain translation
+#[test]
+fn test_contexts() {
+    // present context
+    assert_eq!(
+        ContextTable::localize_ctx("welcome", "es", "feminine"),
+        "Bienvenida"
+    );
+    assert_eq!(
+        ContextTable::localize_ctx("welcome", "fr", "masculine"),
+        "Bienvenu"
+    );
+    // missing context
+    assert_eq!(
+        ContextTable::localize_ctx("welcome", "es", "masculine"),
+        "Bienvenido"
+    );
+    // locale without any contexts
+    assert_eq!(
+        ContextTable::localize_ctx("welcome", "en", "feminine"),
+        "Welcome"
+    );
+    assert_eq!(ContextTable::localize("welcome", "es"), "Bienvenido");
+    assert_eq!(ContextTable::CONTEXTS.len(), 3);
+}
+
+localization_table! {InterpolationTable = LDSL {
+    greeting = {
+        en => "Hello, {name}!",
+        es => "¡Hola, {name}!",
+    },
+    braces = {
+        en => "Use {{name}} for {thing}",
+    },
+    plain = {
+        en => "Hello!",
+        es => "¡Hola!",
+    }
+}}
+
+/// Make sure `localize_cow` only allocates when a placeholder is substituted
+#[test]
+fn test_localize_cow() {
+    let table = InterpolationTable::TABLE;
+    let args = [("name", "Ferris"), ("thing", "names")];
+
+    let greeting = table.localize_cow("greeting", "es", &args);
+    assert_eq!(greeting, "¡Hola, Ferris!");
+    assert!(matches!(greeting, Cow::Owned(_)));
+
+    let plain = table.localize_cow("plain", "es", &args);
+    assert_eq!(plain, "¡Hola!");
+    assert!(matches!(plain, Cow::Borrowed(_)));
+
+    // placeholders without a matching argument are left alone
+    let unsubstituted = table.localize_cow("greeting", "en", &[]);
+    assert_eq!(unsubstituted, "Hello, {name}!");
+    assert!(matches!(unsubstituted, Cow::Borrowed(_)));
+
+    let braces = table.localize_cow("braces", "en", &args);
+    assert_eq!(braces, "Use {name} for names");
+}
+
+localization_table! {MessageTable = LDSL {
+    files = {
+        en => "{user} deleted {count, plural, =0 {no files} one {# file} other {# files}}",
+        ru => "{count, plural, one {# файл} few {# файла} many {# файлов} other {# файла}}",
+    },
+    pronoun = {
+        en => "{gender, select, female {She} male {He} other {They}} replied",
+    },
+    broken = {
+        en => "{count, plural, one {# file}",
+    }
+}}
+
+/// Make sure `format_message` picks plural and select cases by locale and leaves malformed patterns alone
+#[test]
+fn test_format_message() {
+    let table = MessageTable::TABLE;
+    let files = |locale, count| {
+        table.format_message(
+            "files",
+            locale,
+            &[
+                ("user", "Ferris".into()),
+                ("count", MessageArg::Number(count)),
+            ],
+        )
+    };
+    assert_eq!(files("en", 0), "Ferris deleted no files");
+    assert_eq!(files("en", 1), "Ferris deleted 1 file");
+    assert_eq!(files("en", 3), "Ferris deleted 3 files");
+    assert_eq!(files("ru", 1), "1 файл");
+    assert_eq!(files("ru", 3), "3 файла");
+    assert_eq!(files("ru", 5), "5 файлов");
+    assert_eq!(files("ru", 21), "21 файл");
+
+    let pronoun =
+        |gender| table.format_message("pronoun", "en", &[("gender", MessageArg::Str(gender))]);
+    assert_eq!(pronoun("female"), "She replied");
+    assert_eq!(pronoun("male"), "He replied");
+    assert_eq!(pronoun("unknown"), "They replied");
+
+    // a missing `plural` argument or an unbalanced pattern leaves the translation unformatted
+    assert_eq!(
+        table.format_message("pronoun", "en", &[]),
+        "{gender, select, female {She} male {He} other {They}} replied"
+    );
+    assert_eq!(
+        table.format_message("broken", "en", &[("count", MessageArg::Number(1))]),
+        "{count, plural, one {# file}"
+    );
+}
+
+localization_table! {CsvTable = CSV "tests/fixtures/strings.csv"}
+
+/// Make sure the CSV backend reads quoted cells and falls back to `"_"` for empty cells
+#[test]
+fn test_csv() {
+    assert_eq!(CsvTable::TABLE.locales, ["en", "es"]);
+    assert_eq!(CsvTable::localize("greeting", "es"), "Hola");
+    assert_eq!(CsvTable::localize("farewell", "en"), "Goodbye, friend");
+    assert_eq!(CsvTable::localize("farewell", "es"), "<No Savo>");
+    assert_eq!(CsvTable::localize("quote", "en"), r#"She said "hi""#);
+    assert_eq!(CsvTable::localize("quote", "es"), r#"Dijo "hola""#);
+    assert_eq!(
+        CsvTable::localize("multiline", "es"),
+        "Línea uno\nLínea dos"
+    );
+}
+
+localization_table! {PoTable = PO {
+    en => "tests/fixtures/en.po",
+    es => "tests/fixtures/es.po",
+}}
+
+/// Make sure the PO backend joins multi-line strings and treats empty and fuzzy entries as missing
+#[test]
+fn test_po() {
+    assert_eq!(PoTable::TABLE.locales, ["en", "es"]);
+    assert_eq!(PoTable::localize("greeting", "es"), "Hola");
+    assert_eq!(
+        PoTable::localize("instructions", "en"),
+        "Press \"Start\" to begin.\nPress \"Stop\" to end."
+    );
+    assert_eq!(PoTable::localize("instructions", "es"), "<No Savo>");
+    assert_eq!(PoTable::localize("farewell", "en"), "Goodbye");
+    assert_eq!(PoTable::localize("farewell", "es"), "<No Savo>");
+}
+
+localization_table! {KeyEnumTable = LDSL #[default_locale(en)] {
+    "_" = {
+        en => "<Unknown>",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    },
+    farewell_message = {
+        en => "Goodbye",
+        es => "Adiós",
+    },
+    "menu.open" = {
+        en => "Open",
+    }
+}}
+
+/// Make sure the key enum covers identifier keys and looks up the same translations as the string API
+#[test]
+fn test_key_enum() {
+    assert_eq!(
+        KeyEnumTableKey::all(),
+        [KeyEnumTableKey::FarewellMessage, KeyEnumTableKey::Greeting]
+    );
+    for &key in KeyEnumTableKey::all() {
+        for locale in ["en", "es", "de"] {
+            assert_eq!(
+                KeyEnumTable::localize_key(key, locale),
+                KeyEnumTable::localize(key.as_str(), locale)
+            );
+        }
+    }
+    assert_eq!(
+        KeyEnumTableKey::FarewellMessage.to_string(),
+        "farewell_message"
+    );
+    assert_eq!(
+        KeyEnumTable::localize_key(KeyEnumTableKey::Greeting, "de"),
+        "Hello"
+    );
+
+    // exhaustive matching over keys
+    let describe = |key| match key {
+        KeyEnumTableKey::Greeting => "hello",
+        KeyEnumTableKey::FarewellMessage => "goodbye",
+    };
+    assert_eq!(describe(KeyEnumTableKey::Greeting), "hello");
+}
+
+/// Make sure `iter_all` visits every cell, with locales as the outer loop
+#[test]
+fn test_iter_all() {
+    let table = TestLocTable::TABLE;
+    let all: Vec<_> = table.iter_all().collect();
+    assert_eq!(
+        all.len(),
+        table.locales.len() * table.translation_keys.len()
+    );
+    assert_eq!(all[0], ("en", "_", "<Unknown Translation>"));
+    assert_eq!(all[2], ("en", "greeting", "Hello"));
+    assert_eq!(all[4], ("es", "apple", "<No Savo>"));
+    assert_eq!(all[6], ("fr", "_", PLACEHOLDER));
+    assert_eq!(all[7], ("fr", "apple", "Pomme"));
+}
+
+localization_table! {RegionTable = LDSL #[default_locale(en-US)] {
+    color = {
+        en-US => "Color",
+        en-GB => "Colour",
+        "es-419" => "Color (LatAm)",
+    },
+    sidewalk = {
+        en-US => "Sidewalk",
+        en-GB => "Pavement",
+    }
+}}
+
+/// Make sure locales with dashes can be looked up and get sanitized constant names
+#[test]
+fn test_hyphenated_locales() {
+    assert_eq!(RegionTable::TABLE.locales, ["en-GB", "en-US", "es-419"]);
+    assert_eq!(RegionTable::localize("color", "en-GB"), "Colour");
+    assert_eq!(RegionTable::localize("color", "es-419"), "Color (LatAm)");
+    assert_eq!(RegionTable::localize("sidewalk", "fr"), "Sidewalk");
+    assert_eq!(RegionTable::EN_GB.localize("sidewalk"), "Pavement");
+    assert_eq!(RegionTable::EN_US.to_string(), "en-US");
+    assert_eq!(RegionTable::ES_419.localize("color"), "Color (LatAm)");
+}
+
+/// `#[warn_inconsistent_locales]` only warns, so the table still works when the warnings are allowed
+#[allow(deprecated)]
+mod inconsistent_locales {
+    use localize_macros::localization_table;
+
+    localization_table! {InconsistentTable = LDSL #[warn_inconsistent_locales] {
+        apple = {
+            en => "Apple",
+            fr => "Pomme",
+        },
+        greeting = {
+            en => "Hello",
+            es => "Hola",
+        }
+    }}
+
+    /// Make sure a table with inconsistent locales still builds
+    #[test]
+    fn test_inconsistent_locales() {
+        assert_eq!(InconsistentTable::localize("apple", "fr"), "Pomme");
+        assert_eq!(InconsistentTable::localize("greeting", "es"), "Hola");
+    }
+}
+
+/// Make sure `with_locale` resolves several keys at once, falling back like `localize`
+#[test]
+fn test_localize_all() {
+    let spanish = TestLocTable::with_locale("es");
+    assert_eq!(
+        spanish.localize_all(&["greeting", "apple", "farewell"]),
+        ["Hola", "<No Savo>", "<No Savo>"]
+    );
+    assert!(spanish.localize_all(&[]).is_empty());
+}
+
+// the crate under another name, like a dependency renamed in `Cargo.toml`
+extern crate localize as renamed_localize;
+
+mod deps {
+    pub use localize;
+}
+
+localization_table! {RenamedTable = LDSL #[crate(::renamed_localize)] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        es(formal) => "Buenos días",
+    }
+}}
+
+localization_table! {ReexportTable = LDSL #[crate(crate::deps::localize)] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    }
+}}
+
+/// Make sure `#[crate]` lets the generated code find the crate under another name
+#[test]
+fn test_crate_path() {
+    assert_eq!(RenamedTable::localize("greeting", "es"), "Hola");
+    assert_eq!(
+        RenamedTable::localize_ctx("greeting", "es", "formal"),
+        "Buenos días"
+    );
+    assert_eq!(ReexportTable::get_locale("es").localize("greeting"), "Hola");
+}
+
+/// Make sure `Localizer` switches locales and keeps the current one when given an unknown locale
+#[test]
+fn test_localizer() {
+    let mut localizer = Localizer::new(&TestLocTable::TABLE, "en").unwrap();
+    assert_eq!(localizer.t("greeting"), "Hello");
+    assert_eq!(localizer.t("farewell"), "<Unknown Translation>");
+
+    localizer.set_locale("fr").unwrap();
+    assert_eq!(localizer.locale(), "fr");
+    assert_eq!(localizer.t("apple"), "Pomme");
+
+    assert_eq!(localizer.set_locale("de"), Err(UnknownLocale));
+    assert_eq!(localizer.locale(), "fr");
+    assert_eq!(localizer.t("apple"), "Pomme");
+
+    assert!(Localizer::new(&TestLocTable::TABLE, "english").is_err());
+    assert_eq!(UnknownLocale.to_string(), "unknown locale");
+}
+
+/// Make sure `format_number` groups digits by locale and keeps the sign of negative numbers
+#[test]
+fn test_format_number() {
+    assert_eq!(format_number("en", 0), "0");
+    assert_eq!(format_number("en", 999), "999");
+    assert_eq!(format_number("en", 1000), "1,000");
+    assert_eq!(format_number("en-GB", 1_234_567), "1,234,567");
+    assert_eq!(format_number("de", 1000), "1.000");
+    assert_eq!(format_number("de", 1_234_567), "1.234.567");
+    assert_eq!(format_number("fr", 1_234_567), "1\u{202f}234\u{202f}567");
+    assert_eq!(format_number("ja", 1_234_567), "1,234,567");
+
+    assert_eq!(format_number("en", -1_234_567), "-1,234,567");
+    assert_eq!(format_number("de", -12), "-12");
+    assert_eq!(format_number("en", i64::MIN), "-9,223,372,036,854,775,808");
+
+    let interpolated = interpolate("You have {count}", &[("count", &format_number("de", 2500))]);
+    assert_eq!(interpolated, "You have 2.500");
+}
+
+/// Make sure `format_decimal` uses the decimal separator of the locale and groups the integer part
+#[test]
+fn test_format_decimal() {
+    assert_eq!(format_decimal("en", 1234.5, 2), "1,234.50");
+    assert_eq!(format_decimal("de-AT", 1_234_567.891, 2), "1.234.567,89");
+    assert_eq!(format_decimal("fr", 0.5, 1), "0,5");
+    assert_eq!(format_decimal("es", 1000.25, 1), "1000,2");
+    assert_eq!(format_decimal("es", 10000.0, 0), "10.000");
+    assert_eq!(format_decimal("en", -1234.0, 1), "-1,234.0");
+    assert_eq!(format_decimal("ja", 0.0, 0), "0");
+}
+
+localization_table! {JsonTable = JSON_DIR "tests/fixtures/i18n"}
+
+/// Make sure the JSON_DIR backend reads one locale per file and takes the union of their keys
+#[test]
+fn test_json_dir() {
+    assert_eq!(JsonTable::TABLE.locales, ["en", "es"]);
+    assert_eq!(
+        JsonTable::TABLE.translation_keys,
+        ["_", "escaped", "farewell", "greeting", "welcome"]
+    );
+    assert_eq!(JsonTable::localize("greeting", "es"), "Hola");
+    assert_eq!(JsonTable::localize("escaped", "en"), "Tab:\t\"quoted\" é");
+    // `welcome` is only in es.json, and en.json has a `"_"` fallback
+    assert_eq!(JsonTable::localize("welcome", "es"), "Bienvenido");
+    assert_eq!(
+        JsonTable::localize("welcome", "en"),
+        "<Unknown Translation>"
+    );
+    // es.json has no `"_"` fallback, so this falls back to en.json
+    assert_eq!(JsonTable::localize("farewell", "es"), "Goodbye");
+    assert_eq!(
+        JsonTable::TABLE.try_localize("farewell", "es"),
+        Some(PLACEHOLDER)
+    );
+}
+
+localization_table! {CompleteTable = LDSL #[complete] {
+    "_" = {
+        en => "<Unknown>",
+        es => "<Desconocido>",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    },
+    apple = {
+        en => "Apple",
+    }
+}}
+
+/// Make sure `#[complete]` accepts tables whose gaps are filled by `"_"`, and `is_complete` spots gaps
+#[test]
+fn test_complete() {
+    assert!(CompleteTable::TABLE.is_complete());
+    assert_eq!(CompleteTable::localize("apple", "es"), "<Desconocido>");
+    assert!(!TestLocTable::TABLE.is_complete());
+}
+
+/// Make sure `localize_result` reports why a lookup failed, checking the locale first
+#[test]
+fn test_localize_result() {
+    let table = TestLocTable::TABLE;
+    assert_eq!(table.localize_result("greeting", "es"), Ok("Hola"));
+    assert_eq!(table.localize_result("apple", "es"), Ok("<No Savo>"));
+    assert_eq!(
+        table.localize_result("greeting", "de"),
+        Err(LocalizeError::UnknownLocale("de".to_owned()))
+    );
+    assert_eq!(
+        table.localize_result("farewell", "en"),
+        Err(LocalizeError::UnknownKey("farewell".to_owned()))
+    );
+    assert_eq!(
+        table.localize_result("farewell", "de"),
+        Err(LocalizeError::UnknownLocale("de".to_owned()))
+    );
+
+    #[cfg(feature = "std")]
+    {
+        let error: Box<dyn std::error::Error> =
+            table.localize_result("farewell", "en").unwrap_err().into();
+        assert_eq!(error.to_string(), "unknown translation key `farewell`");
+    }
+}
+
+localization_table! {MenuTable = LDSL #[alias(spanish = es)] {
+    "_" = {
+        en => "<Unknown>",
+        es => "<Desconocido>",
+    },
+    "menu.file.open" = {
+        en => "Open",
+        es => "Abrir",
+    },
+    "menu.file.close" = {
+        en => "Close",
+        es => "Cerrar",
+    },
+    "menu.edit" = {
+        en => "Edit",
+        es => "Editar",
+    },
+    "file.open" = {
+        en => "Open file",
+        es => "Abrir archivo",
+    }
+}}
+
+/// Make sure namespaced lookups join the namespace and key with `.`
+#[test]
+fn test_localize_ns() {
+    assert_eq!(MenuTable::localize_ns("menu.file", "open", "es"), "Abrir");
+    assert_eq!(MenuTable::localize_ns("menu", "edit", "spanish"), "Editar");
+    assert_eq!(MenuTable::localize_ns("menu", "file.close", "en"), "Close");
+    assert_eq!(
+        MenuTable::TABLE.localize_ns("menu.file", "close", "es"),
+        "Cerrar"
+    );
+    // the separator is required, so `menu.fil` + `e.open` isn't `menu.file.open`
+    assert_eq!(
+        MenuTable::localize_ns("menu.fil", "e.open", "en"),
+        "<Unknown>"
+    );
+    assert_eq!(
+        MenuTable::localize_ns("menu", "save", "es"),
+        "<Desconocido>"
+    );
+}
+
+/// Make sure namespace handles can be nested, and only match keys under the full namespace
+#[test]
+fn test_nested_namespace() {
+    let menu = MenuTable::get_locale("es").namespace("menu");
+    let file = menu.namespace("file");
+    assert_eq!(file.localize("open"), "Abrir");
+    assert_eq!(file.localize("close"), "Cerrar");
+    assert_eq!(menu.localize("edit"), "Editar");
+    assert_eq!(file.to_string(), "menu.file");
+    assert_eq!(file.locale_handle().to_string(), "es");
+    // `file.open` outside of `menu` isn't found through the nested handle
+    assert_eq!(file.localize("edit"), "<Desconocido>");
+    assert_eq!(
+        MenuTable::get_locale("en")
+            .namespace("file")
+            .localize("open"),
+        "Open file"
+    );
+}
+
+localization_table! {DirectionTable = LDSL #[default_locale(en)] #[locale_meta(ar = { rtl }, es = { date_format = "%d/%m/%Y" })] {
+    greeting = {
+        ar => "مرحبا",
+        en => "Hello",
+        es => "Hola",
+    }
+}}
+
+/// Make sure `#[locale_meta]` marks locales as right-to-left, and other locales default to left-to-right
+#[test]
+fn test_locale_direction() {
+    assert!(DirectionTable::is_rtl("ar"));
+    assert_eq!(DirectionTable::direction("ar"), Direction::Rtl);
+    assert!(!DirectionTable::is_rtl("es"));
+    assert_eq!(DirectionTable::direction("es"), Direction::Ltr);
+    assert_eq!(DirectionTable::direction("es").to_string(), "ltr");
+    // unknown locales use the default locale
+    assert_eq!(DirectionTable::direction("de"), Direction::Ltr);
+    assert_eq!(
+        DirectionTable::LOCALE_META,
+        [
+            LocaleMeta {
+                rtl: true,
+                ..LocaleMeta::DEFAULT
+            },
+            LocaleMeta::DEFAULT,
+            LocaleMeta {
+                date_format: "%d/%m/%Y",
+                ..LocaleMeta::DEFAULT
+            }
+        ]
+    );
+    assert_eq!(TestLocTable::LOCALE_META, [LocaleMeta::default(); 3]);
+}
+
+/// Make sure `#[locale_meta]` date formats are looked up by locale, and default to ISO 8601
+#[test]
+fn test_date_format() {
+    assert_eq!(DirectionTable::date_format("es"), "%d/%m/%Y");
+    assert_eq!(DirectionTable::date_format("ar"), "%Y-%m-%d");
+    assert_eq!(DirectionTable::date_format("en"), "%Y-%m-%d");
+    assert_eq!(DirectionTable::locale_meta("es").date_format, "%d/%m/%Y");
+}
+
+localization_table! {TomlTable = TOML "tests/fixtures/strings.toml"}
+
+/// Make sure the TOML backend reads one table per key and falls back to `"_"` for missing locales
+#[test]
+fn test_toml() {
+    assert_eq!(TomlTable::TABLE.locales, ["en", "es"]);
+    assert_eq!(
+        TomlTable::TABLE.translation_keys,
+        ["_", "farewell", "greeting", "menu.open", "multiline"]
+    );
+    assert_eq!(TomlTable::localize("greeting", "es"), "Hola");
+    assert_eq!(TomlTable::localize("farewell", "en"), "Goodbye, friend");
+    assert_eq!(TomlTable::localize("farewell", "es"), "<No Savo>");
+    assert_eq!(TomlTable::localize("menu.open", "es"), "Abrir");
+    assert_eq!(TomlTable::localize("multiline", "en"), "Line one\nLine two");
+    // literal strings don't process escape sequences
+    assert_eq!(
+        TomlTable::localize("multiline", "es"),
+        r"Línea uno\nLínea dos"
+    );
+}
+
+localization_table! {LdslFileTable = LDSL from "tests/fixtures/strings.ldsl"}
+
+localization_table! {LdslEnvTable = LDSL from env "LOCALIZE_TEST_LDSL"}
+
+/// Make sure `LDSL from` reads the translations from a file, named directly or by an environment variable
+#[test]
+fn test_ldsl_from() {
+    assert_eq!(LdslFileTable::TABLE.locales, ["en", "es"]);
+    assert_eq!(LdslFileTable::localize("greeting", "es"), "Hola");
+    assert_eq!(LdslFileTable::localize("farewell", "es"), "<Falta>");
+    assert_eq!(LdslEnvTable::TABLE, LdslFileTable::TABLE);
+    assert_eq!(LdslEnvTable::localize("farewell", "en"), "Goodbye");
+}
+
+/// Make sure `to_json` writes keys and locales in order and escapes special characters
+#[test]
+fn test_to_json() {
+    assert_eq!(
+        Spanglish::TABLE.to_json(),
+        r#"{"farewell":{"en":"Goodbye","es":"Adiós"},"greeting":{"en":"Hello","es":"Hola"}}"#
+    );
+    let table = LocalizationTable {
+        translation_keys: ["quote"],
+        locales: ["en"],
+        translations: [["Say \"hi\"\\\n\t\u{1}"]],
+    };
+    assert_eq!(
+        table.to_json(),
+        r#"{"quote":{"en":"Say \"hi\"\\\n\t\u0001"}}"#
+    );
+}
+
+localization_table! {ReferenceTable = LDSL {
+    submit = {
+        en => "Submit",
+        es => "Enviar",
+        es(formal) => "Envíe",
+    },
+    submit_alt = @ submit,
+    "form.send" = @ "submit_alt"
+}}
+
+/// Make sure `@` references copy every translation of the referenced key, including contexts
+#[test]
+fn test_key_references() {
+    assert_eq!(ReferenceTable::localize("submit_alt", "en"), "Submit");
+    assert_eq!(ReferenceTable::localize("submit_alt", "es"), "Enviar");
+    assert_eq!(ReferenceTable::localize("form.send", "es"), "Enviar");
+    assert_eq!(
+        ReferenceTable::localize_ctx("form.send", "es", "formal"),
+        "Envíe"
+    );
+}
+
+/// Make sure tables and locale handles can be indexed without falling back
+#[test]
+fn test_index() {
+    assert_eq!(&TestLocTable::TABLE[("es", "greeting")], "Hola");
+    assert_eq!(&TestLocTable::TABLE[("en", "apple")], "Apple");
+    let spanish = TestLocTable::get_locale("es");
+    assert_eq!(&spanish["greeting"], "Hola");
+    assert_eq!(&spanish["_"], "<No Savo>");
+}
+
+/// Make sure indexing a table with an unknown translation key panics
+#[test]
+#[should_panic(expected = "unknown translation key `farewell`")]
+fn test_index_unknown_key() {
+    let _ = &TestLocTable::TABLE[("es", "farewell")];
+}
+
+/// Make sure indexing a table with an unknown locale panics
+#[test]
+#[should_panic(expected = "unknown locale `de`")]
+fn test_index_unknown_locale() {
+    let _ = &TestLocTable::TABLE[("de", "greeting")];
+}
+
+/// Make sure indexing a locale handle with an unknown translation key panics
+#[test]
+#[should_panic(expected = "unknown translation key `farewell` in locale `es`")]
+fn test_handle_index_unknown_key() {
+    let _ = &TestLocTable::get_locale("es")["farewell"];
+}
+
+/// Make sure `try_localize` and `localize_or` only fall back when the key or locale is missing
+#[test]
+fn test_localize_or() {
+    let table = TestLocTable::TABLE;
+    assert_eq!(table.try_localize("greeting", "es"), Some("Hola"));
+    assert_eq!(table.try_localize("farewell", "es"), None);
+    assert_eq!(table.try_localize("greeting", "de"), None);
+    assert_eq!(table.localize_or("greeting", "es", "Hi"), "Hola");
+    // cells filled in by `"_"` are still translations
+    assert_eq!(table.localize_or("apple", "es", "Manzana"), "<No Savo>");
+    assert_eq!(table.localize_or("farewell", "es", "Adiós"), "Adiós");
+    assert_eq!(table.localize_or("greeting", "de", "Hallo"), "Hallo");
+
+    let spanish = TestLocTable::get_locale("es");
+    assert_eq!(spanish.try_localize("greeting"), Some("Hola"));
+    assert_eq!(spanish.try_localize("farewell"), None);
+    assert_eq!(spanish.localize_or("greeting", "Hi"), "Hola");
+    assert_eq!(spanish.localize_or("farewell", "Adiós"), "Adiós");
+}
+
+/// Make sure equal tables and locale handles hash the same, so they collapse in a `HashSet`
+#[test]
+fn test_hash() {
+    use std::collections::HashSet;
+
+    let spanglish = LocalizationTable {
+        translation_keys: ["farewell", "greeting"],
+        locales: ["en", "es"],
+        translations: [["Goodbye", "Hello"], ["Adiós", "Hola"]],
+    };
+    let mut changed = spanglish;
+    changed.translations[1][0] = "Chao";
+    let tables = HashSet::from([Spanglish::TABLE, spanglish, changed]);
+    assert_eq!(tables.len(), 2);
+    assert!(tables.contains(&Spanglish::TABLE));
+
+    let handles = HashSet::from([
+        Spanglish::get_locale("es"),
+        Spanglish::ES,
+        Spanglish::get_locale("en"),
+    ]);
+    assert_eq!(handles.len(), 2);
+}
+
+/// Make sure generated tables are sorted, so `debug_assert_sorted` accepts them
+#[test]
+fn test_sorted() {
+    TestLocTable::TABLE.debug_assert_sorted();
+    RegionTable::TABLE.debug_assert_sorted();
+    TomlTable::TABLE.debug_assert_sorted();
+}
+
+/// Make sure `debug_assert_sorted` catches a hand-built table with unsorted keys
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the translation keys of a `LocalizationTable` must be sorted")]
+fn test_unsorted_keys() {
+    LocalizationTable {
+        translation_keys: ["greeting", "farewell"],
+        locales: ["en"],
+        translations: [["Hello", "Goodbye"]],
+    }
+    .debug_assert_sorted();
+}
+
+/// Make sure `debug_assert_sorted` catches a hand-built table with duplicate locales
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the locales of a `LocalizationTable` must be sorted")]
+fn test_duplicate_locales() {
+    LocalizationTable {
+        translation_keys: ["greeting"],
+        locales: ["en", "en"],
+        translations: [["Hello"], ["Hi"]],
+    }
+    .debug_assert_sorted();
+}
+
+localization_table! {CommentTable = LDSL {
+    // comments can go between keys...
+    greeting = {
+        en => "Hello", // ...after translations...
+        /* ...between translations... */
+        es => "Hola",
+    }
+    // the comma between keys is optional
+    farewell = {
+        // ...and at the start of a block
+        en => "Goodbye",
+        es => "Adiós"
+    },
+    welcome = {
+        en => "Welcome",
+    },
+}}
+
+/// Make sure LDSL tables accept comments, trailing commas, and keys without commas between them
+#[test]
+fn test_ldsl_comments_and_commas() {
+    assert_eq!(
+        CommentTable::TABLE.translation_keys,
+        ["farewell", "greeting", "welcome"]
+    );
+    assert_eq!(CommentTable::localize("greeting", "es"), "Hola");
+    assert_eq!(CommentTable::localize("farewell", "es"), "Adiós");
+    assert_eq!(CommentTable::localize("welcome", "en"), "Welcome");
+}
+
+/// Make sure locales can be selected fallibly, without falling back to the default locale
+#[test]
+fn test_try_get_locale() {
+    assert_eq!(
+        AliasTable::try_get_locale("es").map(|handle| handle.localize("greeting")),
+        Some("Hola")
+    );
+    assert_eq!(
+        AliasTable::try_get_locale("spanish").map(|handle| handle.localize("greeting")),
+        Some("Hola")
+    );
+    assert!(AliasTable::try_get_locale("de").is_none());
+    assert!(TestLocTable::TABLE.try_get_locale("de").is_none());
+
+    assert_eq!(
+        AliasTableLocale::try_from("english"),
+        Ok(AliasTableLocale::En)
+    );
+    assert_eq!("es".parse(), Ok(AliasTableLocale::Es));
+    assert_eq!(AliasTableLocale::try_from("de"), Err(UnknownLocale));
+    assert_eq!(AliasTableLocale::Es.handle().localize("greeting"), "Hola");
+    assert_eq!(AliasTableLocale::Es.to_string(), "es");
+    assert_eq!(
+        RegionTableLocale::all()
+            .iter()
+            .map(|locale| locale.as_str())
+            .collect::<Vec<_>>(),
+        RegionTable::TABLE.locales
+    );
+}
+
+localization_table! {SettingsTable = LDSL #[default_locale(en)] #[strings_struct] {
+    "_" = {
+        en => "<Unknown>",
+    },
+    title = {
+        en => "Settings",
+        es => "Ajustes",
+    },
+    "dark mode" = {
+        en => "Dark mode",
+        es => "Modo oscuro",
+    },
+    "menu.close" = {
+        en => "Close",
+    }
+}}
+
+/// Make sure the `#[strings_struct]` fields match looking up each key individually
+#[test]
+fn test_strings_struct() {
+    for locale in ["en", "es", "de"] {
+        let strings = SettingsTable::localize_struct(locale);
+        assert_eq!(strings.title, SettingsTable::localize("title", locale));
+        assert_eq!(
+            strings.dark_mode,
+            SettingsTable::localize("dark mode", locale)
+        );
+        assert_eq!(
+            strings.menu_close,
+            SettingsTable::localize("menu.close", locale)
+        );
+    }
+    assert_eq!(SettingsTable::localize_struct("es").title, "Ajustes");
+    assert_eq!(SettingsTable::localize_struct("es").menu_close, "Close");
+}
+
+/// Make sure `localize_logged` calls the miss handler for unknown keys and locales and missing cells
+#[cfg(feature = "std")]
+#[test]
+fn test_miss_handler() {
+    use std::sync::Mutex;
+
+    static MISSES: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+    fn record(translation_key: &str, locale: &str) {
+        MISSES
+            .lock()
+            .unwrap()
+            .push((translation_key.to_owned(), locale.to_owned()));
+    }
+    // this is the only test that sets the global handler
+    set_miss_handler(record).unwrap();
+    assert!(set_miss_handler(|_, _| {}).is_err());
+
+    let table = JsonTable::TABLE;
+    assert_eq!(table.localize_logged("greeting", "es"), "Hola");
+    // filled in by `"_"`, which isn't a miss
+    assert_eq!(
+        table.localize_logged("welcome", "en"),
+        "<Unknown Translation>"
+    );
+    // falls back to the default locale, but is still a miss
+    assert_eq!(table.localize_logged("farewell", "es"), "Goodbye");
+    assert_eq!(
+        table.localize_logged("unknown", "en"),
+        "<Unknown Translation>"
+    );
+    assert_eq!(table.localize_logged("greeting", "de"), "Hello");
+    assert_eq!(
+        *MISSES.lock().unwrap(),
+        [
+            ("farewell".to_owned(), "es".to_owned()),
+            ("unknown".to_owned(), "en".to_owned()),
+            ("greeting".to_owned(), "de".to_owned()),
+        ]
+    );
+}
+
+/// Make sure whitespace around translation keys is trimmed, so the keys can still be looked up
+#[allow(deprecated)]
+mod untrimmed_keys {
+    use localize_macros::localization_table;
+
+    localization_table! {UntrimmedTable = LDSL {
+        "greeting " = {
+            en => "Hello",
+            es => "Hola",
+        }
+        "\tfarewell" = {
+            en => "Goodbye",
+        }
+    }}
+
+    #[test]
+    fn test_trimmed_keys() {
+        assert_eq!(
+            UntrimmedTable::localize("greeting", "es"),
+            UntrimmedTable::TABLE.localize("greeting", "es")
+        );
+        assert_eq!(UntrimmedTable::localize("greeting", "es"), "Hola");
+        assert_eq!(UntrimmedTable::localize("farewell", "en"), "Goodbye");
+        assert_eq!(
+            UntrimmedTable::TABLE.translation_keys,
+            ["farewell", "greeting"]
+        );
+    }
+}
+
+localization_table! {AccentTable = LDSL {
+    "café" = {
+        en => "Coffee shop",
+        fr => "Café",
+    }
+    "nai\u{308}ve" = {
+        en => "Naive",
+        fr => "Naïf",
+    }
+}}
+
+/// Make sure keys that are canonically equivalent but written differently resolve to the same translations
+/// with the `nfc` feature
+#[cfg(feature = "nfc")]
+#[test]
+fn test_nfc_keys() {
+    // a precomposed `é` and `e` followed by a combining acute accent
+    assert_eq!(AccentTable::localize("cafe\u{301}", "fr"), "Café");
+    assert_eq!(AccentTable::localize("caf\u{e9}", "fr"), "Café");
+    assert_eq!(AccentTable::localize("na\u{ef}ve", "en"), "Naive");
+    assert_eq!(AccentTable::TABLE.localize("na\u{ef}ve", "fr"), "Naïf");
+    assert_eq!(AccentTable::TABLE.key_index("cafe\u{301}"), Some(0));
+    assert_eq!(
+        AccentTable::get_locale("fr").localize("cafe\u{301}"),
+        "Café"
+    );
+    assert_eq!(AccentTable::TABLE.try_localize("CAFÉ", "fr"), None);
+}
+
+/// Make sure keys are compared byte-for-byte without the `nfc` feature
+#[cfg(not(feature = "nfc"))]
+#[test]
+fn test_keys_without_nfc() {
+    assert_eq!(AccentTable::localize("caf\u{e9}", "fr"), "Café");
+    assert_eq!(AccentTable::TABLE.try_localize("cafe\u{301}", "fr"), None);
+    assert_eq!(AccentTable::TABLE.try_localize("na\u{ef}ve", "en"), None);
+}
+
+/// Counts the bytes written to it without storing them
+struct ByteCounter(usize);
+
+impl std::fmt::Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Make sure `localize_into` and `localize_args_into` write the translation to any `fmt::Write`
+#[test]
+fn test_localize_into() {
+    let mut output = String::new();
+    InterpolationTable::TABLE
+        .localize_into(&mut output, "plain", "es")
+        .unwrap();
+    output.push_str(" / ");
+    InterpolationTable::TABLE
+        .localize_args_into(&mut output, "greeting", "es", &[("name", "Ferris")])
+        .unwrap();
+    assert_eq!(output, "¡Hola! / ¡Hola, Ferris!");
+
+    let mut counter = ByteCounter(0);
+    InterpolationTable::TABLE
+        .localize_args_into(&mut counter, "greeting", "en", &[("name", "Ferris")])
+        .unwrap();
+    assert_eq!(counter.0, "Hello, Ferris!".len());
+    InterpolationTable::TABLE
+        .localize_into(&mut counter, "plain", "es")
+        .unwrap();
+    assert_eq!(counter.0, "Hello, Ferris!".len() + "¡Hola!".len());
+
+    let mut output = String::new();
+    interpolate_into(&mut output, "{{{name}}}", &[("name", "Ferris")]).unwrap();
+    assert_eq!(output, "{Ferris}");
+}
+
+localization_table! {CountTable = LDSL #[alias(spanish = es)] {
+    "apples" = {
+        en => "Some apples",
+        es => "Unas manzanas",
+    }
+    "apples.zero" = {
+        en => "No apples",
+        es => "Ninguna manzana",
+    }
+    "apples.one" = {
+        en => "One apple",
+        es => "Una manzana",
+    }
+    "apples.many" = {
+        en => "Many apples",
+        es => "Muchas manzanas",
+    }
+    "pears" = {
+        en => "Some pears",
+    }
+    "pears.one" = {
+        en => "One pear",
+    }
+}}
+
+/// Make sure `localize_count` picks the `zero`, `one`, or `many` sibling key, and falls back to the key
+/// itself when that sibling is missing
+#[test]
+fn test_localize_count() {
+    assert_eq!(
+        CountTable::TABLE.localize_count("apples", "en", 0),
+        "No apples"
+    );
+    assert_eq!(
+        CountTable::TABLE.localize_count("apples", "en", 1),
+        "One apple"
+    );
+    assert_eq!(
+        CountTable::TABLE.localize_count("apples", "en", 5),
+        "Many apples"
+    );
+    assert_eq!(
+        CountTable::localize_count("apples", "spanish", 5),
+        "Muchas manzanas"
+    );
+    assert_eq!(
+        CountTable::get_locale("es").localize_count("apples", 0),
+        "Ninguna manzana"
+    );
+
+    assert_eq!(
+        CountTable::TABLE.localize_count("pears", "en", 0),
+        "Some pears"
+    );
+    assert_eq!(
+        CountTable::TABLE.localize_count("pears", "en", 1),
+        "One pear"
+    );
+    assert_eq!(
+        CountTable::TABLE.localize_count("pears", "en", 5),
+        "Some pears"
+    );
+}
+
+/// Make sure `row` and `column` get a whole locale or key, and `None` for ones that aren't in the table
+#[test]
+fn test_row_and_column() {
+    let table = DefaultLocaleTable::TABLE;
+    assert_eq!(table.row("es"), Some(&["Hola"]));
+    assert_eq!(table.row("de"), None);
+    assert_eq!(table.column("greeting"), Some(["مرحبا", "Hello", "Hola"]));
+    assert_eq!(table.column("farewell"), None);
+
+    let table = CountTable::TABLE;
+    assert_eq!(table.column("pears.one"), Some(["One pear", PLACEHOLDER]));
+    assert_eq!(table.row("en").map(|row| row.len()), Some(6));
+}
+
+localization_table! {NegotiateTable = LDSL {
+    greeting = {
+        en => "Hello",
+        en-GB => "Hello",
+        es => "Hola",
+        "es-419" => "Hola",
+        "zh-Hant" => "你好",
+    }
+}}
+
+/// Make sure `negotiate` tries each preferred locale exactly and then without its region before moving
+/// on to the next one
+#[test]
+fn test_negotiate() {
+    let table = NegotiateTable::TABLE;
+    assert_eq!(table.negotiate(&["es-419"]), Some("es-419"));
+    assert_eq!(table.negotiate(&["de", "en-GB", "es"]), Some("en-GB"));
+    assert_eq!(table.negotiate(&["en-AU", "es"]), Some("en"));
+    assert_eq!(table.negotiate(&["es_MX"]), Some("es"));
+    assert_eq!(table.negotiate(&["zh-Hant-TW", "en"]), Some("zh-Hant"));
+    assert_eq!(table.negotiate(&["de-DE", "fr", "en-US-posix"]), Some("en"));
+    assert_eq!(table.negotiate(&["de-DE", "fr"]), None);
+    assert_eq!(table.negotiate(&[]), None);
+}
+
+/// Make sure `localize_at` returns the same translation as `localize` for the index of every key
+#[test]
+fn test_localize_at() {
+    for locale in CountTable::TABLE.locales {
+        let handle = CountTable::get_locale(locale);
+        for key in CountTable::TABLE.translation_keys {
+            let key_idx = CountTable::TABLE.key_index(key).unwrap();
+            assert_eq!(handle.key_index(key), Some(key_idx));
+            assert_eq!(handle.localize_at(key_idx), handle.localize(key));
+        }
+    }
+    assert_eq!(CountTable::get_locale("en").key_index("plums"), None);
+}
+
+localization_table! {StatusTable = LDSL {
+    404 = {
+        en => "Not Found",
+        es => "No encontrado",
+    }
+    500 = {
+        en => "Internal Server Error",
+        es => "Error interno del servidor",
+    }
+}}
+
+/// Make sure integer keys are looked up by their digits
+#[test]
+fn test_integer_keys() {
+    assert_eq!(StatusTable::localize("404", "es"), "No encontrado");
+    assert_eq!(StatusTable::localize("500", "en"), "Internal Server Error");
+    assert_eq!(
+        StatusTable::TABLE.localize(&500.to_string(), "es"),
+        "Error interno del servidor"
+    );
+    assert_eq!(StatusTable::TABLE.try_localize("418", "en"), None);
+    assert_eq!(StatusTable::TABLE.translation_keys, ["404", "500"]);
+}
+
+localization_table! {ExpectedLocalesTable = LDSL #[locales(es, en, "es-419")] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        "es-419" => "Hola",
+    }
+}}
+
+/// Make sure a table whose locales match `#[locales]` in a different order compiles
+#[test]
+fn test_expected_locales() {
+    assert_eq!(ExpectedLocalesTable::TABLE.locales, ["en", "es", "es-419"]);
+}
+
+localization_table! {FfiTable = LDSL {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    },
+    separated = {
+        en => "Hello\0World",
+        es => "Hola\0Mundo",
+    }
+}}
+
+/// Make sure the owned lookups are copies of the borrowed ones, and that `localize_cstring` rejects
+/// translations with a null byte
+#[test]
+fn test_owned_lookups() {
+    for (key, locale) in [("greeting", "en"), ("greeting", "es"), ("separated", "de")] {
+        assert_eq!(
+            FfiTable::TABLE.localize_string(key, locale),
+            FfiTable::TABLE.localize(key, locale)
+        );
+    }
+    let greeting = FfiTable::TABLE.localize_cstring("greeting", "es").unwrap();
+    assert_eq!(greeting.to_str(), Ok(FfiTable::localize("greeting", "es")));
+    let error = FfiTable::TABLE
+        .localize_cstring("separated", "en")
+        .unwrap_err();
+    assert_eq!(error.nul_position(), 5);
+}
+
+localization_table! {PreservedOrderTable = LDSL #[preserve_order] {
+    greeting = {
+        es => "Hola",
+        en => "Hello",
+    },
+    farewell = {
+        fr => "Au revoir",
+        en => "Goodbye",
+        de => "Auf Wiedersehen",
+    }
+}}
+
+/// Make sure `#[preserve_order]` keeps the locales in the order they were written, and that lookups
+/// still work on the unsorted locales
+#[test]
+fn test_preserve_order() {
+    assert_eq!(PreservedOrderTable::TABLE.locales, ["es", "en", "fr", "de"]);
+    assert_eq!(
+        PreservedOrderTable::TABLE.translation_keys,
+        ["farewell", "greeting"]
+    );
+    assert_eq!(PreservedOrderTable::DEFAULT_LOCALE, 0);
+    assert_eq!(
+        PreservedOrderTable::localize("farewell", "de"),
+        "Auf Wiedersehen"
+    );
+    assert_eq!(PreservedOrderTable::localize("greeting", "en"), "Hello");
+    // unknown locales fall back to the first locale written
+    assert_eq!(PreservedOrderTable::localize("greeting", "it"), "Hola");
+    assert_eq!(PreservedOrderTable::EN.localize("farewell"), "Goodbye");
+}
+
+merge_tables! {MergedTable = #[default_locale(en)] {
+    MergedCore = LDSL {
+        greeting = {
+            en => "Hello",
+            es => "Hola",
+        },
+        "_" = {
+            es => "Falta",
+        }
+    },
+    MergedPlugin = LDSL {
+        plugin_name = {
+            en => "Spell Checker",
+            fr => "Correcteur",
+        },
+        greeting = {
+            fr => "Bonjour",
+        }
+    },
+}}
+
+/// Make sure `merge_tables` generates each table and a table with the keys and locales of all of them
+#[test]
+fn test_merge_tables() {
+    assert_eq!(MergedCore::TABLE.locales, ["en", "es"]);
+    assert_eq!(MergedPlugin::localize("plugin_name", "fr"), "Correcteur");
+    assert_eq!(MergedTable::TABLE.locales, ["en", "es", "fr"]);
+    assert_eq!(
+        MergedTable::TABLE.translation_keys,
+        ["_", "greeting", "plugin_name"]
+    );
+    assert_eq!(MergedTable::localize("greeting", "es"), "Hola");
+    assert_eq!(MergedTable::localize("greeting", "fr"), "Bonjour");
+    assert_eq!(MergedTable::localize("plugin_name", "en"), "Spell Checker");
+    assert_eq!(MergedTable::localize("plugin_name", "es"), "Falta");
+    assert_eq!(MergedTable::localize("greeting", "de"), "Hello");
+}
+
+/// Look up a greeting in any table, for `test_localizable`
+fn translate<T: Localizable>(locale: &str) -> String {
+    let handle = T::get_locale(locale);
+    format!("{handle}: {}", T::localize("greeting", locale))
+}
+
+/// Make sure generated tables can be used through the `Localizable` trait
+#[test]
+fn test_localizable() {
+    assert_eq!(translate::<AliasTable>("spanish"), "es: Hola");
+    assert_eq!(translate::<PreservedOrderTable>("it"), "es: Hola");
+    assert_eq!(
+        <AliasTable as Localizable>::locales(),
+        AliasTable::TABLE.locales
+    );
+    assert_eq!(
+        <MergedTable as Localizable>::translation_keys(),
+        MergedTable::TABLE.translation_keys
+    );
+    assert_eq!(
+        <AliasTable as Localizable>::try_localize("greeting", "english"),
+        Some("Hello")
+    );
+    assert_eq!(
+        <AliasTable as Localizable>::try_localize("greeting", "de"),
+        None
+    );
+    assert_eq!(
+        &<FfiTable as Localizable>::get_locale("es")["greeting"],
+        "Hola"
+    );
+}
+
+localization_table! {DisplayNameTable = LDSL #[display_name(es = "Español", ja = "日本語")] #[alias(spanish = es)] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        ja => "こんにちは",
+    }
+}}
+
+/// Make sure `display_name` returns the annotated name of a locale, or the locale itself without one
+#[test]
+fn test_display_names() {
+    assert_eq!(DisplayNameTable::DISPLAY_NAMES, ["en", "Español", "日本語"]);
+    assert_eq!(DisplayNameTable::display_name("es"), "Español");
+    assert_eq!(DisplayNameTable::display_name("ja"), "日本語");
+    assert_eq!(DisplayNameTable::display_name("spanish"), "Español");
+    assert_eq!(DisplayNameTable::display_name("en"), "en");
+    // unknown locales use the default locale
+    assert_eq!(DisplayNameTable::display_name("de"), "en");
+}
+
+localization_table! {NotedTable = LDSL #[locale_note(de = "Use formal 'Sie'")] #[alias(german = de)] {
+    greeting = {
+        en => "Hello",
+        de => "Guten Tag",
+    }
+}}
+
+/// Make sure `locale_note` returns the note of a locale, and `None` for locales without one
+#[test]
+fn test_locale_notes() {
+    assert_eq!(NotedTable::LOCALE_NOTES, [Some("Use formal 'Sie'"), None]);
+    assert_eq!(NotedTable::locale_note("de"), Some("Use formal 'Sie'"));
+    assert_eq!(NotedTable::locale_note("german"), Some("Use formal 'Sie'"));
+    assert_eq!(NotedTable::locale_note("en"), None);
+    assert_eq!(NotedTable::locale_note("fr"), None);
+}
+
+/// Make sure `suggest_key` suggests keys that are a few edits away, and nothing for unrelated input
+#[test]
+fn test_suggest_key() {
+    assert_eq!(CountTable::TABLE.suggest_key("appels"), Some("apples"));
+    assert_eq!(
+        CountTable::TABLE.suggest_key("Apples.one"),
+        Some("apples.one")
+    );
+    assert_eq!(CountTable::TABLE.suggest_key("pear"), Some("pears"));
+    assert_eq!(CountTable::TABLE.suggest_key("apples"), Some("apples"));
+    assert_eq!(CountTable::TABLE.suggest_key("xyzzy"), None);
+    assert_eq!(CountTable::TABLE.suggest_key(""), None);
+    assert_eq!(
+        PreservedOrderTable::TABLE.suggest_key("greting"),
+        Some("greeting")
+    );
+    assert_eq!(
+        PreservedOrderTable::TABLE.suggest_key("fairwell"),
+        Some("farewell")
+    );
+}
+
+localization_table! {HelpTable = LDSL {
+    usage = {
+        en => dedent "
+            Usage: app [options]
+
+            Options:
+                -h, --help  Show this message
+        ",
+        es => dedent r"
+            Uso: app [opciones]
+              \ indentado
+        ",
+        fr => "
+            Not dedented",
+    }
+}}
+
+/// Make sure `dedent` translations lose their common indentation and the blank first and last lines
+#[test]
+fn test_dedent() {
+    assert_eq!(
+        HelpTable::localize("usage", "en"),
+        "Usage: app [options]\n\nOptions:\n    -h, --help  Show this message"
+    );
+    assert_eq!(
+        HelpTable::localize("usage", "es"),
+        "Uso: app [opciones]\n  \\ indentado"
+    );
+    assert_eq!(
+        HelpTable::localize("usage", "fr"),
+        "\n            Not dedented"
+    );
+}
+
+/// Make sure `find` returns the index of the first, a middle, and the last element, and `None` for a
+/// string that isn't in the array
+#[test]
+fn test_find() {
+    const KEYS: [&str; 4] = ["apples", "bananas", "cherries", "dates"];
+    const FIRST: Option<usize> = find(&KEYS, "apples");
+    assert_eq!(FIRST, Some(0));
+    assert_eq!(find(&KEYS, "bananas"), Some(1));
+    assert_eq!(find(&KEYS, "dates"), Some(3));
+    assert_eq!(find(&KEYS, "figs"), None);
+    assert_eq!(find(&KEYS, "apple"), None);
+    assert_eq!(find(&[], "apples"), None);
+    assert_eq!(find(&["a", "b", "a"], "a"), Some(0));
+}
+
+localization_table! {SizedTable = LDSL #[size_report] {
+    "greeting" = {
+        en => "Hello",
+        es => "Hola",
+    },
+    "farewell" = {
+        en => "Goodbye",
+        es => "Adiós",
+    },
+    "hi" = {
+        en => "Hello",
+        es => "Hola",
+    }
+}}
+
+/// Make sure `BYTES` counts every distinct string once, and every reference in the table
+#[test]
+fn test_size_report() {
+    // "Hello", "Hola", "Goodbye", and "Adiós" (with a two-byte `ó`)
+    let translations = 5 + 4 + 7 + 6;
+    let keys = "farewell".len() + "greeting".len() + "hi".len();
+    let locales = "en".len() + "es".len();
+    let references = 2 * 3 + 3 + 2;
+    assert_eq!(
+        SizedTable::BYTES,
+        translations + keys + locales + references * std::mem::size_of::<&str>()
+    );
+    const _: () = assert!(SizedTable::BYTES < 1024);
+}
+
+localization_table! {FallbackTable = LDSL #[default_locale(en)] {
+    "_" = {
+        en => "<missing>",
+        fr => "<manquant>",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        fr => "Bonjour",
+        it => "Ciao",
+    },
+    farewell = {
+        en => "Goodbye",
+    },
+    welcome = {
+        es => "Bienvenido",
+    },
+    "menu.open" = {
+        en => "Open",
+    },
+    "apples.one" = {
+        en => "One apple",
+    },
+}}
+
+localization_table! {NoDefaultsTable = LDSL #[default_locale(en)] {
+    greeting = {
+        es => "Hola",
+    },
+    farewell = {
+        en => "Goodbye",
+    }
+}}
+
+/// Make sure `localize` tries the translation, the `"_"` of the locale, the translation of the default
+/// locale, the `"_"` of the default locale, and then the placeholder
+#[test]
+fn test_fallback_tiers() {
+    // the translation itself
+    assert_eq!(FallbackTable::localize("greeting", "es"), "Hola");
+    // the `"_"` of the locale
+    assert_eq!(FallbackTable::localize("farewell", "fr"), "<manquant>");
+    // the translation of the default locale
+    assert_eq!(FallbackTable::localize("farewell", "es"), "Goodbye");
+    // the `"_"` of the default locale, for a locale without a `"_"` of its own
+    assert_eq!(FallbackTable::localize("welcome", "it"), "<missing>");
+    // the placeholder
+    assert_eq!(NoDefaultsTable::localize("greeting", "en"), PLACEHOLDER);
+    assert_eq!(NoDefaultsTable::localize("farewell", "es"), "Goodbye");
+    // the same chain is used by `LocalizationTable::localize`, with the first locale as the default
+    assert_eq!(FallbackTable::TABLE.localize("farewell", "es"), "Goodbye");
+    assert_eq!(
+        FallbackTable::DEFAULTING_TABLE.localize("farewell", "es"),
+        "Goodbye"
+    );
+    // and by every other lookup
+    let spanish = FallbackTable::get_locale("es");
+    assert_eq!(spanish.localize("farewell"), "Goodbye");
+    assert_eq!(FallbackTable::ES.localize("farewell"), "Goodbye");
+    assert_eq!(
+        FallbackTable::get_locale("de").localize("welcome"),
+        "<missing>"
+    );
+    assert_eq!(spanish.try_localize("farewell"), Some("Goodbye"));
+    assert_eq!(spanish.namespace("menu").localize("open"), "Open");
+    assert_eq!(spanish.localize_count("apples", 1), "One apple");
+    assert_eq!(FallbackTable::localize_ns("menu", "open", "es"), "Open");
+    assert_eq!(
+        FallbackTable::localize_count("apples", "es", 1),
+        "One apple"
+    );
+    assert_eq!(
+        FallbackTable::TABLE.localize_ns("menu", "open", "es"),
+        "Open"
+    );
+    assert_eq!(
+        FallbackTable::TABLE.localize_count("apples", "es", 1),
+        "One apple"
+    );
+    assert_eq!(
+        FallbackTable::try_get_locale("es").map(|es| es.localize("farewell")),
+        Some("Goodbye")
+    );
+    let localizer = Localizer::new(&FallbackTable::TABLE, "es").unwrap();
+    assert_eq!(localizer.t("farewell"), "Goodbye");
+    assert_eq!(localizer.t("greeting"), "Hola");
+    let owned = OwnedLocalizationTable::from(FallbackTable::TABLE);
+    assert_eq!(owned.localize("farewell", "es"), "Goodbye");
+    assert_eq!(owned.localize("welcome", "it"), "<missing>");
+    let owned_spanish = owned.get_locale("es");
+    assert_eq!(owned_spanish.localize("farewell"), "Goodbye");
+    assert_eq!(owned_spanish.try_localize("farewell"), Some("Goodbye"));
+    assert_eq!(owned_spanish.localize("greeting"), "Hola");
+    // the cells themselves don't change
+    assert_eq!(
+        FallbackTable::TABLE.try_localize("farewell", "es"),
+        Some(PLACEHOLDER)
+    );
+    assert!(!FallbackTable::TABLE.is_complete());
+}
+
+localization_table! {DescribedTable = LDSL {
+    greeting = "The top-of-page hello" {
+        en => "Hello",
+        es => "Hola",
+    },
+    farewell = {
+        en => "Goodbye",
+        es => "Adiós",
+    },
+    bye = "A shorter farewell" @ farewell,
+}}
+
+/// Make sure descriptions can be looked up by translation key, and keys without one return `None`
+#[test]
+fn test_descriptions() {
+    assert_eq!(
+        DescribedTable::description("greeting"),
+        Some("The top-of-page hello")
+    );
+    assert_eq!(
+        DescribedTable::description("bye"),
+        Some("A shorter farewell")
+    );
+    assert_eq!(DescribedTable::description("farewell"), None);
+    assert_eq!(DescribedTable::description("missing"), None);
+    assert_eq!(
+        DescribedTable::DESCRIPTIONS,
+        [
+            Some("A shorter farewell"),
+            None,
+            Some("The top-of-page hello")
+        ]
+    );
+    assert_eq!(DescribedTable::localize("bye", "es"), "Adiós");
+}
+
+localization_table! {EmptyTable = LDSL {}}
+
+/// Make sure a table without any translation keys or locales compiles, and lookups return the placeholder
+/// instead of panicking
+#[test]
+fn test_empty_table() {
+    assert!(EmptyTable::TABLE.is_empty());
+    assert!(!Spanglish::TABLE.is_empty());
+    assert_eq!(EmptyTable::localize("x", "y"), PLACEHOLDER);
+    assert_eq!(EmptyTable::TABLE.localize("x", "y"), PLACEHOLDER);
+    assert_eq!(EmptyTable::try_localize("x", "y"), None);
+    assert_eq!(EmptyTable::localize_count("x", "y", 2), PLACEHOLDER);
+    assert_eq!(EmptyTable::localize_ns("x", "y", "z"), PLACEHOLDER);
+    assert_eq!(EmptyTable::TABLE.localize_ns("x", "y", "z"), PLACEHOLDER);
+    assert_eq!(EmptyTable::display_name("y"), "");
+    assert_eq!(EmptyTable::locale_meta("y"), LocaleMeta::DEFAULT);
+    assert_eq!(
+        EmptyTable::date_format("y"),
+        LocaleMeta::DEFAULT.date_format
+    );
+    assert!(!EmptyTable::is_rtl("y"));
+    let handle = EmptyTable::get_locale("y");
+    assert_eq!(handle.to_string(), "");
+    assert_eq!(handle.localize("x"), PLACEHOLDER);
+    assert_eq!(handle.namespace("x").localize("y"), PLACEHOLDER);
+    assert_eq!(EmptyTable::try_get_locale("y"), None);
+    assert!(EmptyTable::locales().is_empty());
+
+    // a table with locales but without translation keys is empty too
+    let no_keys = LocalizationTable::<'static, 1, 0> {
+        translation_keys: [],
+        locales: ["en"],
+        translations: [[]],
+    };
+    assert!(no_keys.is_empty());
+    assert_eq!(no_keys.localize("x", "en"), PLACEHOLDER);
+    assert_eq!(no_keys.get_locale("en").localize("x"), PLACEHOLDER);
+}
+
+/// Make sure `to_map` agrees with `localize` and leaves out the placeholder cells
+#[cfg(feature = "std")]
+#[test]
+fn test_to_map() {
+    let map = FallbackTable::TABLE.to_map();
+    for (locale, key) in [
+        ("en", "greeting"),
+        ("es", "greeting"),
+        ("fr", "greeting"),
+        ("en", "farewell"),
+        ("fr", "farewell"),
+    ] {
+        assert_eq!(map[&(locale, key)], FallbackTable::localize(key, locale));
+    }
+    assert_eq!(map.get(&("es", "farewell")), None);
+    let real_cells = FallbackTable::TABLE
+        .translations
+        .iter()
+        .flatten()
+        .filter(|cell| **cell != PLACEHOLDER)
+        .count();
+    assert_eq!(map.len(), real_cells);
+}
+
+localization_table! {RegionalTable = LDSL #[cfg_locale(de, any())] #[cfg_locale(fr, feature = "nfc")] #[alias(deu = de)] {
+    greeting = {
+        en => "Hello",
+        fr => "Bonjour",
+        de => "Hallo",
+    },
+    farewell = {
+        en => "Goodbye",
+        fr => "Au revoir",
+        de => "Auf Wiedersehen",
+    }
+}}
+
+/// Make sure locales behind a false `cfg_locale` condition are left out of the table
+#[test]
+fn test_cfg_locale() {
+    // `any()` is never true
+    assert_eq!(RegionalTable::try_get_locale("de"), None);
+    assert_eq!(RegionalTable::localize("greeting", "deu"), "Hello");
+    #[cfg(feature = "nfc")]
+    assert_eq!(RegionalTable::TABLE.locales, ["en", "fr"]);
+    #[cfg(not(feature = "nfc"))]
+    assert_eq!(RegionalTable::TABLE.locales, ["en"]);
+    #[cfg(feature = "nfc")]
+    assert_eq!(RegionalTable::localize("farewell", "fr"), "Au revoir");
+    #[cfg(not(feature = "nfc"))]
+    assert_eq!(RegionalTable::localize("farewell", "fr"), "Goodbye");
+}
+
+localization_table! {MatchedTable = LDSL #[default_locale(en)] #[alias(english = en)] {
+    "_" = {
+        fr => "<manquant>",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        fr => "Bonjour",
+    },
+    farewell = {
+        en => "Goodbye",
+        es => "Adiós",
+    },
+    welcome = {
+        es => "Bienvenido",
+    },
+}}
+
+localization_table! {ScannedTable = LDSL #[default_locale(en)] #[alias(english = en)] #[match_threshold(0)] {
+    "_" = {
+        fr => "<manquant>",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        fr => "Bonjour",
+    },
+    farewell = {
+        en => "Goodbye",
+        es => "Adiós",
+    },
+    welcome = {
+        es => "Bienvenido",
+    },
+}}
+
+/// Make sure the `localize` that matches on the key and locale returns the same translations as the one
+/// that looks up the key in `KEY_INDEX`
+#[test]
+fn test_match_localize() {
+    for key in ["_", "greeting", "farewell", "welcome", "missing", ""] {
+        for locale in ["en", "es", "fr", "english", "de", ""] {
+            assert_eq!(
+                MatchedTable::localize(key, locale),
+                ScannedTable::localize(key, locale),
+                "{key} in {locale}"
+            );
+        }
+    }
+    assert_eq!(MatchedTable::localize("farewell", "fr"), "<manquant>");
+    assert_eq!(MatchedTable::localize("welcome", "english"), PLACEHOLDER);
+    const GREETING: &str = MatchedTable::localize("greeting", "es");
+    assert_eq!(GREETING, "Hola");
+}
+
+/// Make sure Turkish and Azerbaijani keep the dot of `i` when changing case, and other locales use the
+/// default casing
+#[test]
+fn test_locale_case() {
+    assert_eq!(to_locale_upper("tr", "iı"), "İI");
+    assert_eq!(to_locale_lower("tr", "İI"), "iı");
+    assert_eq!(to_locale_upper("az-Latn", "bilgi"), "BİLGİ");
+    assert_eq!(to_locale_lower("TR", "DİL"), "dil");
+    // the default casing loses the difference between the two letters
+    assert_eq!(to_locale_upper("en", "iı"), "II");
+    assert_eq!(to_locale_lower("en", "I"), "i");
+    assert_eq!(to_locale_lower("en", "İ"), "i\u{307}");
+    assert_eq!(to_locale_upper("de", "straße"), "STRASSE");
+    assert_eq!(
+        to_locale_upper("es", Spanglish::localize("greeting", "es")),
+        "HOLA"
+    );
+}
+
+localization_table! {ConstNamedTable = LDSL #[const_name(en_US = US, zh-Hant = ZH)] {
+    greeting = {
+        en_US => "Hello",
+        es => "Hola",
+        zh-Hant => "你好",
+    }
+}}
+
+/// Make sure `#[const_name]` renames the constants of its locales and leaves the others alone
+#[test]
+fn test_const_name() {
+    assert_eq!(ConstNamedTable::US.localize("greeting"), "Hello");
+    assert_eq!(ConstNamedTable::ZH.localize("greeting"), "你好");
+    assert_eq!(ConstNamedTable::ZH.to_string(), "zh-Hant");
+    assert_eq!(ConstNamedTable::ES.localize("greeting"), "Hola");
+}
+
+/// Make sure `new_checked` accepts sorted tables and rejects unsorted or duplicate keys and locales
+#[test]
+fn test_new_checked() {
+    const TABLE: Result<LocalizationTable<'static, 2, 2>, TableError<'static>> =
+        LocalizationTable::new_checked(
+            ["farewell", "greeting"],
+            ["en", "es"],
+            [["Goodbye", "Hello"], ["Adiós", "Hola"]],
+        );
+    assert_eq!(TABLE, Ok(Spanglish::TABLE));
+    assert_eq!(
+        LocalizationTable::new_checked(["greeting", "farewell"], ["en"], [["Hello", "Goodbye"]]),
+        Err(TableError::UnsortedKey("farewell"))
+    );
+    let duplicate =
+        LocalizationTable::new_checked(["greeting", "greeting"], ["en"], [["Hello", "Hi"]]);
+    assert_eq!(duplicate, Err(TableError::DuplicateKey("greeting")));
+    assert_eq!(
+        duplicate.unwrap_err().to_string(),
+        "duplicate translation key `greeting`"
+    );
+    assert_eq!(
+        LocalizationTable::new_checked(["greeting"], ["en", "en"], [["Hello"], ["Hi"]]),
+        Err(TableError::DuplicateLocale("en"))
+    );
+    assert_eq!(
+        LocalizationTable::new_checked(["greeting"], ["es", "en"], [["Hola"], ["Hello"]])
+            .unwrap_err()
+            .to_string(),
+        "locale `en` is out of order"
+    );
+}
+
+localization_table! {GroupedDefaultsTable = LDSL #[default_key(ja = "_cjk", zh = "_cjk")] {
+    "_" = {
+        en => "N/A",
+        es => "N/D",
+        zh => "<missing>",
+    },
+    "_cjk" = {
+        ja => "なし",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        ja => "こんにちは",
+        zh => "你好",
+    },
+    farewell = {
+        en => "Goodbye",
+    }
+}}
+
+/// Make sure locales with a `#[default_key]` fill in their missing translations from it, and the others
+/// from `"_"`
+#[test]
+fn test_default_key() {
+    assert_eq!(GroupedDefaultsTable::localize("farewell", "es"), "N/D");
+    assert_eq!(GroupedDefaultsTable::localize("farewell", "ja"), "なし");
+    // `"_cjk"` doesn't have a translation for `zh`, so it falls back to `"_"`
+    assert_eq!(
+        GroupedDefaultsTable::localize("farewell", "zh"),
+        "<missing>"
+    );
+    assert_eq!(
+        GroupedDefaultsTable::localize("greeting", "ja"),
+        "こんにちは"
+    );
+    // the default keys are filled in like any other key
+    assert_eq!(GroupedDefaultsTable::localize("_cjk", "es"), "N/D");
+}
+
+/// A `fmt::Write` into a fixed buffer, to check that formatting doesn't need to allocate
+struct StackBuffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl std::fmt::Write for StackBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+localization_table! {UnreadTable = LDSL {
+    unread = {
+        en => "You have {n} unread {{messages}}",
+        es => "Tienes {n} mensajes sin leer",
+    },
+    hello = {
+        en => "Hello!",
+    }
+}}
+
+/// Make sure `LocalizedMessage` substitutes its arguments when it's formatted, with the same brace rules
+/// as `interpolate`
+#[test]
+fn test_localized_message() {
+    use std::fmt::Write;
+
+    let args = [("n", "3")];
+    let message = UnreadTable::TABLE.message("unread", "en", &args);
+    assert_eq!(message.to_string(), "You have 3 unread {messages}");
+    assert_eq!(message.template(), "You have {n} unread {{messages}}");
+    assert_eq!(
+        UnreadTable::TABLE
+            .message("unread", "es", &args)
+            .to_string(),
+        interpolate(UnreadTable::localize("unread", "es"), &args)
+    );
+    // placeholders without a value are left as they are
+    assert_eq!(
+        UnreadTable::TABLE.message("unread", "es", &[]).to_string(),
+        "Tienes {n} mensajes sin leer"
+    );
+    assert_eq!(
+        LocalizedMessage::new("{a} {b", &[("a", "1")]).to_string(),
+        "1 {b"
+    );
+
+    let mut buffer = StackBuffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+    write!(buffer, "{}", UnreadTable::TABLE.message("hello", "en", &[])).unwrap();
+    write!(buffer, " {message}").unwrap();
+    assert_eq!(
+        std::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap(),
+        "Hello! You have 3 unread {messages}"
+    );
+}
+
+/// Make sure `locales_with_translation` only lists the locales with their own translation of a key, and
+/// not those filled in from a default key or left as a placeholder
+#[test]
+fn test_locales_with_translation() {
+    let locales = |key| GroupedDefaultsTable::locales_with_translation(key).collect::<Vec<_>>();
+    assert_eq!(locales("greeting"), ["en", "es", "ja", "zh"]);
+    assert_eq!(locales("farewell"), ["en"]);
+    assert_eq!(locales("_cjk"), ["ja"]);
+    assert!(locales("missing").is_empty());
+}
+
+/// Make sure `is_explicit` is only `true` for cells with their own translation
+#[test]
+fn test_is_explicit() {
+    assert!(GroupedDefaultsTable::is_explicit("greeting", "ja"));
+    assert!(GroupedDefaultsTable::is_explicit("_cjk", "ja"));
+    // filled in from `"_cjk"` and `"_"`
+    assert!(!GroupedDefaultsTable::is_explicit("farewell", "ja"));
+    assert!(!GroupedDefaultsTable::is_explicit("farewell", "es"));
+    // left as a placeholder
+    assert_eq!(
+        NoDefaultsTable::TABLE.try_localize("greeting", "en"),
+        Some(PLACEHOLDER)
+    );
+    assert!(!NoDefaultsTable::is_explicit("greeting", "en"));
+    assert!(NoDefaultsTable::is_explicit("greeting", "es"));
+    assert!(!GroupedDefaultsTable::is_explicit("missing", "en"));
+    assert!(!GroupedDefaultsTable::is_explicit("greeting", "de"));
+}
+
+/// Make sure an `Overlay` uses its overrides first and falls through to the table for everything else
+#[cfg(feature = "std")]
+#[test]
+fn test_overlay() {
+    let mut overlay = Overlay::new(&Spanglish::TABLE);
+    assert_eq!(overlay.localize("greeting", "en"), "Hello");
+    assert_eq!(overlay.set_override("en", "greeting", "Howdy"), None);
+    assert_eq!(overlay.localize("greeting", "en"), "Howdy");
+    // other locales and keys fall through to the table
+    assert_eq!(overlay.localize("greeting", "es"), "Hola");
+    assert_eq!(overlay.localize("farewell", "en"), "Goodbye");
+    assert_eq!(
+        overlay.set_override("en", "greeting", String::from("Hi there")),
+        Some(String::from("Howdy"))
+    );
+    assert_eq!(overlay.localize("greeting", "en"), "Hi there");
+    assert_eq!(
+        overlay.clear_override("en", "greeting"),
+        Some(String::from("Hi there"))
+    );
+    assert_eq!(overlay.clear_override("en", "greeting"), None);
+    assert_eq!(overlay.localize("greeting", "en"), "Hello");
+    assert!(overlay.overrides().is_empty());
+    overlay.set_override("es", "farewell", "Chao");
+    overlay.clear_overrides();
+    assert!(overlay.overrides().is_empty());
+    assert_eq!(overlay.localize("farewell", "es"), "Adiós");
+}
+
+/// Make sure `contains_locale` works in `const` context, and `locale!` accepts locales and aliases of the
+/// table
+#[test]
+fn test_locale_macro() {
+    const _: () = assert!(Spanglish::TABLE.contains_locale("es"));
+    assert!(!Spanglish::TABLE.contains_locale("de"));
+    assert!(!Spanglish::TABLE.contains_locale("greeting"));
+    assert_eq!(locale!(Spanglish, "en"), "en");
+    assert_eq!(
+        Spanglish::localize("greeting", locale!(Spanglish, "es")),
+        "Hola"
+    );
+    assert_eq!(locale!(AliasTable, "spanish"), "spanish");
+}
+
+localization_table! {PositionalTable = LDSL {
+    sent = {
+        en => "{0} sent {1} files",
+        es => "{0} envió {1} archivos",
+    },
+    braces = {
+        en => "{{0}} is {0}, {{ and }} are braces",
+    }
+}}
+
+/// Make sure positional placeholders are substituted by index, out-of-range indices are left as-is, and
+/// doubled braces are escaped
+#[test]
+fn test_localize_positional() {
+    assert_eq!(
+        PositionalTable::TABLE.localize_positional("sent", "es", &["Ferris", "3"]),
+        "Ferris envió 3 archivos"
+    );
+    assert_eq!(
+        PositionalTable::TABLE.localize_positional("sent", "en", &["Ferris"]),
+        "Ferris sent {1} files"
+    );
+    assert_eq!(
+        PositionalTable::TABLE.localize_positional("braces", "en", &["zero"]),
+        "{0} is zero, { and } are braces"
+    );
+    // named placeholders and anything else that isn't an index are left alone
+    assert_eq!(
+        interpolate_positional("{name} {-1} {+0} {} {0}", &["a"]),
+        "{name} {-1} {+0} {} a"
+    );
+    assert!(matches!(
+        interpolate_positional("{3}", &["a"]),
+        Cow::Borrowed("{3}")
+    ));
+}
+
+/// Make sure pseudo-localized strings keep their content inside the markers, and each transform can be
+/// turned off
+#[test]
+fn test_pseudo_localizer() {
+    let mut pseudo = PseudoLocalizer::new(&Spanglish::TABLE);
+    let farewell = pseudo.localize("farewell", "en");
+    assert!(farewell.starts_with("[!! ") && farewell.ends_with(" !!]"));
+    assert!(farewell.contains("Göödbýé"));
+    // 7 characters, expanded by 30% and rounded up
+    assert!(farewell.contains("Göödbýé~~~ "));
+
+    pseudo.accents = false;
+    let greeting = pseudo.localize("greeting", "es");
+    assert!(greeting.contains("Hola"));
+    assert_eq!(greeting, "[!! Hola~~ !!]");
+
+    pseudo.markers = false;
+    pseudo.expansion_percent = 100;
+    assert_eq!(pseudo.localize("greeting", "en"), "Hello~~~~~");
+    pseudo.expansion_percent = 0;
+    assert_eq!(pseudo.localize("farewell", "es"), "Adiós");
+    // placeholders aren't accented
+    pseudo.accents = true;
+    assert_eq!(pseudo.transform("{count} new"), "{count} ñéw");
+}
+
+/// Make sure `keys` and `locales` are in table order, and the sorted copies are sorted even with
+/// `#[preserve_order]`
+#[test]
+fn test_sorted_keys_and_locales() {
+    let is_sorted = |strs: &[&str]| strs.windows(2).all(|pair| pair[0] < pair[1]);
+    assert!(is_sorted(DisplayNameTable::TABLE.keys()));
+    assert!(is_sorted(DisplayNameTable::TABLE.locales()));
+    assert_eq!(
+        DisplayNameTable::TABLE.sorted_locales(),
+        DisplayNameTable::TABLE.locales()
+    );
+    assert_eq!(
+        PreservedOrderTable::TABLE.locales(),
+        ["es", "en", "fr", "de"]
+    );
+    assert_eq!(
+        PreservedOrderTable::TABLE.sorted_locales(),
+        ["de", "en", "es", "fr"]
+    );
+    assert_eq!(
+        PreservedOrderTable::TABLE.sorted_keys(),
+        PreservedOrderTable::TABLE.keys()
+    );
+    assert!(is_sorted(&PreservedOrderTable::TABLE.sorted_keys()));
+}
+
+localization_table! {MultibyteTable = LDSL {
+    "café" = {
+        fr => "Café",
+        "日本" => "カフェ",
+    },
+    "cafè" = {
+        fr => "Cafè",
+    },
+    "🎉" = {
+        fr => "Fête",
+        "日本" => "お祝い",
+    }
+}}
+
+/// Make sure keys and locales with multibyte UTF-8 characters match exactly, including keys of the same
+/// length that only differ in their last bytes
+#[test]
+fn test_multibyte_keys() {
+    assert_eq!(MultibyteTable::localize("café", "fr"), "Café");
+    assert_eq!(MultibyteTable::localize("cafè", "fr"), "Cafè");
+    assert_eq!(MultibyteTable::localize("🎉", "日本"), "お祝い");
+    assert_eq!(MultibyteTable::TABLE.localize("café", "日本"), "カフェ");
+    assert_eq!(MultibyteTable::TABLE.key_index("🎊"), None);
+    assert_eq!(MultibyteTable::TABLE.locale_index("日"), None);
+    assert_eq!(find(&["é", "è", "🎉"], "è"), Some(1));
+}
+
+/// Make sure handles of an owned table look up translations like handles of the static table
+#[test]
+fn test_owned_locale_handle() {
+    let owned = OwnedLocalizationTable::from(Spanglish::TABLE);
+    let spanish: OwnedLocaleHandle = owned.get_locale("es");
+    assert_eq!(spanish.to_string(), "es");
+    for key in ["greeting", "farewell", "unknown"] {
+        assert_eq!(spanish.localize(key), Spanglish::ES.localize(key));
+        assert_eq!(spanish.try_localize(key), Spanglish::ES.try_localize(key));
+    }
+    assert_eq!(&spanish["farewell"], "Adiós");
+    assert_eq!(spanish.localize_or("unknown", "?"), "?");
+    // unknown locales fall back to the first locale
+    assert_eq!(owned.get_locale("de").to_string(), "en");
+    assert_eq!(owned.try_get_locale("de"), None);
+    assert_eq!(owned.try_get_locale("en"), Some(owned.get_locale("en")));
+}
+
+localization_table! {QuietTable = LDSL #[placeholder("")] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    },
+    farewell = {
+        es => "Adiós",
+    }
+}}
+
+localization_table! {MarkedTable = LDSL #[placeholder("???")] #[default_locale(es)] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    },
+    farewell = {
+        es => "Adiós",
+    },
+    welcome = {
+        en => "Welcome",
+    }
+}}
+
+/// Make sure missing cells hold `PLACEHOLDER`, and lookups return the string from `#[placeholder]` only
+/// after falling back to the default locale
+#[test]
+fn test_placeholder() {
+    assert_eq!(NoDefaultsTable::PLACEHOLDER, PLACEHOLDER);
+    assert_eq!(NoDefaultsTable::localize("greeting", "en"), PLACEHOLDER);
+    assert_eq!(QuietTable::PLACEHOLDER, "");
+    assert_eq!(QuietTable::localize("farewell", "en"), "");
+    assert_eq!(QuietTable::EN.localize("farewell"), "");
+    assert_eq!(QuietTable::localize("farewell", "es"), "Adiós");
+    // the default locale has a translation
+    assert_eq!(MarkedTable::localize("farewell", "en"), "Adiós");
+    assert_eq!(MarkedTable::EN.localize("farewell"), "Adiós");
+    // neither locale has one
+    assert_eq!(MarkedTable::localize("welcome", "es"), "???");
+    assert_eq!(MarkedTable::ES.localize("welcome"), "???");
+    assert_eq!(MarkedTable::get_locale("es").localize("welcome"), "???");
+    assert_eq!(
+        MarkedTable::localize_key(MarkedTableKey::Welcome, "es"),
+        "???"
+    );
+    assert_eq!(MarkedTable::localize_resolved("welcome", "es").0, "???");
+    assert_eq!(
+        MarkedTable::TABLE.try_localize("farewell", "en"),
+        Some(PLACEHOLDER)
+    );
+    assert!(!MarkedTable::TABLE.is_complete());
+    #[cfg(feature = "std")]
+    assert_eq!(MarkedTable::TABLE.to_map().len(), 4);
+    assert_eq!(MarkedTable::localize("greeting", "en"), "Hello");
+}
+
+/// Make sure a translation that isn't found anywhere comes back as the non-empty `PLACEHOLDER`
+#[test]
+fn test_placeholder_value() {
+    assert_eq!(PLACEHOLDER, "<NO TRANSLATION>");
+    assert_eq!(
+        NoDefaultsTable::localize("greeting", "en"),
+        "<NO TRANSLATION>"
+    );
+    assert_eq!(NoDefaultsTable::EN.localize("greeting"), "<NO TRANSLATION>");
+    assert_eq!(EmptyTable::localize("x", "y"), "<NO TRANSLATION>");
+}
+
+localization_table! {GroupedLocalesTable = LDSL {
+    ok = {
+        (en, es, it) => "OK",
+        fr => "D'accord",
+    },
+    welcome = {
+        (en, es, it)(short) => "👋",
+        en => "Welcome",
+        es => "Bienvenido",
+        it => "Benvenuto",
+        fr => "Bienvenue",
+    }
+}}
+
+/// Make sure a group of locales gives each of them the translation
+#[test]
+fn test_grouped_locales() {
+    assert_eq!(GroupedLocalesTable::TABLE.locales, ["en", "es", "fr", "it"]);
+    for locale in ["en", "es", "it"] {
+        assert_eq!(GroupedLocalesTable::localize("ok", locale), "OK");
+    }
+    assert_eq!(GroupedLocalesTable::localize("ok", "fr"), "D'accord");
+    assert_eq!(
+        GroupedLocalesTable::localize_ctx("welcome", "es", "short"),
+        "👋"
+    );
+    assert_eq!(
+        GroupedLocalesTable::localize_ctx("welcome", "fr", "short"),
+        "Bienvenue"
+    );
+}
+
+/// Make sure `total_chars` and `distinct_locales` match hand-counted values
+#[test]
+fn test_table_stats() {
+    // "Goodbye" + "Hello" + "Adiós" (with a two-byte `ó`) + "Hola"
+    const TOTAL: usize = Spanglish::TABLE.total_chars();
+    assert_eq!(TOTAL, 7 + 5 + 6 + 4);
+    assert_eq!(Spanglish::TABLE.distinct_locales(), 2);
+    assert_eq!(EmptyTable::TABLE.total_chars(), 0);
+    assert_eq!(EmptyTable::TABLE.distinct_locales(), 0);
+    let repeated = LocalizationTable {
+        translation_keys: ["greeting"],
+        locales: ["en", "es", "en"],
+        translations: [["Hello"], ["Hola"], ["Hi"]],
+    };
+    assert_eq!(repeated.total_chars(), 11);
+    assert_eq!(repeated.distinct_locales(), 2);
+}
+
+localization_table! {BrandTable = LDSL {
+    brand = {
+        fr => "Marque",
+        * => "Brand",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        fr => "Bonjour",
+        ja => "こんにちは",
+    }
+}}
+
+/// Make sure a `*` translation fills in every locale that its key doesn't list, including locales from
+/// later keys, without replacing the locales it does list
+#[test]
+fn test_wildcard() {
+    assert_eq!(BrandTable::localize("brand", "fr"), "Marque");
+    for locale in ["en", "es", "ja"] {
+        assert_eq!(BrandTable::localize("brand", locale), "Brand");
+        assert!(BrandTable::is_explicit("brand", locale));
+    }
+    assert!(BrandTable::TABLE.is_complete());
+}
+
+/// Make sure the global locale is used by `global_localize` and `tg!` once it's set, is shared between
+/// threads, and is only set to locales of the table
+#[cfg(feature = "std")]
+#[test]
+fn test_global_locale() {
+    // the only test that sets the global locale, since it's shared by every test
+    assert_eq!(global_locale(), None);
+    assert_eq!(tg!(Spanglish, "greeting"), "Hello");
+    assert_eq!(set_global_locale::<Spanglish>("de"), Err(UnknownLocale));
+    assert_eq!(global_locale(), None);
+    set_global_locale::<Spanglish>("es").unwrap();
+    assert_eq!(global_locale().as_deref(), Some("es"));
+    assert_eq!(global_localize::<Spanglish>("farewell"), "Adiós");
+    // tables without the global locale use their default locale
+    assert_eq!(tg!(MultibyteTable, "café"), "Café");
+
+    // threads started after the locale was set all translate into it
+    let readers: Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(|| (global_locale(), tg!(Spanglish, "greeting"))))
+        .collect();
+    for reader in readers {
+        assert_eq!(reader.join().unwrap(), (Some(String::from("es")), "Hola"));
+    }
+    // a locale set on one thread is seen by the others once it's set
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            set_global_locale::<Spanglish>("en").unwrap();
+            sender.send(()).unwrap();
+        });
+        scope.spawn(move || {
+            receiver.recv().unwrap();
+            assert_eq!(global_locale().as_deref(), Some("en"));
+            assert_eq!(tg!(Spanglish, "greeting"), "Hello");
+            assert_eq!(global_localize::<Spanglish>("farewell"), "Goodbye");
+        });
+    });
+    assert_eq!(tg!(Spanglish, "greeting"), "Hello");
+}
+
+/// Make sure `localize_in` accepts a locale as a string, a handle, or the generated locale enum, and
+/// `t` is the same as `localize` on a handle
+#[test]
+fn test_as_locale() {
+    let spanish = Spanglish::get_locale("es");
+    assert_eq!(spanish.t("greeting"), spanish.localize("greeting"));
+    assert_eq!(Spanglish::TABLE.localize_in("greeting", "es"), "Hola");
+    assert_eq!(Spanglish::TABLE.localize_in("greeting", spanish), "Hola");
+    assert_eq!(
+        Spanglish::TABLE.localize_in("farewell", SpanglishLocale::Es),
+        "Adiós"
+    );
+    assert_eq!(
+        Spanglish::TABLE.localize_in("farewell", String::from("en")),
+        "Goodbye"
+    );
+    let owned = OwnedLocalizationTable::from(Spanglish::TABLE);
+    assert_eq!(
+        Spanglish::localize_in("greeting", owned.get_locale("es")),
+        "Hola"
+    );
+    // the generated function resolves aliases
+    assert_eq!(AliasTable::localize_in("greeting", "spanish"), "Hola");
+    assert_eq!(AliasTableLocale::Es.as_locale(), "es");
+}
+
+localization_table! {CheckedTable = LDSL #[generate_tests] #[preserve_order] {
+    "_" = {
+        es => "N/D",
+        en => "N/A",
+    },
+    greeting = {
+        es => "Hola",
+        en => "Hello",
+    },
+    farewell = {
+        en => "Goodbye",
+    }
+}}
+
+/// Make sure `#[generate_tests]` generates a test for the table, which passes for a complete table
+#[test]
+fn test_generated_test() {
+    CheckedTable_is_complete_and_sorted();
+}
+
+localization_table! {ReleasedTable = LDSL {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        fr => "Bonjour",
+    },
+    farewell = {
+        en => "Goodbye",
+        es => "Adiós",
+        fr => "Au revoir",
+    }
+}}
+
+localization_table! {UpdatedTable = LDSL {
+    greeting = {
+        en => "Hi",
+        es => "Hola",
+    },
+    farewell = {
+        en => "Goodbye",
+        es => "Adiós",
+    },
+    thanks = {
+        en => "Thanks",
+        es => "Gracias",
+    }
+}}
+
+/// Make sure `diff` lists added keys, removed locales, and changed translations
+#[test]
+fn test_diff() {
+    let changes = ReleasedTable::TABLE.diff(&UpdatedTable::TABLE);
+    assert_eq!(
+        changes,
+        [
+            TableChange::AddedKey("thanks"),
+            TableChange::RemovedLocale("fr"),
+            TableChange::ChangedCell {
+                locale: "en",
+                translation_key: "greeting",
+                old: "Hello",
+                new: "Hi"
+            }
+        ]
+    );
+    assert_eq!(
+        changes[2].to_string(),
+        r#"changed `greeting` in `en` from "Hello" to "Hi""#
+    );
+    assert_eq!(
+        UpdatedTable::TABLE.diff(&ReleasedTable::TABLE)[..2],
+        [
+            TableChange::RemovedKey("thanks"),
+            TableChange::AddedLocale("fr")
+        ]
+    );
+    assert_eq!(ReleasedTable::TABLE.diff(&ReleasedTable::TABLE), []);
+}
+
+localization_table! {ResolvedTable = LDSL #[default_locale(en)] {
+    "_" = {
+        en => "<Missing>",
+    },
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        pt => "Olá",
+    },
+    farewell = {
+        en => "Goodbye",
+        pt => "Tchau",
+    },
+    unknown = {
+        en => "<Missing>",
+    }
+}}
+
+/// Make sure `localize_resolved` reports which locale or default each translation came from
+#[test]
+fn test_localize_resolved() {
+    assert_eq!(
+        ResolvedTable::localize_resolved("greeting", "es"),
+        ("Hola", Resolution::Exact("es"))
+    );
+    // a locale that isn't part of the table falls back to its base language first
+    assert_eq!(
+        ResolvedTable::localize_resolved("farewell", "pt-BR"),
+        ("Tchau", Resolution::BaseLanguage("pt"))
+    );
+    assert_eq!(
+        ResolvedTable::localize_resolved("farewell", "es-MX"),
+        ("Goodbye", Resolution::Default("en"))
+    );
+    assert_eq!(
+        ResolvedTable::localize_resolved("farewell", "es"),
+        ("Goodbye", Resolution::Default("en"))
+    );
+    assert_eq!(
+        ResolvedTable::localize_resolved("greeting", "de"),
+        ("Hello", Resolution::Default("en"))
+    );
+    assert_eq!(
+        ResolvedTable::localize_resolved("welcome", "pt"),
+        ("<Missing>", Resolution::DefaultKey)
+    );
+    // a translation that happens to be the same as the `"_"` is still its own
+    assert_eq!(
+        ResolvedTable::localize_resolved("unknown", "en"),
+        ("<Missing>", Resolution::Exact("en"))
+    );
+    // cells filled in from a `#[default_key]` other than `"_"`
+    assert_eq!(
+        GroupedDefaultsTable::localize_resolved("farewell", "ja"),
+        ("なし", Resolution::DefaultKey)
+    );
+    assert_eq!(
+        GroupedDefaultsTable::localize_resolved("greeting", "ja"),
+        ("こんにちは", Resolution::Exact("ja"))
+    );
+    assert_eq!(
+        NoDefaultsTable::localize_resolved("greeting", "en"),
+        (PLACEHOLDER, Resolution::Missing)
+    );
+    assert_eq!(Resolution::Exact("pt").locale(), Some("pt"));
+    assert_eq!(Resolution::BaseLanguage("pt").locale(), Some("pt"));
+    assert_eq!(Resolution::Missing.locale(), None);
+}
+
+/// Make sure `localize_resolved` reports the translation that `localize` returns for the locale it
+/// resolved to
+#[test]
+fn test_localize_resolved_matches_localize() {
+    for key in ["_", "greeting", "farewell", "welcome", "missing"] {
+        for locale in ["en", "es", "pt", "de", "de-AT"] {
+            assert_eq!(
+                ResolvedTable::localize_resolved(key, locale).0,
+                ResolvedTable::localize(key, locale)
+            );
+            assert_eq!(
+                ResolvedTable::TABLE.localize_resolved(key, locale).0,
+                ResolvedTable::TABLE.localize(key, locale)
+            );
+            assert_eq!(
+                Spanglish::TABLE.localize_resolved(key, locale).0,
+                Spanglish::TABLE.localize(key, locale)
+            );
+            assert_eq!(
+                MarkedTable::localize_resolved(key, locale).0,
+                MarkedTable::localize(key, locale)
+            );
+            assert_eq!(
+                FallbackTable::localize_resolved(key, locale).0,
+                FallbackTable::localize(key, locale)
+            );
+        }
+    }
+    for key in ["greeting", "farewell", "welcome"] {
+        assert_eq!(
+            ResolvedTable::localize_resolved(key, "pt-BR").0,
+            ResolvedTable::localize(key, "pt")
+        );
+    }
+    assert_eq!(
+        MarkedTable::localize_resolved("welcome", "es"),
+        ("???", Resolution::Missing)
+    );
+}
+
+localization_table! {IdenticalRowsTable = LDSL #[size_report] {
+    colour = {
+        en => "Color",
+        en-AU => "Colour",
+        en-GB => "Colour",
+        es => "Color",
+    },
+    elevator = {
+        en => "Elevator",
+        es => "Ascensor",
+    },
+    favourite = {
+        en => "Favorite",
+        en-AU => "Favourite",
+        en-GB => "Favourite",
+        es => "Favorito",
+    }
+}}
+
+/// Make sure locales with identical translations share a row, translate every key correctly, including
+/// falling back to the default locale, and take up less space than separate rows
+#[test]
+fn test_identical_rows() {
+    assert_eq!(
+        IdenticalRowsTable::TABLE.locales,
+        ["en", "en-AU", "en-GB", "es"]
+    );
+    assert_eq!(IdenticalRowsTable::ROW_INDEX, [0, 1, 1, 2]);
+    assert_eq!(
+        IdenticalRowsTable::TABLE.translations[1],
+        IdenticalRowsTable::TABLE.translations[2]
+    );
+    assert_eq!(IdenticalRowsTable::localize("colour", "en-AU"), "Colour");
+    assert_eq!(IdenticalRowsTable::localize("colour", "en-GB"), "Colour");
+    assert_eq!(IdenticalRowsTable::localize("colour", "es"), "Color");
+    assert_eq!(
+        IdenticalRowsTable::localize("elevator", "en-GB"),
+        "Elevator"
+    );
+    assert_eq!(IdenticalRowsTable::localize("elevator", "es"), "Ascensor");
+    assert_eq!(
+        IdenticalRowsTable::localize("favourite", "en-GB"),
+        "Favourite"
+    );
+    for key in ["colour", "elevator", "favourite", "missing"] {
+        for locale in ["en", "en-AU", "en-GB", "es", "de"] {
+            assert_eq!(
+                IdenticalRowsTable::localize(key, locale),
+                IdenticalRowsTable::TABLE.localize(key, locale)
+            );
+        }
+    }
+    assert_eq!(IdenticalRowsTable::localize("colour", "de"), "Color");
+
+    // "Color", "Colour", "Elevator", "Ascensor", "Favorite", "Favourite", "Favorito", and the placeholder
+    let translations = 5 + 6 + 8 + 8 + 8 + 9 + 8 + PLACEHOLDER.len();
+    let keys = "colour".len() + "elevator".len() + "favourite".len();
+    let locales = "en".len() + "en-AU".len() + "en-GB".len() + "es".len();
+    let strings = translations + keys + locales;
+    // three distinct rows instead of four, and the index of every locale's row
+    let shared = (3 * 3 + 3 + 4) * size_of::<&str>() + 4 * size_of::<usize>();
+    let separate = (4 * 3 + 3 + 4) * size_of::<&str>();
+    assert_eq!(IdenticalRowsTable::BYTES, strings + shared);
+    assert!(IdenticalRowsTable::BYTES < strings + separate);
+}
+
+/// Make sure `from_str_fallback` strips subtags to find a locale, and returns `None` if nothing matches
+#[test]
+fn test_from_str_fallback() {
+    assert_eq!(
+        SpanglishLocale::from_str_fallback("en-US"),
+        Some(SpanglishLocale::En)
+    );
+    assert_eq!(
+        SpanglishLocale::from_str_fallback("es"),
+        Some(SpanglishLocale::Es)
+    );
+    assert_eq!(SpanglishLocale::from_str_fallback("de"), None);
+    assert_eq!(
+        IdenticalRowsTableLocale::from_str_fallback("en-GB-oxendict"),
+        Some(IdenticalRowsTableLocale::EnGb)
+    );
+}
+
+/// Make sure `validate` accepts sorted, complete tables, and rejects unsorted or incomplete ones
+#[test]
+fn test_validate() {
+    const VALID: LocalizationTable<2, 2> = LocalizationTable {
+        translation_keys: ["farewell", "greeting"],
+        locales: ["en", "es"],
+        translations: [["Goodbye", "Hello"], ["Adiós", "Hola"]],
+    };
+    const _: () = assert!(VALID.validate());
+    assert!(Spanglish::TABLE.validate());
+    let unsorted_keys = LocalizationTable {
+        translation_keys: ["greeting", "farewell"],
+        ..VALID
+    };
+    assert!(!unsorted_keys.validate());
+    let duplicate_locales = LocalizationTable {
+        locales: ["en", "en"],
+        ..VALID
+    };
+    assert!(!duplicate_locales.validate());
+    let incomplete = LocalizationTable {
+        translations: [["Goodbye", "Hello"], [PLACEHOLDER, "Hola"]],
+        ..VALID
+    };
+    assert!(!incomplete.validate());
+}
+
+localization_table! {PropertiesTable = LDSL {
+    "menu title" = {
+        en => "File: Open",
+    },
+    path = {
+        en => r"C:\Users",
+    },
+    tip = {
+        en => "  Press = to\nconfirm #1!",
+    }
+}}
+
+/// Make sure `write_properties` writes a line for every key, escaping keys and values like Java does
+#[test]
+fn test_write_properties() {
+    let mut properties = String::new();
+    PropertiesTable::with_locale("en")
+        .write_properties(&mut properties)
+        .unwrap();
+    assert_eq!(
+        properties,
+        "menu\\ title=File\\: Open\npath=C\\:\\\\Users\ntip=\\  Press \\= to\\nconfirm \\#1\\!\n"
+    );
+}
+
+/// Make sure locale handles compare and hash by their locale, regardless of the table they come from
+#[test]
+fn test_locale_handle_eq() {
+    use std::collections::HashMap;
+
+    let retranslated = LocalizationTable {
+        translations: [["Goodbye", "Hi"], ["Chao", "Hola"]],
+        ..Spanglish::TABLE
+    };
+    assert_eq!(retranslated.get_locale("es"), Spanglish::ES);
+    assert_ne!(retranslated.get_locale("en"), Spanglish::ES);
+
+    let mut cache = HashMap::new();
+    cache.insert(Spanglish::EN, "rendered in English");
+    cache.insert(Spanglish::get_locale("es"), "rendered in Spanish");
+    assert_eq!(cache.len(), 2);
+    assert_eq!(
+        cache.get(&retranslated.get_locale("es")),
+        Some(&"rendered in Spanish")
+    );
+    assert_eq!(
+        cache.get(&Spanglish::get_locale("de")),
+        Some(&"rendered in English")
+    );
+}
+
+localization_table! {SpanglishByLocale = LDSL_BY_LOCALE {
+    en {
+        greeting => "Hello",
+        farewell => "Goodbye",
+    }
+    es {
+        "greeting" => "Hola",
+        "farewell" => "Adiós",
+    }
+}}
+
+/// Make sure the locale-major `LDSL_BY_LOCALE` form builds the same table as the key-major LDSL form
+#[test]
+fn test_ldsl_by_locale() {
+    assert_eq!(SpanglishByLocale::TABLE, Spanglish::TABLE);
+    assert_eq!(SpanglishByLocale::localize("farewell", "es"), "Adiós");
+}
+
+/// Make sure `--cfg localize_show_keys` makes `localize` return the identifier keys of the table instead
+/// of their translations. Run with `RUSTFLAGS="--cfg localize_show_keys" cargo test test_show_keys`,
+/// since it changes what every other test expects.
+#[cfg(localize_show_keys)]
+#[test]
+fn test_show_keys() {
+    assert_eq!(Spanglish::localize("greeting", "en"), "greeting");
+    assert_eq!(Spanglish::localize("farewell", "es"), "farewell");
+    // keys that aren't identifiers are still looked up
+    assert_eq!(
+        CountTable::localize("apples.one", "en"),
+        CountTable::TABLE.localize("apples.one", "en")
+    );
+    assert_eq!(
+        FallbackTable::localize("_", "en"),
+        FallbackTable::TABLE.localize("_", "en")
+    );
+    // keys that aren't part of the table are still looked up
+    assert_eq!(
+        Spanglish::localize("welcome", "en"),
+        Spanglish::TABLE.localize("welcome", "en")
+    );
+}
+
+localization_table! {BrandedTable = LDSL #[alias(spanish = es)] {
+    "_" = {
+        en => "<Missing>",
+        es => "<Falta>",
+    },
+    "acme.welcome" = {
+        en => "Welcome to Acme",
+    },
+    welcome = {
+        en => "Welcome",
+        es => "Bienvenido",
+    }
+}}
+
+/// Make sure `localize_first` skips candidate keys without their own translation in the locale
+#[test]
+fn test_localize_first() {
+    let candidates = ["acme.welcome", "welcome"];
+    assert_eq!(
+        BrandedTable::localize_first(&candidates, "en"),
+        Some("Welcome to Acme")
+    );
+    assert_eq!(
+        BrandedTable::localize_first(&candidates, "spanish"),
+        Some("Bienvenido")
+    );
+    assert_eq!(
+        BrandedTable::localize_first(&["unknown", "welcome"], "es"),
+        Some("Bienvenido")
+    );
+    assert_eq!(BrandedTable::localize_first(&["acme.welcome"], "es"), None);
+    assert_eq!(BrandedTable::localize_first(&candidates, "de"), None);
+    assert_eq!(BrandedTable::localize_first(&[], "en"), None);
+}
+
+#[allow(deprecated)]
+mod same_as_key {
+    use localize_macros::localization_table;
+
+    localization_table! {SameAsKeyTable = LDSL #[warn_same_as_key] #[allow_same_as_key("Email")] {
+        Email = {
+            en => "Email",
+            de => "Email",
+        },
+        OK = {
+            en => "OK",
+            de => "OK",
+        }
+    }}
+
+    /// Make sure a table with translations that are the same as their keys still builds
+    #[test]
+    fn test_same_as_key() {
+        assert_eq!(SameAsKeyTable::localize("Email", "de"), "Email");
+        assert_eq!(SameAsKeyTable::localize("OK", "de"), "OK");
+    }
+}
+
+localization_table! {MultiTable = LDSL_MULTI {
+    locale en {
+        "_" => "Hello",
+        greeting => "Hello",
+        farewell => "Goodbye",
+    }
+    locale es {
+        "_" => "Hola",
+        greeting => "Hola",
+        thanks => "Gracias",
+    }
+}}
+
+/// Make sure `LDSL_MULTI` unions the keys of its locale blocks, and that a key a block doesn't list falls
+/// back to `"_"`
+#[test]
+fn test_ldsl_multi() {
+    assert_eq!(
+        MultiTable::TABLE.translation_keys,
+        ["_", "farewell", "greeting", "thanks"]
+    );
+    assert_eq!(MultiTable::localize("greeting", "es"), "Hola");
+    assert_eq!(MultiTable::localize("thanks", "es"), "Gracias");
+    assert_eq!(MultiTable::localize("thanks", "en"), "Hello");
+    assert_eq!(MultiTable::localize("farewell", "es"), "Hola");
+}
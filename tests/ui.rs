@@ -0,0 +1,6 @@
+/// Make sure the compile errors of the macros have the right messages and point at the right tokens
+#[test]
+fn test_ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,13 @@
+use localize::localization_table;
+
+localization_table! {Spanglish = LDSL #[complete] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    },
+    farewell = {
+        en => "Goodbye",
+    }
+}}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use localize::localization_table;
+
+localization_table! {Spanglish = LDSL #[validate_locales] {
+    greeting = {
+        english123 => "Hello",
+    }
+}}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use localize::localization_table;
+
+localization_table! {Spanglish = LDSL {
+    greeting = {
+        en => "Hello",
+    },
+    farewell = {
+        En => "Goodbye",
+    }
+}}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use localize::localization_table;
+
+localization_table! {Spanglish = LDSL #[locales(en, es, fr)] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    }
+}}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use localize::localization_table;
+
+localization_table! {Spanglish = LDSL {
+    greeting = {
+        _ => "x",
+    }
+}}
+
+fn main() {}
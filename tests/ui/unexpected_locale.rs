@@ -0,0 +1,11 @@
+use localize::localization_table;
+
+localization_table! {Spanglish = LDSL #[locales(es, en)] {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+        fr => "Bonjour",
+    }
+}}
+
+fn main() {}
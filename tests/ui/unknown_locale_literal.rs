@@ -0,0 +1,12 @@
+use localize::{locale, localization_table};
+
+localization_table! {Spanglish = LDSL {
+    greeting = {
+        en => "Hello",
+        es => "Hola",
+    }
+}}
+
+fn main() {
+    let _ = Spanglish::localize("greeting", locale!(Spanglish, "sp"));
+}
@@ -0,0 +1,11 @@
+use localize::localization_table;
+
+localization_table! {Spanglish = YAML "locales/strings.yaml"}
+
+localization_table! {Frenglish = ldsl {
+    greeting = {
+        en => "Hello",
+    }
+}}
+
+fn main() {}